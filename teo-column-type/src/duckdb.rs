@@ -0,0 +1,125 @@
+use std::{fmt::{self, Display, Formatter}, str::FromStr};
+use crate::error::Error;
+
+#[derive(Debug, PartialEq)]
+pub enum ColumnType {
+    BigInt,
+    Bit,
+    Blob,
+    Boolean,
+    Date,
+    Decimal { p: usize, s: usize },
+    Double,
+    Float,
+    HugeInt,
+    Integer,
+    Interval,
+    JSON,
+    SmallInt,
+    Time,
+    Timestamp,
+    TimestampWithTimeZone,
+    TinyInt,
+    UBigInt,
+    UHugeInt,
+    UInteger,
+    USmallInt,
+    UTinyInt,
+    UUID,
+    Varchar,
+}
+
+impl FromStr for ColumnType {
+
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "bigint" | "int8" | "long" => Self::BigInt,
+            "bit" | "bitstring" => Self::Bit,
+            "blob" | "bytea" | "binary" | "varbinary" => Self::Blob,
+            "boolean" | "bool" | "logical" => Self::Boolean,
+            "date" => Self::Date,
+            "decimal" | "numeric" => Self::Decimal { p: 18, s: 3 },
+            "double" | "float8" => Self::Double,
+            "float" | "float4" | "real" => Self::Float,
+            "hugeint" => Self::HugeInt,
+            "integer" | "int4" | "int" | "signed" => Self::Integer,
+            "interval" => Self::Interval,
+            "json" => Self::JSON,
+            "smallint" | "int2" | "short" => Self::SmallInt,
+            "time" => Self::Time,
+            "timestamp" | "datetime" => Self::Timestamp,
+            "timestamp with time zone" | "timestamptz" => Self::TimestampWithTimeZone,
+            "tinyint" | "int1" => Self::TinyInt,
+            "ubigint" => Self::UBigInt,
+            "uhugeint" => Self::UHugeInt,
+            "uinteger" => Self::UInteger,
+            "usmallint" => Self::USmallInt,
+            "utinyint" => Self::UTinyInt,
+            "uuid" => Self::UUID,
+            "varchar" | "char" | "bpchar" | "text" | "string" => Self::Varchar,
+            c if c.contains("(") && c.contains(")") => {
+                let start = c.find("(").ok_or(Error::new(s))?;
+                let end = c.find(")").ok_or(Error::new(s))?;
+                let prefix = c[0..start].trim();
+                let arg = c[start + 1..end].trim();
+                let suffix = c[end + 1..].trim();
+                match (prefix, suffix) {
+                    ("varchar", "") | ("char", "") | ("bpchar", "") => Self::Varchar,
+                    ("decimal", "") | ("numeric", "") => {
+                        let mut args = arg.split(",").map(|a| a.trim());
+                        let arg_p = if let Some(p) = args.next() {
+                            usize::from_str(p).map_err(|_| Error::new(s))?
+                        } else {
+                            Err(Error::new(s))?
+                        };
+                        let arg_s = if let Some(s) = args.next() {
+                            usize::from_str(s).map_err(|_| Error::new(s))?
+                        } else {
+                            0
+                        };
+                        if args.next().is_some() {
+                            Err(Error::new(s))?
+                        }
+                        Self::Decimal { p: arg_p, s: arg_s }
+                    },
+                    _ => Err(Error::new(s))?
+                }
+            }
+            _ => Err(Error::new(s))?
+        })
+    }
+}
+
+impl Display for ColumnType {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
+            ColumnType::BigInt => "bigint".to_string(),
+            ColumnType::Bit => "bit".to_string(),
+            ColumnType::Blob => "blob".to_string(),
+            ColumnType::Boolean => "boolean".to_string(),
+            ColumnType::Date => "date".to_string(),
+            ColumnType::Decimal { p, s } => format!("decimal({p},{s})"),
+            ColumnType::Double => "double".to_string(),
+            ColumnType::Float => "float".to_string(),
+            ColumnType::HugeInt => "hugeint".to_string(),
+            ColumnType::Integer => "integer".to_string(),
+            ColumnType::Interval => "interval".to_string(),
+            ColumnType::JSON => "json".to_string(),
+            ColumnType::SmallInt => "smallint".to_string(),
+            ColumnType::Time => "time".to_string(),
+            ColumnType::Timestamp => "timestamp".to_string(),
+            ColumnType::TimestampWithTimeZone => "timestamp with time zone".to_string(),
+            ColumnType::TinyInt => "tinyint".to_string(),
+            ColumnType::UBigInt => "ubigint".to_string(),
+            ColumnType::UHugeInt => "uhugeint".to_string(),
+            ColumnType::UInteger => "uinteger".to_string(),
+            ColumnType::USmallInt => "usmallint".to_string(),
+            ColumnType::UTinyInt => "utinyint".to_string(),
+            ColumnType::UUID => "uuid".to_string(),
+            ColumnType::Varchar => "varchar".to_string(),
+        })
+    }
+}
@@ -16,3 +16,5 @@ impl Display for Error {
         Ok(())
     }
 }
+
+impl std::error::Error for Error { }
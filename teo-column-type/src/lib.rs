@@ -2,6 +2,9 @@ mod error;
 
 pub use error::Error;
 
+#[cfg(feature = "duckdb")]
+pub mod duckdb;
+
 #[cfg(feature = "mongo")]
 pub mod mongo;
 
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{fmt::{self, Display, Formatter}, str::FromStr};
 use serde::{Deserialize, Serialize};
 
 use crate::error::Error;
@@ -38,10 +38,10 @@ impl FromStr for ColumnType {
     }
 }
 
-impl ToString for ColumnType {
+impl Display for ColumnType {
 
-    fn to_string(&self) -> String {
-        match self {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
             ColumnType::Bool => "bool".to_string(),
             ColumnType::String => "string".to_string(),
             ColumnType::Date => "date".to_string(),
@@ -52,6 +52,6 @@ impl ToString for ColumnType {
             ColumnType::Decimal128 => "decimal128".to_string(),
             ColumnType::Timestamp => "timestamp".to_string(),
             ColumnType::UUID => "uuid".to_string(),
-        }
+        })
     }
 }
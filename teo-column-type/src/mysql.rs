@@ -1,6 +1,24 @@
-use std::str::FromStr;
+use std::{fmt::{self, Display, Formatter}, str::FromStr};
 use crate::error::Error;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Variant {
+    #[default]
+    MySQL,
+    MariaDB,
+}
+
+impl Variant {
+
+    pub fn from_server_version(version: &str) -> Self {
+        if version.to_lowercase().contains("mariadb") {
+            Self::MariaDB
+        } else {
+            Self::MySQL
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum ColumnType {
     TinyInt,
@@ -38,6 +56,9 @@ pub enum ColumnType {
     Multipolygon,
     GeometryCollection,
     JSON,
+    UUID,
+    Inet4,
+    Inet6,
 }
 
 impl FromStr for ColumnType {
@@ -78,6 +99,9 @@ impl FromStr for ColumnType {
             "multipolygon" => Self::Multipolygon,
             "geometrycollection" => Self::GeometryCollection,
             "json" => Self::JSON,
+            "uuid" => Self::UUID,
+            "inet4" => Self::Inet4,
+            "inet6" => Self::Inet6,
             c if c.contains("(") && c.contains(")") => {
                 let start = c.find("(").ok_or(Error::new(s))?;
                 let end = c.find(")").ok_or(Error::new(s))?;
@@ -86,14 +110,14 @@ impl FromStr for ColumnType {
                 let suffix = c[end + 1..].trim();
                 match (prefix, suffix) {
                     ("decimal", "") | ("numeric", "") | ("dec", "") | ("fixed", "") => {
-                        let mut args = arg.split(",").into_iter().map(|a| a.trim());
+                        let mut args = arg.split(",").map(|a| a.trim());
                         let m = if let Some(m) = args.next() {
                             usize::from_str(m).map_err(|_| Error::new(s))?
                         } else {
                             Err(Error::new(s))?
                         };
                         if let Some(d) = args.next() {
-                            if args.next() != None {
+                            if args.next().is_some() {
                                 Err(Error::new(s))?
                             } else {
                                 let d = usize::from_str(d).map_err(|_| Error::new(s))?;
@@ -151,10 +175,10 @@ impl FromStr for ColumnType {
     }
 }
 
-impl ToString for ColumnType {
+impl Display for ColumnType {
 
-    fn to_string(&self) -> String {
-        match self {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
             ColumnType::TinyInt => "tinyint".to_string(),
             ColumnType::SmallInt => "smallint".to_string(),
             ColumnType::MediumInt => "mediumint".to_string(),
@@ -190,6 +214,9 @@ impl ToString for ColumnType {
             ColumnType::Multipolygon => "multipolygon".to_string(),
             ColumnType::GeometryCollection => "geometrycollection".to_string(),
             ColumnType::JSON => "json".to_string(),
-        }
+            ColumnType::UUID => "uuid".to_string(),
+            ColumnType::Inet4 => "inet4".to_string(),
+            ColumnType::Inet6 => "inet6".to_string(),
+        })
     }
 }
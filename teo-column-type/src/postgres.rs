@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{fmt::{self, Display, Formatter}, str::FromStr};
 use crate::error::Error;
 
 #[derive(Debug, PartialEq)]
@@ -120,7 +120,7 @@ impl FromStr for ColumnType {
                         Self::CharacterVarying { n: Some(n) }
                     },
                     ("numeric", "") | ("decimal", "") => {
-                        let mut args = arg.split(",").into_iter().map(|a| a.trim());
+                        let mut args = arg.split(",").map(|a| a.trim());
                         let arg_p = if let Some(p) = args.next() {
                             usize::from_str(p).map_err(|_| Error::new(s))?
                         } else {
@@ -131,7 +131,7 @@ impl FromStr for ColumnType {
                         } else {
                             Err(Error::new(s))?
                         };
-                        if args.next() != None {
+                        if args.next().is_some() {
                             Err(Error::new(s))?
                         }
                         Self::Numeric { p: Some(arg_p), s: Some(arg_s) }
@@ -160,9 +160,10 @@ impl FromStr for ColumnType {
     }
 }
 
-impl ToString for ColumnType {
-    fn to_string(&self) -> String {
-        match self {
+impl Display for ColumnType {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
             ColumnType::BigInt => "bigint".to_string(),
             ColumnType::BigSerial => "bigserial".to_string(),
             ColumnType::Bit { n } => format!("bit({n})"),
@@ -235,6 +236,6 @@ impl ToString for ColumnType {
             ColumnType::TxIDSnapshot => "txid_snapshot".to_string(),
             ColumnType::UUID => "uuid".to_string(),
             ColumnType::XML => "xml".to_string(),
-        }
+        })
     }
 }
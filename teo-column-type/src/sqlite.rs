@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{fmt::{self, Display, Formatter}, str::FromStr};
 use crate::error::Error;
 
 #[derive(Debug, PartialEq)]
@@ -26,13 +26,14 @@ impl FromStr for ColumnType {
     }
 }
 
-impl ToString for ColumnType {
-    fn to_string(&self) -> String {
-        match self {
+impl Display for ColumnType {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&match self {
             ColumnType::Integer => "integer".to_string(),
             ColumnType::Real => "real".to_string(),
             ColumnType::Text => "text".to_string(),
             ColumnType::Blob => "blob".to_string(),
-        }
+        })
     }
 }
@@ -0,0 +1,127 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Result, Type, spanned::Spanned};
+use teo_column_type::duckdb::ColumnType;
+use crate::{entity::column_types::extended_column_type::ExtendedColumnType, utils::extract_first_path_argument};
+
+impl ExtendedColumnType for ColumnType {
+    #[allow(clippy::if_same_then_else)]
+    fn default_column_type(ty: &Type) -> Result<TokenStream> {
+        match ty {
+            Type::Array(_)
+            | Type::BareFn(_)
+            | Type::ImplTrait(_)
+            | Type::Infer(_)
+            | Type::Macro(_)
+            | Type::Never(_)
+            | Type::Ptr(_)
+            | Type::Reference(_)
+            | Type::Slice(_)
+            | Type::TraitObject(_)
+            | Type::Tuple(_)
+            | Type::Verbatim(_) => Err(Error::new(ty.span(), "teo(duckdb): Can't figure out default column type.")),
+
+            Type::Group(syn::TypeGroup { elem, .. })
+            | Type::Paren(syn::TypeParen { elem, .. })
+            | Type::Path(syn::TypePath {
+                qself: Some(syn::QSelf { ty: elem, .. }),
+                ..
+            }) => Self::default_column_type(elem),
+
+            Type::Path(syn::TypePath { qself: None, path }) => {
+                if path.is_ident("bool") {
+                    Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::Boolean })
+                } else if path.is_ident("i32") {
+                    Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::Integer })
+                } else if path.is_ident("i64") {
+                    Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::BigInt })
+                } else if path.is_ident("f32") {
+                    Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::Float })
+                } else if path.is_ident("f64") {
+                    Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::Double })
+                } else if path.is_ident("String") {
+                    Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::Varchar })
+                } else if path.is_ident("Uuid") {
+                    Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::UUID })
+                } else {
+                    if path.leading_colon.is_none()
+                        && path.segments.len() == 1
+                        && path.segments[0].ident == "Option" {
+                        let inner_ty = extract_first_path_argument(path)?;
+                        Self::default_column_type(inner_ty)
+                    } else if path.segments.len() == 3
+                        && (path.segments[0].ident == "std" || path.segments[0].ident == "core")
+                        && path.segments[1].ident == "option"
+                        && path.segments[2].ident == "Option" {
+                        let inner_ty = extract_first_path_argument(path)?;
+                        Self::default_column_type(inner_ty)
+                    } else {
+                        if path.segments.len() == 2
+                            && path.segments[0].ident == "uuid"
+                            && path.segments[1].ident == "Uuid" {
+                            Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::UUID })
+                        } else if path.segments.len() == 3
+                            && path.segments[0].ident == "std"
+                            && path.segments[1].ident == "string"
+                            && path.segments[2].ident == "String" {
+                            Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::Varchar })
+                        } else if path.segments.len() == 1
+                            && path.segments[0].ident == "DateTime" {
+                            Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::TimestampWithTimeZone })
+                        } else if path.segments.len() == 2
+                            && path.segments[0].ident == "chrono"
+                            && path.segments[1].ident == "DateTime" {
+                            Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::TimestampWithTimeZone })
+                        } else if path.segments.len() == 1
+                            && path.segments[0].ident == "BigDecimal" {
+                            Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::Decimal { p: 38, s: 10 } })
+                        } else if path.segments.len() == 2
+                            && path.segments[0].ident == "bigdecimal"
+                            && path.segments[1].ident == "BigDecimal" {
+                            Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::Decimal { p: 38, s: 10 } })
+                        } else if path.segments.len() == 1
+                            && path.segments[0].ident == "Decimal" {
+                            Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::Decimal { p: 38, s: 10 } })
+                        } else if path.segments.len() == 2
+                            && path.segments[0].ident == "rust_decimal"
+                            && path.segments[1].ident == "Decimal" {
+                            Ok(quote! { ::teo::teo_column_type::duckdb::ColumnType::Decimal { p: 38, s: 10 } })
+                        } else {
+                            Err(Error::new(ty.span(), "teo(duckdb): Can't figure out default column type."))
+                        }
+                    }
+                }
+            },
+            _ => Err(Error::new(ty.span(), "teo(duckdb): Can't figure out default column type.")),
+        }
+    }
+
+    fn to_token_stream(&self) -> TokenStream {
+        match self {
+            ColumnType::BigInt => { quote! { ::teo::teo_column_type::duckdb::ColumnType::BigInt } },
+            ColumnType::Bit => { quote! { ::teo::teo_column_type::duckdb::ColumnType::Bit } },
+            ColumnType::Blob => { quote! { ::teo::teo_column_type::duckdb::ColumnType::Blob } },
+            ColumnType::Boolean => { quote! { ::teo::teo_column_type::duckdb::ColumnType::Boolean } },
+            ColumnType::Date => { quote! { ::teo::teo_column_type::duckdb::ColumnType::Date } },
+            ColumnType::Decimal { p, s } => { quote! { ::teo::teo_column_type::duckdb::ColumnType::Decimal { p: #p, s: #s } } },
+            ColumnType::Double => { quote! { ::teo::teo_column_type::duckdb::ColumnType::Double } },
+            ColumnType::Float => { quote! { ::teo::teo_column_type::duckdb::ColumnType::Float } },
+            ColumnType::HugeInt => { quote! { ::teo::teo_column_type::duckdb::ColumnType::HugeInt } },
+            ColumnType::Integer => { quote! { ::teo::teo_column_type::duckdb::ColumnType::Integer } },
+            ColumnType::Interval => { quote! { ::teo::teo_column_type::duckdb::ColumnType::Interval } },
+            ColumnType::JSON => { quote! { ::teo::teo_column_type::duckdb::ColumnType::JSON } },
+            ColumnType::SmallInt => { quote! { ::teo::teo_column_type::duckdb::ColumnType::SmallInt } },
+            ColumnType::Time => { quote! { ::teo::teo_column_type::duckdb::ColumnType::Time } },
+            ColumnType::Timestamp => { quote! { ::teo::teo_column_type::duckdb::ColumnType::Timestamp } },
+            ColumnType::TimestampWithTimeZone => { quote! { ::teo::teo_column_type::duckdb::ColumnType::TimestampWithTimeZone } },
+            ColumnType::TinyInt => { quote! { ::teo::teo_column_type::duckdb::ColumnType::TinyInt } },
+            ColumnType::UBigInt => { quote! { ::teo::teo_column_type::duckdb::ColumnType::UBigInt } },
+            ColumnType::UHugeInt => { quote! { ::teo::teo_column_type::duckdb::ColumnType::UHugeInt } },
+            ColumnType::UInteger => { quote! { ::teo::teo_column_type::duckdb::ColumnType::UInteger } },
+            ColumnType::USmallInt => { quote! { ::teo::teo_column_type::duckdb::ColumnType::USmallInt } },
+            ColumnType::UTinyInt => { quote! { ::teo::teo_column_type::duckdb::ColumnType::UTinyInt } },
+            ColumnType::UUID => { quote! { ::teo::teo_column_type::duckdb::ColumnType::UUID } },
+            ColumnType::Varchar => { quote! { ::teo::teo_column_type::duckdb::ColumnType::Varchar } },
+        }
+    }
+}
@@ -1,4 +1,6 @@
 pub(in crate::entity) mod extended_column_type;
+#[cfg(feature = "duckdb")]
+pub(in crate::entity) mod duckdb;
 #[cfg(feature = "mongo")]
 pub(in crate::entity) mod mongo;
 #[cfg(feature = "mysql")]
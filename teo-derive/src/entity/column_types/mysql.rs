@@ -5,6 +5,7 @@ use teo_column_type::mysql::ColumnType;
 use crate::{entity::column_types::extended_column_type::ExtendedColumnType, utils::extract_first_path_argument};
 
 impl ExtendedColumnType for ColumnType {
+    #[allow(clippy::if_same_then_else)]
     fn default_column_type(ty: &Type) -> Result<TokenStream> {
         match ty {
             Type::Array(_)
@@ -71,6 +72,20 @@ impl ExtendedColumnType for ColumnType {
                             && path.segments[0].ident == "chrono"
                             && path.segments[1].ident == "DateTime" {
                             Ok(quote! { ::teo::teo_column_type::mysql::ColumnType::Timestamp { fsp: 6 } })
+                        } else if path.segments.len() == 1
+                            && path.segments[0].ident == "BigDecimal" {
+                            Ok(quote! { ::teo::teo_column_type::mysql::ColumnType::Decimal { m: 65, d: 30 } })
+                        } else if path.segments.len() == 2
+                            && path.segments[0].ident == "bigdecimal"
+                            && path.segments[1].ident == "BigDecimal" {
+                            Ok(quote! { ::teo::teo_column_type::mysql::ColumnType::Decimal { m: 65, d: 30 } })
+                        } else if path.segments.len() == 1
+                            && path.segments[0].ident == "Decimal" {
+                            Ok(quote! { ::teo::teo_column_type::mysql::ColumnType::Decimal { m: 65, d: 30 } })
+                        } else if path.segments.len() == 2
+                            && path.segments[0].ident == "rust_decimal"
+                            && path.segments[1].ident == "Decimal" {
+                            Ok(quote! { ::teo::teo_column_type::mysql::ColumnType::Decimal { m: 65, d: 30 } })
                         } else {
                             Err(Error::new(ty.span(), "teo(mysql): Can't figure out default column type."))
                         }
@@ -118,6 +133,9 @@ impl ExtendedColumnType for ColumnType {
             ColumnType::Multipolygon => { quote! { ::teo::teo_column_type::mysql::ColumnType::Multipolygon } },
             ColumnType::GeometryCollection => { quote! { ::teo::teo_column_type::mysql::ColumnType::GeometryCollection } },
             ColumnType::JSON => { quote! { ::teo::teo_column_type::mysql::ColumnType::JSON } },
+            ColumnType::UUID => { quote! { ::teo::teo_column_type::mysql::ColumnType::UUID } },
+            ColumnType::Inet4 => { quote! { ::teo::teo_column_type::mysql::ColumnType::Inet4 } },
+            ColumnType::Inet6 => { quote! { ::teo::teo_column_type::mysql::ColumnType::Inet6 } },
         }
     }
 }
@@ -5,6 +5,7 @@ use teo_column_type::postgres::ColumnType;
 use crate::{entity::column_types::extended_column_type::ExtendedColumnType, utils::extract_first_path_argument};
 
 impl ExtendedColumnType for ColumnType {
+    #[allow(clippy::if_same_then_else)]
     fn default_column_type(ty: &Type) -> Result<TokenStream> {
         match ty {
             Type::Array(_)
@@ -66,11 +67,25 @@ impl ExtendedColumnType for ColumnType {
                             Ok(quote! { ::teo::teo_column_type::postgres::ColumnType::Text })
                         } else if path.segments.len() == 1
                             && path.segments[0].ident == "DateTime" {
-                            Ok(quote! { ::teo::teo_column_type::postgres::ColumnType::TimestampWithTimeZone { n: 6 } })
+                            Ok(quote! { ::teo::teo_column_type::postgres::ColumnType::TimestampWithTimeZone { p: 6 } })
                         } else if path.segments.len() == 2
                             && path.segments[0].ident == "chrono"
                             && path.segments[1].ident == "DateTime" {
-                            Ok(quote! { ::teo::teo_column_type::postgres::ColumnType::TimestampWithTimeZone { n: 6 } })
+                            Ok(quote! { ::teo::teo_column_type::postgres::ColumnType::TimestampWithTimeZone { p: 6 } })
+                        } else if path.segments.len() == 1
+                            && path.segments[0].ident == "BigDecimal" {
+                            Ok(quote! { ::teo::teo_column_type::postgres::ColumnType::Numeric { p: None, s: None } })
+                        } else if path.segments.len() == 2
+                            && path.segments[0].ident == "bigdecimal"
+                            && path.segments[1].ident == "BigDecimal" {
+                            Ok(quote! { ::teo::teo_column_type::postgres::ColumnType::Numeric { p: None, s: None } })
+                        } else if path.segments.len() == 1
+                            && path.segments[0].ident == "Decimal" {
+                            Ok(quote! { ::teo::teo_column_type::postgres::ColumnType::Numeric { p: None, s: None } })
+                        } else if path.segments.len() == 2
+                            && path.segments[0].ident == "rust_decimal"
+                            && path.segments[1].ident == "Decimal" {
+                            Ok(quote! { ::teo::teo_column_type::postgres::ColumnType::Numeric { p: None, s: None } })
                         } else {
                             Err(Error::new(ty.span(), "teo(postgres): Can't figure out default column type."))
                         }
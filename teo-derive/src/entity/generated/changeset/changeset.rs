@@ -0,0 +1,45 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Result;
+use crate::entity::types::EntityDef;
+
+pub(in crate::entity) fn generate_changeset(opts: EntityDef) -> Result<TokenStream> {
+    let vis = opts.vis.clone();
+    let changeset_ident = opts.changeset_ident();
+    let fields = opts.data.take_struct().unwrap().fields;
+    let mut members: Vec<TokenStream> = Vec::new();
+    let mut changes: Vec<TokenStream> = Vec::new();
+    for field_def in &fields {
+        if let Some(ident) = &field_def.ident {
+            if field_def.relation.is_some() {
+                continue;
+            }
+            let column_name = field_def.column_name.clone().unwrap_or(ident.to_string());
+            let field_vis = &field_def.vis;
+            let ty = &field_def.ty;
+            members.push(quote! {
+                #field_vis #ident: ::teo::types::Change<#ty>,
+            });
+            changes.push(quote! {
+                if let ::teo::types::Change::Set(value) = &self.#ident {
+                    changes.push((#column_name, ::teo::types::ToValue::to_value(value)));
+                }
+            });
+        }
+    }
+    Ok(quote! {
+        #[derive(Default)]
+        #[allow(dead_code)]
+        #vis struct #changeset_ident {
+            #( #members )*
+        }
+
+        impl ::teo::types::Changeset for #changeset_ident {
+            fn changes(&self) -> Vec<(&'static str, ::teo::types::Value)> {
+                let mut changes = Vec::new();
+                #( #changes )*
+                changes
+            }
+        }
+    })
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+pub(crate) mod changeset;
+
+pub(in crate::entity) use changeset::generate_changeset;
@@ -0,0 +1,87 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Result};
+use crate::entity::types::{EntityDef, FieldDef, RelationShape};
+
+pub(in crate::entity) fn generate_impl_columns(opts: EntityDef) -> Result<TokenStream> {
+    let struct_ident = opts.ident.clone();
+    let fields = opts.data.as_ref().take_struct().unwrap().fields;
+    let mut accessors: Vec<TokenStream> = Vec::new();
+    for field_def in &fields {
+        if let Some(ident) = &field_def.ident {
+            let vis = &field_def.vis;
+            if field_def.relation.is_some() {
+                let include = gen_include(&opts, field_def, &fields)?;
+                accessors.push(quote! {
+                    #vis fn #ident() -> #include
+                });
+                continue;
+            }
+            let column_name = field_def.column_name.clone().unwrap_or(ident.to_string());
+            let ty = &field_def.ty;
+            accessors.push(quote! {
+                #vis const fn #ident() -> ::teo::query::Column<Self, #ty> {
+                    ::teo::query::Column::new(#column_name)
+                }
+            });
+        }
+    }
+    Ok(quote! {
+        #[allow(dead_code)]
+        impl #struct_ident {
+            #( #accessors )*
+        }
+    })
+}
+
+fn gen_include(opts: &EntityDef, field_def: &FieldDef, fields: &[&FieldDef]) -> Result<TokenStream> {
+    let ident = field_def.ident.as_ref().unwrap();
+    let relation = field_def.relation.clone().unwrap_or_default();
+    let (shape, target) = field_def.relation_target()?;
+    let assign = match shape {
+        RelationShape::Many => quote! { entity.#ident = ::teo::types::Relation::Loaded(rows); },
+        RelationShape::Optional => quote! { entity.#ident = ::teo::types::Relation::Loaded(rows.into_iter().next()); },
+        RelationShape::One => quote! {
+            if let Some(row) = rows.into_iter().next() {
+                entity.#ident = ::teo::types::Relation::Loaded(row);
+            }
+        },
+    };
+    let link = if relation.many_to_many {
+        if shape != RelationShape::Many {
+            return Err(Error::new(ident.span(), "teo(relation): many to many relations are `Relation<Vec<T>>`."));
+        }
+        if !fields.iter().any(|f| f.primary) {
+            return Err(Error::new(ident.span(), "teo(relation): many to many relations need a primary key."));
+        }
+        let (table, source_is_a) = opts.join_table(ident, &target)?;
+        let (source, target_column) = if source_is_a { ("A", "B") } else { ("B", "A") };
+        quote! { ::teo::query::Include::join(#table, #source, #target_column, assign) }
+    } else {
+        let (Some(relation_fields), Some(references)) = (&relation.fields, &relation.references) else {
+            return Err(Error::new(ident.span(), "teo(relation): expect `fields` and `references`, or `many_to_many`."));
+        };
+        if relation_fields.0.is_empty() || relation_fields.0.len() != references.0.len() {
+            return Err(Error::new(ident.span(), "teo(relation): `fields` and `references` should have the same length."));
+        }
+        let mut columns = Vec::new();
+        for field in &relation_fields.0 {
+            let Some(field_def) = fields.iter().find(|f| f.ident.as_ref() == Some(field) && f.relation.is_none()) else {
+                return Err(Error::new(field.span(), "teo(relation): unknown field."));
+            };
+            columns.push(field_def.column_name.clone().unwrap_or(field.to_string()));
+        }
+        let references = &references.0;
+        quote! {
+            ::teo::query::Include::columns(vec![#( #columns ),*], vec![#( <#target>::#references().name() ),*], assign)
+        }
+    };
+    Ok(quote! {
+        ::teo::query::Include<Self, #target> {
+            let assign: fn(&mut Self, Vec<#target>) = |entity, rows| {
+                #assign
+            };
+            #link
+        }
+    })
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+pub(crate) mod impl_columns;
+
+pub(in crate::entity) use impl_columns::generate_impl_columns;
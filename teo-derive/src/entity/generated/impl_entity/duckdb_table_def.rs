@@ -0,0 +1 @@
+make_table_def!(gen_duckdb_table_def, duckdb_table_def, duckdb_join_table_defs, ::teo::teo_column_type::duckdb::ColumnType, duckdb_column_type, teo_column_type::duckdb::ColumnType, None, None, false);
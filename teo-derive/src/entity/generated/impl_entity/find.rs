@@ -0,0 +1,61 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Result};
+use crate::entity::types::EntityDef;
+
+pub(in crate::entity) fn gen_find(opts: EntityDef) -> Result<TokenStream> {
+    let soft_delete_column = opts.soft_delete_column();
+    let fields = opts.data.take_struct().unwrap().fields;
+    let mut primary: Option<(String, syn::Ident, syn::Type)> = None;
+    let mut column_arms = Vec::new();
+    for field_def in &fields {
+        if let Some(ident) = &field_def.ident {
+            if field_def.relation.is_some() {
+                continue;
+            }
+            let column_name = field_def.column_name.clone().unwrap_or(ident.to_string());
+            if Some(column_name.as_str()) == soft_delete_column {
+                return Err(Error::new(ident.span(), "teo(soft_delete): the column is added by the entity."));
+            }
+            column_arms.push(quote! { #column_name => Some(::teo::types::ToValue::to_value(&self.#ident)), });
+        }
+        if let Some(ident) = &field_def.ident && field_def.primary {
+            if primary.is_some() {
+                return Err(Error::new(ident.span(), "teo: only one field can be primary."));
+            }
+            let column_name = field_def.column_name.clone().unwrap_or(ident.to_string());
+            primary = Some((column_name, ident.clone(), field_def.ty.clone()));
+        }
+    }
+    let (primary_key, primary_column, primary_value) = if let Some((column_name, ident, ty)) = primary {
+        (quote! { #ty }, quote! { Some(#column_name) }, quote! { ::teo::types::ToValue::to_value(&self.#ident) })
+    } else {
+        (quote! { ::teo::types::Value }, quote! { None }, quote! { ::teo::types::Value::Null })
+    };
+    let soft_delete_column = match soft_delete_column {
+        Some(column) => quote! { Some(#column) },
+        None => quote! { None },
+    };
+    Ok(quote! {
+        type PrimaryKey = #primary_key;
+
+        fn primary_column() -> Option<&'static str> {
+            #primary_column
+        }
+
+        fn primary_value(&self) -> ::teo::types::Value {
+            #primary_value
+        }
+
+        fn soft_delete_column() -> Option<&'static str> {
+            #soft_delete_column
+        }
+
+        fn column_value(&self, column: &str) -> Option<::teo::types::Value> {
+            match column {
+                #(#column_arms)*
+                _ => None,
+            }
+        }
+    })
+}
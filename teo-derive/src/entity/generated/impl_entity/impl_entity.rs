@@ -1,7 +1,9 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Result;
-use crate::entity::types::EntityDef;
+use crate::entity::{generated::impl_entity::{find::gen_find, insert::gen_insert, update::gen_update}, types::EntityDef};
+#[cfg(feature = "duckdb")]
+use crate::entity::generated::impl_entity::duckdb_table_def::gen_duckdb_table_def;
 #[cfg(feature = "mongo")]
 use crate::entity::generated::impl_entity::mongo_table_def::gen_mongo_table_def;
 #[cfg(feature = "mysql")]
@@ -13,6 +15,9 @@ use crate::entity::generated::impl_entity::sqlite_table_def::gen_sqlite_table_de
 
 pub(in crate::entity) fn generate_impl_entity(opts: EntityDef) -> Result<TokenStream> {
     let struct_ident = opts.ident.clone();
+    let insert = gen_insert(opts.clone())?;
+    let find = gen_find(opts.clone())?;
+    let update = gen_update(opts.clone())?;
     #[cfg(feature = "mongo")]
     let mongo_table_def = gen_mongo_table_def(opts.clone())?;
     #[cfg(not(feature = "mongo"))]
@@ -29,12 +34,20 @@ pub(in crate::entity) fn generate_impl_entity(opts: EntityDef) -> Result<TokenSt
     let sqlite_table_def = gen_sqlite_table_def(opts.clone())?;
     #[cfg(not(feature = "sqlite"))]
     let sqlite_table_def = quote! { };
+    #[cfg(feature = "duckdb")]
+    let duckdb_table_def = gen_duckdb_table_def(opts.clone())?;
+    #[cfg(not(feature = "duckdb"))]
+    let duckdb_table_def = quote! { };
     Ok(quote! {
         impl ::teo::types::Entity for #struct_ident {
+            #insert
+            #find
+            #update
             #mongo_table_def
             #mysql_table_def
             #postgres_table_def
             #sqlite_table_def
+            #duckdb_table_def
         }
     })
 }
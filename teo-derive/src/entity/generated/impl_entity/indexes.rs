@@ -0,0 +1,72 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Result};
+use crate::entity::types::{EntityDef, IndexColumnOrder};
+
+// `predicate_quote` is the dialect's identifier quote, `None` when it has no partial indexes.
+pub(in crate::entity) fn gen_indexes(opts: &EntityDef, predicate_quote: Option<char>) -> Result<TokenStream> {
+    let predicate = |unique: bool| match (opts.soft_delete_column(), predicate_quote) {
+        (Some(column), Some(q)) if unique && opts.partial_unique() => {
+            let predicate = format!("{q}{column}{q} IS NULL");
+            quote! { Some(std::borrow::Cow::Borrowed(#predicate)) }
+        },
+        _ => quote! { None },
+    };
+    let table_name = opts.table_name();
+    let fields = opts.data.as_ref().take_struct().unwrap().fields;
+    let mut indexes: Vec<TokenStream> = Vec::new();
+    for field_def in &fields {
+        if let Some(ident) = &field_def.ident && field_def.relation.is_none() && (field_def.unique || field_def.index) {
+            let column_name = field_def.column_name.clone().unwrap_or(ident.to_string());
+            let unique = field_def.unique;
+            let name = format!("{table_name}_{column_name}_{}", if unique { "key" } else { "idx" });
+            let predicate = predicate(unique);
+            indexes.push(quote! {
+                indexes.push(::teo::migration::IndexDef {
+                    name: std::borrow::Cow::Borrowed(#name),
+                    columns: vec![::teo::migration::IndexColumnDef {
+                        name: std::borrow::Cow::Borrowed(#column_name),
+                        order: ::teo::types::SortOrder::Asc,
+                    }],
+                    unique: #unique,
+                    predicate: #predicate,
+                });
+            });
+        }
+    }
+    for index_def in &opts.indexes {
+        let mut column_names = Vec::new();
+        let mut columns = Vec::new();
+        for column in &index_def.columns {
+            let Some(field_def) = fields.iter().find(|f| f.ident.as_ref() == Some(&column.name)) else {
+                return Err(Error::new(column.name.span(), "teo: unknown field in index."));
+            };
+            let column_name = field_def.column_name.clone().unwrap_or(column.name.to_string());
+            let order = match column.order.unwrap_or_default() {
+                IndexColumnOrder::Asc => quote! { ::teo::types::SortOrder::Asc },
+                IndexColumnOrder::Desc => quote! { ::teo::types::SortOrder::Desc },
+            };
+            columns.push(quote! {
+                ::teo::migration::IndexColumnDef {
+                    name: std::borrow::Cow::Borrowed(#column_name),
+                    order: #order,
+                }
+            });
+            column_names.push(column_name);
+        }
+        let unique = index_def.unique;
+        let name = index_def.name.clone().unwrap_or_else(|| {
+            format!("{table_name}_{}_{}", column_names.join("_"), if unique { "key" } else { "idx" })
+        });
+        let predicate = predicate(unique);
+        indexes.push(quote! {
+            indexes.push(::teo::migration::IndexDef {
+                name: std::borrow::Cow::Borrowed(#name),
+                columns: vec![#( #columns ),*],
+                unique: #unique,
+                predicate: #predicate,
+            });
+        });
+    }
+    Ok(quote! { #( #indexes )* })
+}
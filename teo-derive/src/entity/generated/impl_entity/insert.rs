@@ -0,0 +1,98 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Result};
+use crate::{entity::types::EntityDef, utils::is_std_option};
+
+pub(in crate::entity) fn gen_insert(opts: EntityDef) -> Result<TokenStream> {
+    let table_name = opts.table_name();
+    let fields = opts.data.take_struct().unwrap().fields;
+    let mut values: Vec<TokenStream> = Vec::new();
+    let mut auto_increment: Option<(String, syn::Ident)> = None;
+    let mut timestamps: Vec<TokenStream> = Vec::new();
+    for field_def in &fields {
+        if let Some(ident) = &field_def.ident {
+            if field_def.relation.is_some() {
+                continue;
+            }
+            let column_name = field_def.column_name.clone().unwrap_or(ident.to_string());
+            if field_def.auto_increment {
+                if auto_increment.is_some() {
+                    return Err(Error::new(ident.span(), "teo: only one field can be auto increment."));
+                }
+                auto_increment = Some((column_name, ident.clone()));
+                continue;
+            }
+            if field_def.is_timestamp()? {
+                timestamps.push(quote! {
+                    #column_name => self.#ident = ::teo::types::FromValue::from_value(value.clone())?,
+                });
+                values.push(quote! {
+                    values.push((#column_name, now.clone()));
+                });
+                continue;
+            }
+            if field_def.default.is_some() && is_std_option(&field_def.ty) {
+                values.push(quote! {
+                    if self.#ident.is_some() {
+                        values.push((#column_name, ::teo::types::ToValue::to_value(&self.#ident)));
+                    }
+                });
+            } else {
+                values.push(quote! {
+                    values.push((#column_name, ::teo::types::ToValue::to_value(&self.#ident)));
+                });
+            }
+        }
+    }
+    let (auto_increment_column, set_auto_increment_value) = if let Some((column_name, ident)) = auto_increment {
+        (quote! { Some(#column_name) }, quote! {
+            self.#ident = ::teo::types::FromValue::from_value(value)?;
+            Ok(())
+        })
+    } else {
+        (quote! { None }, quote! {
+            let _ = value;
+            Ok(())
+        })
+    };
+    let (now, set_timestamps) = if timestamps.is_empty() {
+        (quote! { }, quote! {
+            let _ = values;
+            Ok(())
+        })
+    } else {
+        (quote! { let now = ::teo::types::Value::now(); }, quote! {
+            for (column, value) in values {
+                match *column {
+                    #( #timestamps )*
+                    _ => (),
+                }
+            }
+            Ok(())
+        })
+    };
+    Ok(quote! {
+        fn table_name() -> &'static str {
+            #table_name
+        }
+
+        fn insert_values(&self) -> Vec<(&'static str, ::teo::types::Value)> {
+            let mut values = Vec::new();
+            #now
+            #( #values )*
+            values
+        }
+
+        fn auto_increment_column() -> Option<&'static str> {
+            #auto_increment_column
+        }
+
+        fn set_auto_increment_value(&mut self, value: ::teo::types::Value) -> ::teo::Result<()> {
+            #set_auto_increment_value
+        }
+
+        fn set_timestamps(&mut self, values: &[(&'static str, ::teo::types::Value)]) -> ::teo::Result<()> {
+            #set_timestamps
+        }
+    })
+}
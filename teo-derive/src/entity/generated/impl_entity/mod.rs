@@ -1,7 +1,14 @@
 #[macro_use]
 mod table_def;
 
+#[allow(clippy::module_inception)]
 pub(crate) mod impl_entity;
+mod insert;
+mod find;
+mod update;
+mod indexes;
+#[cfg(feature = "duckdb")]
+mod duckdb_table_def;
 #[cfg(feature = "mongo")]
 mod mongo_table_def;
 #[cfg(feature = "mysql")]
@@ -1 +1 @@
-make_table_def!(gen_mongo_table_def, mongo_table_def, ::teo::teo_column_type::mongo::ColumnType, mongo_column_type);
+make_table_def!(gen_mongo_table_def, mongo_table_def, mongo_join_table_defs, ::teo::teo_column_type::mongo::ColumnType, mongo_column_type, teo_column_type::mongo::ColumnType, None, None, false);
@@ -1 +1 @@
-make_table_def!(gen_mysql_table_def, mysql_table_def, ::teo::teo_column_type::mysql::ColumnType, mysql_column_type);
+make_table_def!(gen_mysql_table_def, mysql_table_def, mysql_join_table_defs, ::teo::teo_column_type::mysql::ColumnType, mysql_column_type, teo_column_type::mysql::ColumnType, None, Some("current_timestamp(6)"), true);
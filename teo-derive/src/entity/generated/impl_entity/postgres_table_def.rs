@@ -1 +1 @@
-make_table_def!(gen_postgres_table_def, postgres_table_def, ::teo::teo_column_type::postgres::ColumnType, postgres_column_type);
+make_table_def!(gen_postgres_table_def, postgres_table_def, postgres_join_table_defs, ::teo::teo_column_type::postgres::ColumnType, postgres_column_type, teo_column_type::postgres::ColumnType, Some('"'), Some("now()"), false);
@@ -1 +1 @@
-make_table_def!(gen_sqlite_table_def, sqlite_table_def, ::teo::teo_column_type::sqlite::ColumnType, sqlite_column_type);
+make_table_def!(gen_sqlite_table_def, sqlite_table_def, sqlite_join_table_defs, ::teo::teo_column_type::sqlite::ColumnType, sqlite_column_type, teo_column_type::sqlite::ColumnType, Some('"'), Some("(strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))"), false);
@@ -1,42 +1,120 @@
 macro_rules! make_table_def {
-    ($name:ident, $fn_name:ident, $return_type:path, $config_name:ident) => {
+    ($name:ident, $fn_name:ident, $join_fn_name:ident, $return_type:path, $config_name:ident, $column_type:path, $predicate_quote:expr, $timestamp_default:expr, $on_update:expr) => {
         pub(in crate::entity) fn $name(
             opts: crate::entity::types::EntityDef,
         ) -> ::syn::Result<::proc_macro2::TokenStream> {
             let table_name = opts.table_name();
-            let fields = opts.data.take_struct().unwrap().fields;
+            let indexes = super::indexes::gen_indexes(&opts, $predicate_quote)?;
+            let fields = opts.data.as_ref().take_struct().unwrap().fields;
             let mut columns: Vec<::proc_macro2::TokenStream> = Vec::new();
+            let mut foreign_keys: Vec<::proc_macro2::TokenStream> = Vec::new();
+            let mut join_table_defs: Vec<::proc_macro2::TokenStream> = Vec::new();
             for field_def in &fields {
                 if let Some(ident) = &field_def.ident {
+                    if let Some(relation) = &field_def.relation {
+                        let (shape, target) = field_def.relation_target()?;
+                        if relation.many_to_many {
+                            let (join_table, source_is_a) = opts.join_table(ident, &target)?;
+                            let (a, b) = if source_is_a {
+                                (::quote::quote! { Self }, ::quote::quote! { #target })
+                            } else {
+                                (::quote::quote! { #target }, ::quote::quote! { Self })
+                            };
+                            join_table_defs.push(::quote::quote! {
+                                table_defs.push(::teo::migration::join_table_def(
+                                    #join_table,
+                                    <#a as ::teo::types::Entity>::$fn_name(),
+                                    <#a as ::teo::types::Entity>::primary_column().unwrap_or_default(),
+                                    <#b as ::teo::types::Entity>::$fn_name(),
+                                    <#b as ::teo::types::Entity>::primary_column().unwrap_or_default(),
+                                ));
+                            });
+                        } else if shape != crate::entity::types::RelationShape::Many &&
+                                let (Some(relation_fields), Some(references)) = (&relation.fields, &relation.references) {
+                            let mut relation_columns = Vec::new();
+                            for field in &relation_fields.0 {
+                                let column_name = fields.iter()
+                                    .find(|f| f.ident.as_ref() == Some(field))
+                                    .and_then(|f| f.column_name.clone())
+                                    .unwrap_or(field.to_string());
+                                relation_columns.push(column_name);
+                            }
+                            let name = format!("{table_name}_{}_fkey", relation_columns.join("_"));
+                            let references = &references.0;
+                            foreign_keys.push(::quote::quote! {
+                                foreign_keys.push(::teo::migration::ForeignKeyDef {
+                                    name: std::borrow::Cow::Borrowed(#name),
+                                    columns: vec![#( std::borrow::Cow::Borrowed(#relation_columns) ),*],
+                                    referenced_table: std::borrow::Cow::Borrowed(<#target as ::teo::types::Entity>::table_name()),
+                                    referenced_columns: vec![#( std::borrow::Cow::Borrowed(<#target>::#references().name()) ),*],
+                                });
+                            });
+                        }
+                        continue;
+                    }
                     let column_name = field_def.column_name.clone().unwrap_or(ident.to_string());
                     let nullable = crate::utils::is_std_option(&field_def.ty);
+                    let auto_increment = field_def.auto_increment;
                     let ty = field_def.$config_name()?;
+                    let timestamp_default: Option<&str> = if field_def.is_timestamp()? { $timestamp_default } else { None };
                     let default = if let Some(default) = &field_def.default {
                         Some(::quote::quote! { Some(std::borrow::Cow::Borrowed(#default)) })
+                    } else if let Some(default) = timestamp_default {
+                        Some(::quote::quote! { Some(std::borrow::Cow::Borrowed(#default)) })
                     } else {
                         Some(::quote::quote! { None })
                     };
+                    let on_update = match timestamp_default {
+                        Some(default) if field_def.updated_at && $on_update => ::quote::quote! { Some(std::borrow::Cow::Borrowed(#default)) },
+                        _ => ::quote::quote! { None },
+                    };
                     columns.push(::quote::quote! {
                         columns.push(::teo::migration::ColumnDef {
                             name: std::borrow::Cow::Borrowed(#column_name),
                             ty: #ty,
                             nullable: #nullable,
                             default: #default,
+                            auto_increment: #auto_increment,
+                            on_update: #on_update,
                         });
                     });
                 }
             }
+            if let Some(column_name) = opts.soft_delete_column() {
+                use crate::entity::column_types::extended_column_type::ExtendedColumnType;
+                let ty = <$column_type>::default_column_type(&::syn::parse_quote! { chrono::DateTime<chrono::Utc> })?;
+                columns.push(::quote::quote! {
+                    columns.push(::teo::migration::ColumnDef {
+                        name: std::borrow::Cow::Borrowed(#column_name),
+                        ty: #ty,
+                        nullable: true,
+                        default: None,
+                        auto_increment: false,
+                        on_update: None,
+                    });
+                });
+            }
             Ok(::quote::quote! {
                 fn $fn_name() -> ::teo::migration::TableDef<$return_type> {
                     let mut columns = Vec::new();
                     #( #columns )*
                     let mut indexes = Vec::new();
+                    #indexes
+                    let mut foreign_keys = Vec::new();
+                    #( #foreign_keys )*
                     ::teo::migration::TableDef {
                         name: std::borrow::Cow::Borrowed(#table_name),
                         columns,
-                        indexes
+                        indexes,
+                        foreign_keys,
                     }
                 }
+
+                fn $join_fn_name() -> Vec<::teo::migration::TableDef<$return_type>> {
+                    let mut table_defs = Vec::new();
+                    #( #join_table_defs )*
+                    table_defs
+                }
             })
         }
     };
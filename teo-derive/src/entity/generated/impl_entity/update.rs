@@ -0,0 +1,51 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Result};
+use crate::entity::types::EntityDef;
+
+pub(in crate::entity) fn gen_update(opts: EntityDef) -> Result<TokenStream> {
+    let changeset_ident = opts.changeset_ident();
+    let fields = opts.data.take_struct().unwrap().fields;
+    let mut values: Vec<TokenStream> = Vec::new();
+    let mut updated_at: Option<String> = None;
+    for field_def in &fields {
+        if let Some(ident) = &field_def.ident {
+            if field_def.relation.is_some() {
+                continue;
+            }
+            if field_def.primary {
+                continue;
+            }
+            let column_name = field_def.column_name.clone().unwrap_or(ident.to_string());
+            if field_def.is_timestamp()? {
+                if field_def.updated_at {
+                    if updated_at.is_some() {
+                        return Err(Error::new(ident.span(), "teo: only one field can be updated_at."));
+                    }
+                    updated_at = Some(column_name);
+                }
+                continue;
+            }
+            values.push(quote! {
+                values.push((#column_name, ::teo::types::ToValue::to_value(&self.#ident)));
+            });
+        }
+    }
+    let updated_at_column = match updated_at {
+        Some(column) => quote! { Some(#column) },
+        None => quote! { None },
+    };
+    Ok(quote! {
+        type Changeset = #changeset_ident;
+
+        fn update_values(&self) -> Vec<(&'static str, ::teo::types::Value)> {
+            let mut values = Vec::new();
+            #( #values )*
+            values
+        }
+
+        fn updated_at_column() -> Option<&'static str> {
+            #updated_at_column
+        }
+    })
+}
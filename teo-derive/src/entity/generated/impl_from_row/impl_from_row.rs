@@ -0,0 +1,37 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Result;
+use crate::{entity::types::EntityDef, utils::is_std_option};
+
+pub(in crate::entity) fn generate_impl_from_row(opts: EntityDef) -> Result<TokenStream> {
+    let struct_ident = opts.ident.clone();
+    let fields = opts.data.take_struct().unwrap().fields;
+    let mut assignments: Vec<TokenStream> = Vec::new();
+    for field_def in &fields {
+        if let Some(ident) = &field_def.ident {
+            let column_name = field_def.column_name.clone().unwrap_or(ident.to_string());
+            if field_def.relation.is_some() {
+                assignments.push(quote! {
+                    #ident: ::teo::types::Relation::NotLoaded,
+                });
+            } else if is_std_option(&field_def.ty) {
+                assignments.push(quote! {
+                    #ident: ::teo::types::Row::get_or_null(row, #column_name)?,
+                });
+            } else {
+                assignments.push(quote! {
+                    #ident: ::teo::types::Row::get(row, #column_name)?,
+                });
+            }
+        }
+    }
+    Ok(quote! {
+        impl ::teo::types::FromRow for #struct_ident {
+            fn from_row<R>(row: &R) -> ::teo::Result<Self> where R: ::teo::types::Row + ?Sized {
+                Ok(Self {
+                    #( #assignments )*
+                })
+            }
+        }
+    })
+}
@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+pub(crate) mod impl_from_row;
+
+pub(in crate::entity) use impl_from_row::generate_impl_from_row;
@@ -1,3 +1,9 @@
 mod impl_entity;
+mod impl_from_row;
+mod impl_columns;
+mod changeset;
 
 pub(in crate::entity) use impl_entity::generate_impl_entity;
+pub(in crate::entity) use impl_from_row::generate_impl_from_row;
+pub(in crate::entity) use impl_columns::generate_impl_columns;
+pub(in crate::entity) use changeset::generate_changeset;
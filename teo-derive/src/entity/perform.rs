@@ -3,7 +3,7 @@ use proc_macro2::TokenStream as TokenStream2;
 use darling::FromDeriveInput;
 use quote::quote;
 use syn::{Result, DeriveInput, parse_macro_input};
-use crate::entity::{generated::generate_impl_entity,  types::EntityDef};
+use crate::entity::{generated::{generate_changeset, generate_impl_columns, generate_impl_entity, generate_impl_from_row}, types::EntityDef};
 
 pub(crate) fn perform(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -14,9 +14,15 @@ pub(crate) fn perform(input: TokenStream) -> TokenStream {
 
 fn _perform(input: DeriveInput) -> Result<TokenStream2> {
     let opts = EntityDef::from_derive_input(&input)?;
-    let impl_entity = generate_impl_entity(opts)?;
+    let impl_entity = generate_impl_entity(opts.clone())?;
+    let impl_from_row = generate_impl_from_row(opts.clone())?;
+    let impl_columns = generate_impl_columns(opts.clone())?;
+    let changeset = generate_changeset(opts)?;
     let output = quote! {
         #impl_entity
+        #impl_from_row
+        #impl_columns
+        #changeset
     };
     Ok(output)
 }
@@ -1,19 +1,15 @@
-use darling::{Error, FromDeriveInput, FromField, FromMeta, Result, ast::Data, util::Ignored};
+use darling::{Error, FromDeriveInput, FromField, FromMeta, Result, ast::Data, util::{Ignored, Override}};
 use proc_macro2::TokenStream;
 use syn::{Attribute, Expr, Ident, Lit, LitStr, Type, Visibility};
+use crate::utils::{extract_first_path_argument, is_std_option, is_std_vec};
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Default)]
 pub(in crate::entity) enum IndexColumnOrder {
+    #[default]
     Asc,
     Desc,
 }
 
-impl Default for IndexColumnOrder {
-    fn default() -> Self {
-        Self::Asc
-    }
-}
-
 impl FromMeta for IndexColumnOrder {
     fn from_string(value: &str) -> Result<Self> {
         Ok(match value {
@@ -26,18 +22,24 @@ impl FromMeta for IndexColumnOrder {
 
 #[derive(FromMeta, Clone)]
 pub(in crate::entity) struct IndexColumnDef {
-    name: Ident,
+    pub(in crate::entity) name: Ident,
     #[darling(default)]
-    order: Option<IndexColumnOrder>
+    pub(in crate::entity) order: Option<IndexColumnOrder>
 }
 
 #[derive(Default, FromMeta, Clone)]
 pub(in crate::entity) struct IndexDef {
-    name: Option<String>,
+    pub(in crate::entity) name: Option<String>,
     #[darling(multiple, rename = "column")]
-    columns: Vec<IndexColumnDef>,
+    pub(in crate::entity) columns: Vec<IndexColumnDef>,
+    #[darling(default)]
+    pub(in crate::entity) unique: bool,
+}
+
+#[derive(Default, FromMeta, Clone)]
+pub(in crate::entity) struct SoftDeleteDef {
     #[darling(default)]
-    unique: bool,
+    pub(in crate::entity) partial_unique: bool,
 }
 
 #[derive(Clone)]
@@ -58,6 +60,38 @@ impl FromMeta for ColumnType {
     }
 }
 
+#[derive(Clone)]
+pub(in crate::entity) struct IdentList(pub(in crate::entity) Vec<Ident>);
+
+impl FromMeta for IdentList {
+    fn from_expr(expr: &Expr) -> Result<Self> {
+        let Expr::Array(array) = expr else {
+            return Err(Error::unexpected_expr_type(expr));
+        };
+        array.elems.iter().map(|elem| match elem {
+            Expr::Path(path) => path.path.get_ident().cloned().ok_or_else(|| Error::unexpected_expr_type(elem)),
+            _ => Err(Error::unexpected_expr_type(elem)),
+        }).collect::<Result<Vec<Ident>>>().map(Self)
+    }
+}
+
+#[derive(Default, FromMeta, Clone)]
+pub(in crate::entity) struct RelationDef {
+    #[darling(default)]
+    pub(in crate::entity) fields: Option<IdentList>,
+    #[darling(default)]
+    pub(in crate::entity) references: Option<IdentList>,
+    #[darling(default)]
+    pub(in crate::entity) many_to_many: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(in crate::entity) enum RelationShape {
+    One,
+    Optional,
+    Many,
+}
+
 #[cfg(feature = "mongo")]
 #[derive(Default, FromMeta, Clone)]
 pub(in crate::entity) struct MongoFieldDef {
@@ -86,8 +120,16 @@ pub(in crate::entity) struct SQLiteFieldDef {
     pub(in crate::entity) column_type: Option<ColumnType>,
 }
 
+#[cfg(feature = "duckdb")]
+#[derive(Default, FromMeta, Clone)]
+pub(in crate::entity) struct DuckDBFieldDef {
+    #[darling(default)]
+    pub(in crate::entity) column_type: Option<ColumnType>,
+}
+
 #[derive(FromField, Clone)]
 #[darling(attributes(teo))]
+#[allow(dead_code)]
 pub(in crate::entity) struct FieldDef {
     pub(in crate::entity) ident: Option<Ident>,
     pub(in crate::entity) ty: Type,
@@ -104,6 +146,12 @@ pub(in crate::entity) struct FieldDef {
     pub(in crate::entity) index: bool,
     #[darling(default)]
     pub(in crate::entity) default: Option<Expr>,
+    #[darling(default)]
+    pub(in crate::entity) created_at: bool,
+    #[darling(default)]
+    pub(in crate::entity) updated_at: bool,
+    #[darling(default)]
+    pub(in crate::entity) relation: Option<RelationDef>,
     #[cfg(feature = "mongo")]
     #[darling(default)]
     pub(in crate::entity) mongo: Option<MongoFieldDef>,
@@ -116,9 +164,51 @@ pub(in crate::entity) struct FieldDef {
     #[cfg(feature = "sqlite")]
     #[darling(default)]
     pub(in crate::entity) sqlite: Option<SQLiteFieldDef>,
+    #[cfg(feature = "duckdb")]
+    #[darling(default)]
+    pub(in crate::entity) duckdb: Option<DuckDBFieldDef>,
 }
 
 impl FieldDef {
+    /// Whether the field is set to the current time when rows are written.
+    pub(in crate::entity) fn is_timestamp(&self) -> syn::Result<bool> {
+        if !self.created_at && !self.updated_at {
+            return Ok(false);
+        }
+        if self.created_at && self.updated_at {
+            return Err(syn::Error::new_spanned(&self.ty, "teo: a field can't be both created_at and updated_at."));
+        }
+        let is_date_time = matches!(&self.ty, Type::Path(path) if path.qself.is_none() && path.path.segments.last().is_some_and(|s| s.ident == "DateTime"));
+        if !is_date_time {
+            let attribute = if self.created_at { "created_at" } else { "updated_at" };
+            return Err(syn::Error::new_spanned(&self.ty, format!("teo({attribute}): expect a chrono `DateTime`.")));
+        }
+        Ok(true)
+    }
+
+    /// The related entity and how many rows a `Relation<T>` field holds.
+    pub(in crate::entity) fn relation_target(&self) -> syn::Result<(RelationShape, Type)> {
+        let invalid = || syn::Error::new_spanned(&self.ty, "teo(relation): expect `Relation<T>`, `Relation<Option<T>>` or `Relation<Vec<T>>`.");
+        let Type::Path(path) = &self.ty else {
+            return Err(invalid());
+        };
+        if path.path.segments.last().is_none_or(|s| s.ident != "Relation") {
+            return Err(invalid());
+        }
+        let target = extract_first_path_argument(&path.path).map_err(|_| invalid())?;
+        let inner = |ty: &Type| match ty {
+            Type::Path(path) => extract_first_path_argument(&path.path).cloned().map_err(|_| invalid()),
+            _ => Err(invalid()),
+        };
+        if is_std_vec(target) {
+            Ok((RelationShape::Many, inner(target)?))
+        } else if is_std_option(target) {
+            Ok((RelationShape::Optional, inner(target)?))
+        } else {
+            Ok((RelationShape::One, target.clone()))
+        }
+    }
+
     #[cfg(feature = "mongo")]
     pub(in crate::entity) fn mongo_column_type(&self) -> syn::Result<TokenStream> {
         if let Some(mongo) = &self.mongo && let Some(column_type) = &mongo.column_type {
@@ -210,16 +300,43 @@ impl FieldDef {
             ColumnType::default_column_type(&self.ty)
         }
     }
+
+    #[cfg(feature = "duckdb")]
+    pub(in crate::entity) fn duckdb_column_type(&self) -> syn::Result<TokenStream> {
+        if let Some(duckdb) = &self.duckdb && let Some(column_type) = &duckdb.column_type {
+            use quote::quote;
+            match column_type {
+                ColumnType::LitStr(lit_str) => {
+                    use std::str::FromStr;
+                    use teo_column_type::duckdb::ColumnType;
+                    use crate::entity::column_types::extended_column_type::ExtendedColumnType;
+                    let column_type = ColumnType::from_str(&lit_str.value()).map_err(|_| {
+                        syn::Error::new(lit_str.span(), "teo(duckdb): invalid column type.")
+                    })?;
+                    Ok(column_type.to_token_stream())
+                },
+                ColumnType::Expr(expr) => Ok(quote! { #expr }),
+            }
+        } else {
+            use teo_column_type::duckdb::ColumnType;
+            use crate::entity::column_types::extended_column_type::ExtendedColumnType;
+            ColumnType::default_column_type(&self.ty)
+        }
+    }
 }
 
 #[derive(FromDeriveInput, Clone)]
 #[darling(attributes(teo), forward_attrs(allow, doc, cfg), supports(struct_named))]
+#[allow(dead_code)]
 pub(in crate::entity) struct EntityDef {
     pub(in crate::entity) ident: Ident,
+    pub(in crate::entity) vis: Visibility,
     pub(in crate::entity) attrs: Vec<Attribute>,
     pub(in crate::entity) table_name: Option<String>,
     #[darling(multiple, rename = "index")]
     pub(in crate::entity) indexes: Vec<IndexDef>,
+    #[darling(default)]
+    pub(in crate::entity) soft_delete: Option<Override<SoftDeleteDef>>,
     pub(in crate::entity) data: Data<Ignored, FieldDef>,
 }
 
@@ -227,4 +344,59 @@ impl EntityDef {
     pub(in crate::entity) fn table_name(&self) -> String {
         self.table_name.clone().unwrap_or(self.ident.to_string())
     }
+
+    /// The column soft deletion timestamps are stored in.
+    pub(in crate::entity) fn soft_delete_column(&self) -> Option<&'static str> {
+        self.soft_delete.as_ref().map(|_| "deleted_at")
+    }
+
+    /// Whether unique indexes only cover rows which aren't soft deleted.
+    pub(in crate::entity) fn partial_unique(&self) -> bool {
+        self.soft_delete.clone().is_some_and(|soft_delete| soft_delete.unwrap_or_default().partial_unique)
+    }
+
+    pub(in crate::entity) fn changeset_ident(&self) -> Ident {
+        quote::format_ident!("{}Update", self.ident)
+    }
+
+    /// Names the join table of a many-to-many relation after both entities, in the same order
+    /// from either side. Returns whether this entity is stored in column `A`, the two fields of
+    /// a relation to itself are told apart by their names.
+    pub(in crate::entity) fn join_table(&self, field: &Ident, target: &Type) -> syn::Result<(String, bool)> {
+        let source = self.ident.to_string();
+        let target = self.relation_entity(target)?;
+        if source != target {
+            return if source < target {
+                Ok((format!("{source}To{target}"), true))
+            } else {
+                Ok((format!("{target}To{source}"), false))
+            };
+        }
+        let mut others = Vec::new();
+        for field_def in self.data.as_ref().take_struct().unwrap().fields {
+            if let Some(ident) = &field_def.ident && ident != field && field_def.relation.as_ref().is_some_and(|r| r.many_to_many) &&
+                    self.relation_entity(&field_def.relation_target()?.1)? == source {
+                others.push(ident.clone());
+            }
+        }
+        match others.as_slice() {
+            [] => Ok((format!("{source}To{source}"), true)),
+            [other] => Ok((format!("{source}To{source}"), *field < *other)),
+            _ => Err(syn::Error::new(field.span(), "teo(relation): an entity can only have one many to many relation to itself.")),
+        }
+    }
+
+    fn relation_entity(&self, target: &Type) -> syn::Result<String> {
+        let Type::Path(path) = target else {
+            return Err(syn::Error::new_spanned(target, "teo(relation): invalid related entity."));
+        };
+        let Some(segment) = path.path.segments.last() else {
+            return Err(syn::Error::new_spanned(target, "teo(relation): invalid related entity."));
+        };
+        if segment.ident == "Self" {
+            Ok(self.ident.to_string())
+        } else {
+            Ok(segment.ident.to_string())
+        }
+    }
 }
@@ -0,0 +1,6 @@
+make_table_defs!(
+    generate_duckdb_table_defs,
+    duckdb_table_defs,
+    duckdb_table_def,
+    duckdb_join_table_defs,
+    duckdb);
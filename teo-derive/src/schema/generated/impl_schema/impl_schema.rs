@@ -1,6 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Result;
+#[cfg(feature = "duckdb")]
+use crate::schema::generated::impl_schema::duckdb_table_defs::generate_duckdb_table_defs;
 #[cfg(feature = "mongo")]
 use crate::schema::generated::impl_schema::mongo_table_defs::generate_mongo_table_defs;
 #[cfg(feature = "mysql")]
@@ -29,6 +31,10 @@ pub(in crate::schema) fn generate_impl_schema(opts: SchemaDef) -> Result<TokenSt
     let sqlite_table_defs = generate_sqlite_table_defs(opts.clone())?;
     #[cfg(not(feature = "sqlite"))]
     let sqlite_table_defs = quote! { };
+    #[cfg(feature = "duckdb")]
+    let duckdb_table_defs = generate_duckdb_table_defs(opts.clone())?;
+    #[cfg(not(feature = "duckdb"))]
+    let duckdb_table_defs = quote! { };
     Ok(quote! {
         impl ::teo::types::Schema for #struct_ident {
             fn enum_defs() -> Vec<::teo::migration::EnumDef> {
@@ -38,6 +44,7 @@ pub(in crate::schema) fn generate_impl_schema(opts: SchemaDef) -> Result<TokenSt
             #mysql_table_defs
             #postgres_table_defs
             #sqlite_table_defs
+            #duckdb_table_defs
         }
     })
 }
@@ -1,7 +1,10 @@
 #[macro_use]
 mod table_defs;
 
+#[allow(clippy::module_inception)]
 pub(in crate::schema) mod impl_schema;
+#[cfg(feature = "duckdb")]
+mod duckdb_table_defs;
 mod mongo_table_defs;
 mod mysql_table_defs;
 mod postgres_table_defs;
@@ -2,4 +2,5 @@ make_table_defs!(
     generate_mongo_table_defs,
     mongo_table_defs,
     mongo_table_def,
+    mongo_join_table_defs,
     mongo);
@@ -2,4 +2,5 @@ make_table_defs!(
     generate_mysql_table_defs,
     mysql_table_defs,
     mysql_table_def,
+    mysql_join_table_defs,
     mysql);
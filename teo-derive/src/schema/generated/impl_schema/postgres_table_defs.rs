@@ -2,4 +2,5 @@ make_table_defs!(
     generate_postgres_table_defs,
     postgres_table_defs,
     postgres_table_def,
+    postgres_join_table_defs,
     postgres);
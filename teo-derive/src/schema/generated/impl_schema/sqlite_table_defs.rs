@@ -2,4 +2,5 @@ make_table_defs!(
     generate_sqlite_table_defs,
     sqlite_table_defs,
     sqlite_table_def,
+    sqlite_join_table_defs,
     sqlite);
@@ -1,5 +1,5 @@
 macro_rules! make_table_defs {
-    ($name:ident, $fn_name:ident, $table_def_fn_name:ident, $config_name:ident) => {
+    ($name:ident, $fn_name:ident, $table_def_fn_name:ident, $join_table_defs_fn_name:ident, $config_name:ident) => {
         pub(in crate::schema) fn $name(
             opts: crate::schema::types::SchemaDef,
         ) -> ::syn::Result<::proc_macro2::TokenStream> {
@@ -7,12 +7,20 @@ macro_rules! make_table_defs {
                 let path = &entity.path;
                 ::quote::quote! {
                     table_defs.push(<#path as ::teo::types::Entity>::$table_def_fn_name());
+                    table_defs.extend(<#path as ::teo::types::Entity>::$join_table_defs_fn_name());
+                }
+            }).collect();
+            let primary_columns: Vec<::proc_macro2::TokenStream> = opts.entities.iter().map(|entity| {
+                let path = &entity.path;
+                ::quote::quote! {
+                    (<#path as ::teo::types::Entity>::table_name(), <#path as ::teo::types::Entity>::primary_column())
                 }
             }).collect();
             Ok(::quote::quote! {
                 fn $fn_name() -> Vec<::teo::migration::TableDef<::teo::teo_column_type::$config_name::ColumnType>> {
                     let mut table_defs = Vec::new();
                     #( #entities )*
+                    ::teo::migration::link_table_defs(&mut table_defs, &[#( #primary_columns ),*]);
                     table_defs
                 }
             })
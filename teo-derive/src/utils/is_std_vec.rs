@@ -0,0 +1,20 @@
+use syn::Type;
+
+/// Return `true`, if the type path refers to `std::vec::Vec`
+pub(crate) fn is_std_vec(type_: &Type) -> bool {
+    match type_ {
+        Type::Group(syn::TypeGroup { elem, .. })
+        | Type::Paren(syn::TypeParen { elem, .. }) => is_std_vec(elem),
+
+        Type::Path(syn::TypePath { qself: None, path }) => {
+            (path.leading_colon.is_none()
+            && path.segments.len() == 1
+            && path.segments[0].ident == "Vec")
+            || (path.segments.len() == 3
+            && (path.segments[0].ident == "std" || path.segments[0].ident == "alloc")
+            && path.segments[1].ident == "vec"
+            && path.segments[2].ident == "Vec")
+        }
+        _ => false,
+    }
+}
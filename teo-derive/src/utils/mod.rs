@@ -1,5 +1,7 @@
 mod is_std_option;
+mod is_std_vec;
 mod extract_first_path_argument;
 
 pub(crate) use is_std_option::is_std_option;
+pub(crate) use is_std_vec::is_std_vec;
 pub(crate) use extract_first_path_argument::extract_first_path_argument;
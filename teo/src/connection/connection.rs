@@ -1,15 +1,19 @@
-use crate::types::Schema;
+use crate::{error::Result, migration::{MigrationObserver, MigrationReport}, types::Schema};
 
 pub trait SyncConnection {
 
-    type Err;
+    fn migrate<S>(&mut self) -> Result<MigrationReport> where S: Schema {
+        self.migrate_with_observer::<S>(&mut ())
+    }
 
-    fn migrate<S>(&mut self) -> Result<(), Self::Err> where S: Schema;
+    fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema;
 }
 
-pub trait AsyncConnection {
+pub trait AsyncConnection: Send {
 
-    type Err;
+    fn migrate<S>(&mut self) -> impl Future<Output = Result<MigrationReport>> + Send where S: Schema {
+        async { self.migrate_with_observer::<S>(&mut ()).await }
+    }
 
-    fn migrate<S>(&mut self) -> impl Future<Output = Result<(), Self::Err>> + Send where S: Schema;
+    fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> impl Future<Output = Result<MigrationReport>> + Send where S: Schema;
 }
@@ -0,0 +1,49 @@
+use crate::{error::Result, query::{Aggregate, Delete, Select, Update, Upsert}, types::{Entity, FromRow, Value}};
+
+pub trait SyncExecutor {
+
+    fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity;
+
+    fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow;
+
+    fn upsert(&mut self, upsert: &Upsert) -> Result<u64>;
+
+    fn update(&mut self, update: &Update) -> Result<u64>;
+
+    fn delete(&mut self, delete: &Delete) -> Result<u64>;
+
+    fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow;
+
+    fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>>;
+
+    fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow;
+
+    fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64>;
+
+    /// The most parameters one statement can bind, included rows are loaded in batches below it.
+    fn max_params(&self) -> usize;
+}
+
+pub trait AsyncExecutor: Send {
+
+    fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> impl Future<Output = Result<Vec<Value>>> + Send where E: Entity;
+
+    fn find<E>(&mut self, select: &Select) -> impl Future<Output = Result<Vec<E>>> + Send where E: FromRow + Send;
+
+    fn upsert(&mut self, upsert: &Upsert) -> impl Future<Output = Result<u64>> + Send;
+
+    fn update(&mut self, update: &Update) -> impl Future<Output = Result<u64>> + Send;
+
+    fn delete(&mut self, delete: &Delete) -> impl Future<Output = Result<u64>> + Send;
+
+    fn delete_returning<E>(&mut self, delete: &Delete) -> impl Future<Output = Result<Vec<E>>> + Send where E: FromRow + Send;
+
+    fn aggregate(&mut self, aggregate: &Aggregate) -> impl Future<Output = Result<Vec<Vec<Value>>>> + Send;
+
+    fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> impl Future<Output = Result<Vec<E>>> + Send where E: FromRow + Send;
+
+    fn execute_raw(&mut self, q: &str, params: &[Value]) -> impl Future<Output = Result<u64>> + Send;
+
+    /// The most parameters one statement can bind, included rows are loaded in batches below it.
+    fn max_params(&self) -> usize;
+}
@@ -1,3 +1,12 @@
+#[allow(clippy::module_inception)]
 mod connection;
+mod executor;
+mod transaction;
+mod stream;
 
 pub use connection::{SyncConnection, AsyncConnection};
+pub use executor::{SyncExecutor, AsyncExecutor};
+pub use stream::StreamExecutor;
+#[cfg(any(feature = "mysql_async", feature = "mongodb", feature = "tokio-rusqlite"))]
+pub(crate) use stream::channel_stream;
+pub use transaction::{IsolationLevel, SyncTransactional, AsyncTransactional, Transaction};
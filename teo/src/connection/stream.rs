@@ -0,0 +1,26 @@
+use futures::stream::BoxStream;
+use crate::{error::Result, query::Select, types::FromRow};
+
+pub trait StreamExecutor: Send {
+
+    /// Decodes the selected rows one by one as the driver receives them.
+    fn stream<'a, E>(&'a mut self, select: &Select) -> BoxStream<'a, Result<E>> where E: FromRow + Send + 'static;
+}
+
+// For drivers whose row streams can't outlive a connection owned by the query, `produce` sends
+// the rows into a bounded channel and waits whenever the consumer falls behind.
+#[cfg(any(feature = "mysql_async", feature = "mongodb", feature = "tokio-rusqlite"))]
+pub(crate) fn channel_stream<'a, E, F>(produce: impl FnOnce(futures::channel::mpsc::Sender<Result<E>>) -> F) -> BoxStream<'a, Result<E>> where E: Send + 'a, F: Future<Output = Result<()>> + Send + 'a {
+    use futures::{FutureExt, SinkExt, StreamExt, channel::mpsc, future::ready, stream};
+    // The number of decoded rows buffered ahead of the consumer.
+    const BUFFER: usize = 64;
+    let (sender, receiver) = mpsc::channel(BUFFER);
+    let mut errors = sender.clone();
+    let produce = produce(sender);
+    let producer = async move {
+        if let Err(error) = produce.await {
+            let _ = errors.send(Err(error)).await;
+        }
+    };
+    stream::select(receiver, producer.into_stream().filter_map(|()| ready(None))).boxed()
+}
@@ -0,0 +1,330 @@
+use std::{panic::{AssertUnwindSafe, catch_unwind, resume_unwind}, pin::Pin, task::{Context, Poll}, thread};
+use crate::{error::{Error, Result}, query::{Aggregate, Delete, Select, Update, Upsert}, types::{Entity, FromRow, Value}};
+use futures::stream::BoxStream;
+use super::{AsyncExecutor, StreamExecutor, SyncExecutor};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl AsRef<str> for IsolationLevel {
+
+    fn as_ref(&self) -> &str {
+        match self {
+            IsolationLevel::ReadUncommitted => "read uncommitted",
+            IsolationLevel::ReadCommitted => "read committed",
+            IsolationLevel::RepeatableRead => "repeatable read",
+            IsolationLevel::Serializable => "serializable",
+        }
+    }
+}
+
+pub trait SyncTransactional: SyncExecutor + Sized {
+
+    fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()>;
+
+    fn commit(&mut self) -> Result<()>;
+
+    fn rollback(&mut self) -> Result<()>;
+
+    fn savepoint(&mut self, name: &str) -> Result<()>;
+
+    fn release_savepoint(&mut self, name: &str) -> Result<()>;
+
+    fn rollback_to_savepoint(&mut self, name: &str) -> Result<()>;
+
+    /// Commits when `f` succeeds and rolls back when it returns an error or panics. Called on a
+    /// transaction, this runs `f` inside a savepoint. The rusqlite and postgres connections have a
+    /// `transaction` method of their own, call this one as
+    /// `SyncTransactional::transaction(&mut conn, |tx| ..)`.
+    fn transaction<F, T>(&mut self, f: F) -> Result<T> where F: FnOnce(&mut Transaction<'_, Self>) -> Result<T> {
+        Transaction::run(self, 1, None, f)
+    }
+
+    fn transaction_with<F, T>(&mut self, isolation: IsolationLevel, f: F) -> Result<T> where F: FnOnce(&mut Transaction<'_, Self>) -> Result<T> {
+        Transaction::run(self, 1, Some(isolation), f)
+    }
+}
+
+pub trait AsyncTransactional: AsyncExecutor + Sized {
+
+    fn begin(&mut self, isolation: Option<IsolationLevel>) -> impl Future<Output = Result<()>> + Send;
+
+    fn commit(&mut self) -> impl Future<Output = Result<()>> + Send;
+
+    fn rollback(&mut self) -> impl Future<Output = Result<()>> + Send;
+
+    fn savepoint(&mut self, name: &str) -> impl Future<Output = Result<()>> + Send;
+
+    fn release_savepoint(&mut self, name: &str) -> impl Future<Output = Result<()>> + Send;
+
+    fn rollback_to_savepoint(&mut self, name: &str) -> impl Future<Output = Result<()>> + Send;
+
+    /// Commits when the future returned by `f` succeeds and rolls back when it fails or panics.
+    /// The future is boxed so that it can borrow the transaction, e.g.
+    /// `conn.transaction(|tx| Box::pin(async move { .. }))`. The tokio-postgres client has a
+    /// `transaction` method of its own, call this one as `AsyncTransactional::transaction(&mut client, ..)`.
+    fn transaction<F, T>(&mut self, f: F) -> impl Future<Output = Result<T>> + Send where F: for<'t, 'c> FnOnce(&'t mut Transaction<'c, Self>) -> Pin<Box<dyn Future<Output = Result<T>> + Send + 't>> + Send, T: Send {
+        Transaction::run_async(self, 1, None, f)
+    }
+
+    fn transaction_with<F, T>(&mut self, isolation: IsolationLevel, f: F) -> impl Future<Output = Result<T>> + Send where F: for<'t, 'c> FnOnce(&'t mut Transaction<'c, Self>) -> Pin<Box<dyn Future<Output = Result<T>> + Send + 't>> + Send, T: Send {
+        Transaction::run_async(self, 1, Some(isolation), f)
+    }
+}
+
+/// A connection inside a transaction, it's accepted wherever the connection is.
+pub struct Transaction<'a, C> {
+    connection: &'a mut C,
+    depth: usize,
+}
+
+impl<'a, C> Transaction<'a, C> {
+
+    fn savepoint_name(&self) -> String {
+        format!("teo_savepoint_{}", self.depth)
+    }
+
+    fn run<F, T>(connection: &'a mut C, depth: usize, isolation: Option<IsolationLevel>, f: F) -> Result<T> where C: SyncTransactional, F: FnOnce(&mut Transaction<'_, C>) -> Result<T> {
+        connection.begin(isolation)?;
+        let mut transaction = Transaction { connection, depth };
+        match catch_unwind(AssertUnwindSafe(|| f(&mut transaction))) {
+            // A failed commit can leave the transaction open, e.g. on a deferred constraint.
+            Ok(Ok(value)) => match transaction.connection.commit() {
+                Ok(()) => Ok(value),
+                Err(error) => {
+                    let _ = transaction.connection.rollback();
+                    Err(error)
+                },
+            },
+            Ok(Err(error)) => {
+                let _ = transaction.connection.rollback();
+                Err(error)
+            },
+            Err(panic) => {
+                let _ = transaction.connection.rollback();
+                resume_unwind(panic)
+            },
+        }
+    }
+
+    async fn run_async<F, T>(connection: &'a mut C, depth: usize, isolation: Option<IsolationLevel>, f: F) -> Result<T> where C: AsyncTransactional, F: for<'t, 'c> FnOnce(&'t mut Transaction<'c, C>) -> Pin<Box<dyn Future<Output = Result<T>> + Send + 't>> + Send, T: Send {
+        connection.begin(isolation).await?;
+        let mut transaction = Transaction { connection, depth };
+        match CatchUnwind(f(&mut transaction)).await {
+            Ok(Ok(value)) => match transaction.connection.commit().await {
+                Ok(()) => Ok(value),
+                Err(error) => {
+                    let _ = transaction.connection.rollback().await;
+                    Err(error)
+                },
+            },
+            Ok(Err(error)) => {
+                let _ = transaction.connection.rollback().await;
+                Err(error)
+            },
+            Err(panic) => {
+                let _ = transaction.connection.rollback().await;
+                resume_unwind(panic)
+            },
+        }
+    }
+}
+
+// Nested transactions are savepoints named after the depth of the enclosing transaction.
+impl<C> SyncTransactional for Transaction<'_, C> where C: SyncTransactional {
+
+    fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        if isolation.is_some() {
+            return Err(Error::NestedIsolationLevel);
+        }
+        let name = self.savepoint_name();
+        self.connection.savepoint(&name)
+    }
+
+    fn commit(&mut self) -> Result<()> {
+        let name = self.savepoint_name();
+        self.connection.release_savepoint(&name)
+    }
+
+    fn rollback(&mut self) -> Result<()> {
+        let name = self.savepoint_name();
+        self.connection.rollback_to_savepoint(&name)
+    }
+
+    fn savepoint(&mut self, name: &str) -> Result<()> {
+        self.connection.savepoint(name)
+    }
+
+    fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        self.connection.release_savepoint(name)
+    }
+
+    fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        self.connection.rollback_to_savepoint(name)
+    }
+
+    fn transaction<F, T>(&mut self, f: F) -> Result<T> where F: FnOnce(&mut Transaction<'_, Self>) -> Result<T> {
+        let depth = self.depth + 1;
+        Transaction::run(self, depth, None, f)
+    }
+
+    fn transaction_with<F, T>(&mut self, isolation: IsolationLevel, f: F) -> Result<T> where F: FnOnce(&mut Transaction<'_, Self>) -> Result<T> {
+        let depth = self.depth + 1;
+        Transaction::run(self, depth, Some(isolation), f)
+    }
+}
+
+impl<C> AsyncTransactional for Transaction<'_, C> where C: AsyncTransactional {
+
+    async fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        if isolation.is_some() {
+            return Err(Error::NestedIsolationLevel);
+        }
+        let name = self.savepoint_name();
+        self.connection.savepoint(&name).await
+    }
+
+    async fn commit(&mut self) -> Result<()> {
+        let name = self.savepoint_name();
+        self.connection.release_savepoint(&name).await
+    }
+
+    async fn rollback(&mut self) -> Result<()> {
+        let name = self.savepoint_name();
+        self.connection.rollback_to_savepoint(&name).await
+    }
+
+    async fn savepoint(&mut self, name: &str) -> Result<()> {
+        self.connection.savepoint(name).await
+    }
+
+    async fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        self.connection.release_savepoint(name).await
+    }
+
+    async fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        self.connection.rollback_to_savepoint(name).await
+    }
+
+    fn transaction<F, T>(&mut self, f: F) -> impl Future<Output = Result<T>> + Send where F: for<'t, 'c> FnOnce(&'t mut Transaction<'c, Self>) -> Pin<Box<dyn Future<Output = Result<T>> + Send + 't>> + Send, T: Send {
+        let depth = self.depth + 1;
+        Transaction::run_async(self, depth, None, f)
+    }
+
+    fn transaction_with<F, T>(&mut self, isolation: IsolationLevel, f: F) -> impl Future<Output = Result<T>> + Send where F: for<'t, 'c> FnOnce(&'t mut Transaction<'c, Self>) -> Pin<Box<dyn Future<Output = Result<T>> + Send + 't>> + Send, T: Send {
+        let depth = self.depth + 1;
+        Transaction::run_async(self, depth, Some(isolation), f)
+    }
+}
+
+impl<C> SyncExecutor for Transaction<'_, C> where C: SyncExecutor {
+
+    fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        self.connection.insert::<E>(rows)
+    }
+
+    fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow {
+        self.connection.find(select)
+    }
+
+    fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        self.connection.upsert(upsert)
+    }
+
+    fn update(&mut self, update: &Update) -> Result<u64> {
+        self.connection.update(update)
+    }
+
+    fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        self.connection.delete(delete)
+    }
+
+    fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow {
+        self.connection.delete_returning(delete)
+    }
+
+    fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        self.connection.aggregate(aggregate)
+    }
+
+    fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow {
+        self.connection.query_raw(q, params)
+    }
+
+    fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        self.connection.execute_raw(q, params)
+    }
+
+    fn max_params(&self) -> usize {
+        self.connection.max_params()
+    }
+}
+
+impl<C> AsyncExecutor for Transaction<'_, C> where C: AsyncExecutor {
+
+    async fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        self.connection.insert::<E>(rows).await
+    }
+
+    async fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow + Send {
+        self.connection.find(select).await
+    }
+
+    async fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        self.connection.upsert(upsert).await
+    }
+
+    async fn update(&mut self, update: &Update) -> Result<u64> {
+        self.connection.update(update).await
+    }
+
+    async fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        self.connection.delete(delete).await
+    }
+
+    async fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow + Send {
+        self.connection.delete_returning(delete).await
+    }
+
+    async fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        self.connection.aggregate(aggregate).await
+    }
+
+    async fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow + Send {
+        self.connection.query_raw(q, params).await
+    }
+
+    async fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        self.connection.execute_raw(q, params).await
+    }
+
+    fn max_params(&self) -> usize {
+        self.connection.max_params()
+    }
+}
+
+impl<C> StreamExecutor for Transaction<'_, C> where C: StreamExecutor {
+
+    fn stream<'a, E>(&'a mut self, select: &Select) -> BoxStream<'a, Result<E>> where E: FromRow + Send + 'static {
+        self.connection.stream(select)
+    }
+}
+
+struct CatchUnwind<F>(F);
+
+impl<F> Future for CatchUnwind<F> where F: Future + Unpin {
+
+    type Output = thread::Result<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match catch_unwind(AssertUnwindSafe(|| Pin::new(&mut self.0).poll(cx))) {
+            Ok(poll) => poll.map(Ok),
+            Err(panic) => Poll::Ready(Err(panic)),
+        }
+    }
+}
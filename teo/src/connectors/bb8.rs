@@ -0,0 +1,87 @@
+use bb8::{ManageConnection, PooledConnection};
+use futures::stream::BoxStream;
+use crate::{connection::{AsyncConnection, AsyncExecutor, AsyncTransactional, IsolationLevel, StreamExecutor}, error::Result, migration::{MigrationObserver, MigrationReport}, query::{Aggregate, Delete, Select, Update, Upsert}, types::{Entity, FromRow, Schema, Value}};
+
+impl<M> AsyncConnection for PooledConnection<'_, M> where M: ManageConnection, M::Connection: AsyncConnection {
+
+    async fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        (**self).migrate_with_observer::<S>(observer).await
+    }
+}
+
+impl<M> AsyncExecutor for PooledConnection<'_, M> where M: ManageConnection, M::Connection: AsyncExecutor {
+
+    async fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        (**self).insert::<E>(rows).await
+    }
+
+    async fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow + Send {
+        (**self).find(select).await
+    }
+
+    async fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        (**self).upsert(upsert).await
+    }
+
+    async fn update(&mut self, update: &Update) -> Result<u64> {
+        (**self).update(update).await
+    }
+
+    async fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        (**self).delete(delete).await
+    }
+
+    async fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow + Send {
+        (**self).delete_returning(delete).await
+    }
+
+    async fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        (**self).aggregate(aggregate).await
+    }
+
+    async fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow + Send {
+        (**self).query_raw(q, params).await
+    }
+
+    async fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        (**self).execute_raw(q, params).await
+    }
+
+    fn max_params(&self) -> usize {
+        (**self).max_params()
+    }
+}
+
+impl<M> StreamExecutor for PooledConnection<'_, M> where M: ManageConnection, M::Connection: StreamExecutor {
+
+    fn stream<'a, E>(&'a mut self, select: &Select) -> BoxStream<'a, Result<E>> where E: FromRow + Send + 'static {
+        (**self).stream(select)
+    }
+}
+
+impl<M> AsyncTransactional for PooledConnection<'_, M> where M: ManageConnection, M::Connection: AsyncTransactional {
+
+    async fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        (**self).begin(isolation).await
+    }
+
+    async fn commit(&mut self) -> Result<()> {
+        (**self).commit().await
+    }
+
+    async fn rollback(&mut self) -> Result<()> {
+        (**self).rollback().await
+    }
+
+    async fn savepoint(&mut self, name: &str) -> Result<()> {
+        (**self).savepoint(name).await
+    }
+
+    async fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        (**self).release_savepoint(name).await
+    }
+
+    async fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        (**self).rollback_to_savepoint(name).await
+    }
+}
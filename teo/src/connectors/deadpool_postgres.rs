@@ -0,0 +1,87 @@
+use deadpool_postgres::Object;
+use futures::stream::BoxStream;
+use crate::{connection::{AsyncConnection, AsyncExecutor, AsyncTransactional, IsolationLevel, StreamExecutor}, error::Result, migration::{AsyncMigration, MigrationObserver, MigrationReport}, query::{Aggregate, Delete, Select, Update, Upsert}, types::{Entity, FromRow, Schema, Value}};
+
+impl AsyncConnection for Object {
+
+    async fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        AsyncMigration::migrate::<S>(&mut ***self, observer).await
+    }
+}
+
+impl AsyncExecutor for Object {
+
+    async fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        AsyncExecutor::insert::<E>(&mut ***self, rows).await
+    }
+
+    async fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow + Send {
+        AsyncExecutor::find(&mut ***self, select).await
+    }
+
+    async fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        AsyncExecutor::upsert(&mut ***self, upsert).await
+    }
+
+    async fn update(&mut self, update: &Update) -> Result<u64> {
+        AsyncExecutor::update(&mut ***self, update).await
+    }
+
+    async fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        AsyncExecutor::delete(&mut ***self, delete).await
+    }
+
+    async fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow + Send {
+        AsyncExecutor::delete_returning(&mut ***self, delete).await
+    }
+
+    async fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        AsyncExecutor::aggregate(&mut ***self, aggregate).await
+    }
+
+    async fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow + Send {
+        AsyncExecutor::query_raw(&mut ***self, q, params).await
+    }
+
+    async fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        AsyncExecutor::execute_raw(&mut ***self, q, params).await
+    }
+
+    fn max_params(&self) -> usize {
+        AsyncExecutor::max_params(&***self)
+    }
+}
+
+impl StreamExecutor for Object {
+
+    fn stream<'a, E>(&'a mut self, select: &Select) -> BoxStream<'a, Result<E>> where E: FromRow + Send + 'static {
+        StreamExecutor::stream(&mut ***self, select)
+    }
+}
+
+impl AsyncTransactional for Object {
+
+    async fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        AsyncTransactional::begin(&mut ***self, isolation).await
+    }
+
+    async fn commit(&mut self) -> Result<()> {
+        AsyncTransactional::commit(&mut ***self).await
+    }
+
+    async fn rollback(&mut self) -> Result<()> {
+        AsyncTransactional::rollback(&mut ***self).await
+    }
+
+    async fn savepoint(&mut self, name: &str) -> Result<()> {
+        AsyncTransactional::savepoint(&mut ***self, name).await
+    }
+
+    async fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        AsyncTransactional::release_savepoint(&mut ***self, name).await
+    }
+
+    async fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        AsyncTransactional::rollback_to_savepoint(&mut ***self, name).await
+    }
+}
@@ -0,0 +1,93 @@
+use std::{borrow::Cow, str::FromStr};
+
+use ::duckdb::Connection;
+use teo_column_type::duckdb;
+use crate::{connection::SyncConnection, dialect::{Dialect, DuckDB}, error::{Error, Result}, migration::{collect_foreign_keys, ColumnDef, IndexDef, MigrationObserver, MigrationReport, SyncMigration, SyncSQLMigration, TableDef}, types::Schema};
+
+impl SyncConnection for Connection {
+
+    fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        SyncMigration::migrate::<S>(self, observer)
+    }
+}
+
+impl SyncSQLMigration for Connection {
+
+    type Dialect = DuckDB;
+
+    #[inline]
+    fn dialect(&self) -> DuckDB {
+        DuckDB
+    }
+
+    fn execute_without_params(&mut self, q: &str) -> Result<()> {
+        self.execute_batch(q)?;
+        Ok(())
+    }
+
+    fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        let mut statement = self.prepare(self.dialect().table_names_statement())?;
+        let rows = statement.query_map([], |row| {
+            let name: String = row.get(0)?;
+            Ok(name)
+        })?;
+        let mut table_names = vec![];
+        for name in rows {
+            table_names.push(name?);
+        }
+        Ok(table_names)
+    }
+
+    fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<duckdb::ColumnType>> {
+        let column_sql = self.dialect().table_columns_statement(table_name);
+        let mut column_statement = self.prepare(&column_sql)?;
+        let column_rows = column_statement.query_map([], |row| {
+            let name: String = row.get("column_name")?;
+            let ty: String = row.get("data_type")?;
+            let is_nullable: String = row.get("is_nullable")?;
+            let column_default: Option<String> = row.get("column_default")?;
+            Ok((name, ty, is_nullable, column_default))
+        })?;
+        let mut columns = vec![];
+        for column_row in column_rows {
+            let (name, ty, is_nullable, column_default) = column_row?;
+            let ty = duckdb::ColumnType::from_str(&ty).map_err(|e| Error::unknown_column_type(table_name, &name, e))?;
+            columns.push(ColumnDef {
+                name: Cow::Owned(name),
+                ty,
+                nullable: is_nullable == "YES",
+                default: column_default.map(Cow::Owned),
+                auto_increment: false,
+                on_update: None,
+            });
+        }
+        let indexes_sql = self.dialect().table_indexes_statement(table_name);
+        let mut indexes_statement = self.prepare(&indexes_sql)?;
+        let indexes_rows = indexes_statement.query_map([], |row| {
+            let name: String = row.get("index_name")?;
+            let sql: Option<String> = row.get("sql")?;
+            Ok((name, sql))
+        })?;
+        let mut indexes = vec![];
+        for index_row in indexes_rows {
+            let (name, sql) = index_row?;
+            indexes.push(IndexDef {
+                name: Cow::Owned(name),
+                columns: sql.as_ref().map(|sql| self.dialect().index_columns(sql)).unwrap_or_default(),
+                unique: sql.as_ref().is_some_and(|sql| self.dialect().index_unique(sql)),
+                predicate: sql.as_ref().and_then(|sql| self.dialect().index_predicate(sql)).map(Cow::Owned),
+            });
+        }
+        let foreign_keys_sql = self.dialect().table_foreign_keys_statement(table_name);
+        let mut foreign_keys_statement = self.prepare(&foreign_keys_sql)?;
+        let foreign_keys = foreign_keys_statement.query_map([], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })?.collect::<::duckdb::Result<Vec<_>>>()?;
+        Ok(TableDef {
+            name: Cow::Owned(table_name.to_string()),
+            columns,
+            indexes,
+            foreign_keys: collect_foreign_keys(table_name, foreign_keys, false),
+        })
+    }
+}
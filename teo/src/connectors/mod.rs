@@ -1,5 +1,13 @@
+#[macro_use]
+pub(crate) mod value;
+
 #[cfg(feature = "mongodb")]
 mod mongodb;
+#[cfg(feature = "mongodb")]
+pub use mongodb::MongoSession;
+
+#[cfg(feature = "mongodb_sync")]
+mod mongodb_sync;
 
 #[cfg(feature = "mysql_async")]
 mod mysql_async;
@@ -15,3 +23,21 @@ mod postgres;
 
 #[cfg(feature = "rusqlite")]
 mod rusqlite;
+
+#[cfg(feature = "tokio-rusqlite")]
+mod tokio_rusqlite;
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "duckdb")]
+mod duckdb;
+
+#[cfg(feature = "deadpool-postgres")]
+mod deadpool_postgres;
+
+#[cfg(feature = "bb8")]
+mod bb8;
+
+#[cfg(feature = "r2d2")]
+mod r2d2;
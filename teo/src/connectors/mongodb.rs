@@ -1,214 +1,439 @@
-use mongodb::{Collection, Database, IndexModel, bson::{Bson, doc, to_bson}, error::Error, options::IndexOptions};
-use crate::{connection::AsyncConnection, migration::{AsyncMigration, ColumnDef, EnumDef, IndexColumnDef, IndexDef, TableDef}, types::{Schema, SortOrder}};
+use futures::{SinkExt, StreamExt, TryFutureExt, TryStreamExt, stream::BoxStream};
+use mongodb::{ClientSession, Collection, Database, IndexModel, Namespace, bson::{Bson, Document, doc, to_bson}, options::{IndexOptions, UpdateOneModel}};
+use crate::{connection::{AsyncConnection, AsyncExecutor, AsyncTransactional, IsolationLevel, StreamExecutor, channel_stream}, connectors::value::bson::{inserted_keys, to_document}, error::{Error, Result}, migration::{AsyncMigration, ColumnDef, EnumDef, ForeignKeyDef, IndexDef, MigrationObserver, MigrationReport, Recorder, TableDef}, query::{Aggregate, Delete, Select, Update, Upsert, mongo::{aggregate_pipeline, aggregate_values, filter_document, find_options, method_command, shell_command, upsert_documents}}, trace, types::{Entity, FromRow, Schema, Value}};
 use teo_column_type::mongo;
 
 impl AsyncConnection for Database {
 
-    type Err = Error;
-
-    async fn migrate<S>(&mut self) -> Result<(), Error> where S: Schema {
-        AsyncMigration::migrate::<S>(self).await
+    async fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        AsyncMigration::migrate::<S>(self, observer).await
     }
 }
 
-impl AsyncMigration for Database {
+impl AsyncExecutor for Database {
 
-    type Err = Error;
+    async fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+        let collection: Collection<Document> = self.collection(E::table_name());
+        let documents: Vec<Document> = rows.iter().map(|row| to_document(row.clone())).collect();
+        trace::statement_async("mongodb", Some(E::table_name()), &method_command(E::table_name(), "insertMany"), &[], async {
+            let result = collection.insert_many(documents).await?;
+            inserted_keys::<E>(result.inserted_ids)
+        }).await
+    }
 
-    type ColumnType = mongo::ColumnType;
+    async fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow + Send {
+        let collection: Collection<Document> = self.collection(select.table);
+        let find = collection.find(filter_document(select.filter.as_ref())).with_options(find_options(select));
+        trace::statement_async("mongodb", Some(select.table), &method_command(select.table, "find"), &[], async {
+            let documents: Vec<Document> = find.await?.try_collect().await?;
+            documents.iter().map(|document| E::from_row(document)).collect()
+        }).await
+    }
 
-    async fn execute_without_params(&mut self, q: &str) -> Result<(), Self::Err> {
-        unreachable!()
+    async fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        let namespace = Namespace::new(self.name(), upsert.table);
+        let models = upsert_documents(upsert)?.into_iter().map(|(filter, update)| {
+            UpdateOneModel::builder().namespace(namespace.clone()).filter(filter).update(update).upsert(true).build()
+        });
+        trace::statement_async("mongodb", Some(upsert.table), &method_command(upsert.table, "bulkWrite"), &[], async {
+            let result = self.client().bulk_write(models).await?;
+            Ok((result.matched_count + result.upserted_count) as u64)
+        }).await
     }
 
-    fn ident_quote_char() -> &'static str {
-        unreachable!()
+    async fn update(&mut self, update: &Update) -> Result<u64> {
+        let collection: Collection<Document> = self.collection(update.table);
+        let changes = to_document(update.values.clone());
+        trace::statement_async("mongodb", Some(update.table), &method_command(update.table, "updateMany"), &[], async {
+            let result = collection.update_many(filter_document(update.filter.as_ref()), doc!{ "$set": changes }).await?;
+            Ok(result.matched_count)
+        }).await
     }
 
-    fn string_quote_char() -> &'static str {
-        unreachable!()
+    async fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        let collection: Collection<Document> = self.collection(delete.table);
+        let filter = filter_document(delete.filter.as_ref());
+        let method = if delete.single { "deleteOne" } else { "deleteMany" };
+        trace::statement_async("mongodb", Some(delete.table), &method_command(delete.table, method), &[], async {
+            let result = if delete.single {
+                collection.delete_one(filter).await?
+            } else {
+                collection.delete_many(filter).await?
+            };
+            Ok(result.deleted_count)
+        }).await
     }
 
-    async fn exist_enum_names(&mut self) -> Result<Vec<String>, Self::Err> {
-        Ok(Vec::new())
+    async fn delete_returning<E>(&mut self, _delete: &Delete) -> Result<Vec<E>> where E: FromRow + Send {
+        Err(Error::unsupported("mongodb", "delete returning"))
     }
 
-    fn enum_create_statement(&self, enum_def: &EnumDef) -> String {
-        unreachable!()
+    async fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        let collection: Collection<Document> = self.collection(aggregate.table);
+        trace::statement_async("mongodb", Some(aggregate.table), &method_command(aggregate.table, "aggregate"), &[], async {
+            let documents: Vec<Document> = collection.aggregate(aggregate_pipeline(aggregate)).await?.try_collect().await?;
+            aggregate_values(aggregate, documents)
+        }).await
     }
 
-    fn enum_drop_statement(&self, enum_name: &str) -> String {
-        unreachable!()
+    async fn query_raw<E>(&mut self, _q: &str, _params: &[Value]) -> Result<Vec<E>> where E: FromRow + Send {
+        Err(Error::unsupported("mongodb", "raw sql"))
     }
 
-    fn add_enum_variant_statement(&self, enum_name: &str, variant_name: &str) -> String {
-        unreachable!()
+    async fn execute_raw(&mut self, _q: &str, _params: &[Value]) -> Result<u64> {
+        Err(Error::unsupported("mongodb", "raw sql"))
     }
 
-    async fn exist_enum_def(&mut self, enum_name: &'static str) -> Result<EnumDef, Self::Err> {
-        unreachable!()
+    fn max_params(&self) -> usize {
+        usize::MAX
     }
+}
 
-    fn defined_table_defs<S>(&self) -> Vec<TableDef<Self::ColumnType>> where S: Schema {
-        S::mongo_table_defs()
+impl StreamExecutor for Database {
+
+    fn stream<'a, E>(&'a mut self, select: &Select) -> BoxStream<'a, Result<E>> where E: FromRow + Send + 'static {
+        let collection: Collection<Document> = self.collection(select.table);
+        let filter = filter_document(select.filter.as_ref());
+        let options = find_options(select);
+        let traced = trace::statement_stream("mongodb", Some(select.table), &method_command(select.table, "find"), &[]);
+        traced(async move {
+            let cursor = collection.find(filter).with_options(options).await?;
+            Ok::<_, Error>(cursor.map(|document| E::from_row(&document?)))
+        }.try_flatten_stream().boxed())
     }
+}
+
+/// Runs queries in a client session, transactions need a replica set or a sharded cluster.
+pub struct MongoSession {
+    database: Database,
+    session: ClientSession,
+}
 
-    async fn exist_table_names(&mut self) -> Result<Vec<String>, Self::Err> {
-        Ok(self.list_collection_names().await?.into_iter().filter(|n| !n.starts_with("_")).collect())
+impl MongoSession {
+
+    pub async fn start(database: &Database) -> Result<Self> {
+        let session = database.client().start_session().await?;
+        Ok(Self { database: database.clone(), session })
     }
+}
 
-    fn drop_table_statement(&self, table_name: &str) -> String {
-        unreachable!()
+impl AsyncExecutor for MongoSession {
+
+    async fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+        let collection: Collection<Document> = self.database.collection(E::table_name());
+        let documents: Vec<Document> = rows.iter().map(|row| to_document(row.clone())).collect();
+        trace::statement_async("mongodb", Some(E::table_name()), &method_command(E::table_name(), "insertMany"), &[], async {
+            let result = collection.insert_many(documents).session(&mut self.session).await?;
+            inserted_keys::<E>(result.inserted_ids)
+        }).await
     }
 
-    fn create_table_statement(&self, table_def: &TableDef<Self::ColumnType>) -> String {
-        unreachable!()
+    async fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow + Send {
+        let collection: Collection<Document> = self.database.collection(select.table);
+        let find = collection.find(filter_document(select.filter.as_ref())).with_options(find_options(select));
+        trace::statement_async("mongodb", Some(select.table), &method_command(select.table, "find"), &[], async {
+            let mut cursor = find.session(&mut self.session).await?;
+            let documents: Vec<Document> = cursor.stream(&mut self.session).try_collect().await?;
+            documents.iter().map(|document| E::from_row(document)).collect()
+        }).await
     }
 
-    fn column_statement(&self, column_def: &ColumnDef<Self::ColumnType>) -> String {
-        unreachable!()
+    async fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        let namespace = Namespace::new(self.database.name(), upsert.table);
+        let models = upsert_documents(upsert)?.into_iter().map(|(filter, update)| {
+            UpdateOneModel::builder().namespace(namespace.clone()).filter(filter).update(update).upsert(true).build()
+        });
+        trace::statement_async("mongodb", Some(upsert.table), &method_command(upsert.table, "bulkWrite"), &[], async {
+            let result = self.database.client().bulk_write(models).session(&mut self.session).await?;
+            Ok((result.matched_count + result.upserted_count) as u64)
+        }).await
     }
 
-    fn index_column_statement(&self, index_column_def: &IndexColumnDef) -> String {
-        unreachable!()
+    async fn update(&mut self, update: &Update) -> Result<u64> {
+        let collection: Collection<Document> = self.database.collection(update.table);
+        let changes = to_document(update.values.clone());
+        trace::statement_async("mongodb", Some(update.table), &method_command(update.table, "updateMany"), &[], async {
+            let result = collection.update_many(filter_document(update.filter.as_ref()), doc!{ "$set": changes }).session(&mut self.session).await?;
+            Ok(result.matched_count)
+        }).await
     }
 
-    fn create_index_statement(&self, table_name: &str, index_def: &IndexDef) -> String {
-        unreachable!()
+    async fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        let collection: Collection<Document> = self.database.collection(delete.table);
+        let filter = filter_document(delete.filter.as_ref());
+        let method = if delete.single { "deleteOne" } else { "deleteMany" };
+        trace::statement_async("mongodb", Some(delete.table), &method_command(delete.table, method), &[], async {
+            let result = if delete.single {
+                collection.delete_one(filter).session(&mut self.session).await?
+            } else {
+                collection.delete_many(filter).session(&mut self.session).await?
+            };
+            Ok(result.deleted_count)
+        }).await
     }
 
-    async fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<mongo::ColumnType>, Self::Err> {
-        let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
-        let table_def = collections.find_one(doc!{ "name": table_name }).await?.unwrap();
-        Ok(table_def)
+    async fn delete_returning<E>(&mut self, _delete: &Delete) -> Result<Vec<E>> where E: FromRow + Send {
+        Err(Error::unsupported("mongodb", "delete returning"))
     }
 
-    fn drop_table_column_statement(&self, table_name: &str, column_name: &str) -> String {
-        unreachable!()
+    async fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        let collection: Collection<Document> = self.database.collection(aggregate.table);
+        trace::statement_async("mongodb", Some(aggregate.table), &method_command(aggregate.table, "aggregate"), &[], async {
+            let mut cursor = collection.aggregate(aggregate_pipeline(aggregate)).session(&mut self.session).await?;
+            let documents: Vec<Document> = cursor.stream(&mut self.session).try_collect().await?;
+            aggregate_values(aggregate, documents)
+        }).await
     }
 
-    fn add_table_column_statement(&self, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> String {
-        unreachable!()
+    async fn query_raw<E>(&mut self, _q: &str, _params: &[Value]) -> Result<Vec<E>> where E: FromRow + Send {
+        Err(Error::unsupported("mongodb", "raw sql"))
     }
 
-    fn alter_table_column_type_statement(&self, table_name: &str, column_name: &str, column_ty: &Self::ColumnType) -> String {
-        unreachable!()
+    async fn execute_raw(&mut self, _q: &str, _params: &[Value]) -> Result<u64> {
+        Err(Error::unsupported("mongodb", "raw sql"))
     }
 
-    fn alter_table_column_set_not_null_statement(&self, table_name: &str, column_name: &str) -> String {
-        unreachable!()
+    fn max_params(&self) -> usize {
+        usize::MAX
     }
+}
 
-    fn alter_table_column_drop_not_null_statement(&self, table_name: &str, column_name: &str) -> String {
-        unreachable!()
+impl StreamExecutor for MongoSession {
+
+    fn stream<'a, E>(&'a mut self, select: &Select) -> BoxStream<'a, Result<E>> where E: FromRow + Send + 'static {
+        let collection: Collection<Document> = self.database.collection(select.table);
+        let filter = filter_document(select.filter.as_ref());
+        let options = find_options(select);
+        let traced = trace::statement_stream("mongodb", Some(select.table), &method_command(select.table, "find"), &[]);
+        traced(channel_stream(move |mut sender| async move {
+            let mut cursor = collection.find(filter).with_options(options).session(&mut self.session).await?;
+            while let Some(document) = cursor.next(&mut self.session).await {
+                if sender.send(document.map_err(Error::from).and_then(|document| E::from_row(&document))).await.is_err() {
+                    break;
+                }
+            }
+            Ok(())
+        }))
     }
+}
 
-    fn alter_table_column_set_default_statement(&self, table_name: &str, column_name: &str, default: &str) -> String {
-        unreachable!()
+impl AsyncTransactional for MongoSession {
+
+    async fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        if isolation.is_some() {
+            return Err(Error::unsupported("mongodb", "isolation level"));
+        }
+        trace::statement_async("mongodb", None, "session.startTransaction()", &[], async {
+            self.session.start_transaction().await?;
+            Ok(())
+        }).await
     }
 
-    fn alter_table_column_drop_default_statement(&self, table_name: &str, column_name: &str) -> String {
-        unreachable!()
+    async fn commit(&mut self) -> Result<()> {
+        trace::statement_async("mongodb", None, "session.commitTransaction()", &[], async {
+            self.session.commit_transaction().await?;
+            Ok(())
+        }).await
     }
 
-    fn drop_index_statement(&self, index_name: &str) -> String {
-        unreachable!()
+    async fn rollback(&mut self) -> Result<()> {
+        trace::statement_async("mongodb", None, "session.abortTransaction()", &[], async {
+            self.session.abort_transaction().await?;
+            Ok(())
+        }).await
     }
 
-    async fn create_enum(&mut self, enum_def: &EnumDef) -> Result<(), Self::Err> {
-        Ok(())
+    async fn savepoint(&mut self, _name: &str) -> Result<()> {
+        Err(Error::unsupported("mongodb", "savepoint"))
     }
 
-    async fn diff_enum(&mut self, defined_enum_def: &EnumDef) -> Result<(), Self::Err> {
-        Ok(())
+    async fn release_savepoint(&mut self, _name: &str) -> Result<()> {
+        Err(Error::unsupported("mongodb", "savepoint"))
     }
 
-    async fn delete_enum(&mut self, enum_name: &str) -> Result<(), Self::Err> {
-        Ok(())
+    async fn rollback_to_savepoint(&mut self, _name: &str) -> Result<()> {
+        Err(Error::unsupported("mongodb", "savepoint"))
     }
+}
 
-    async fn add_enum_variant(&mut self, enum_name: &str, variant_name: &str) -> Result<(), Self::Err> {
-        Ok(())
+impl AsyncMigration for Database {
+
+    type ColumnType = mongo::ColumnType;
+
+    async fn exist_enum_names(&mut self) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    async fn exist_enum_def(&mut self, _enum_name: &'static str) -> Result<EnumDef> {
+        Err(Error::unsupported("mongodb", "introspect enum"))
     }
 
-    async fn delete_table(&mut self, table_name: &str) -> Result<(), Self::Err> {
-        self.collection::<Bson>(table_name).drop().await?;
+    fn defined_table_defs<S>(&self) -> Vec<TableDef<Self::ColumnType>> where S: Schema {
+        S::mongo_table_defs()
+    }
+
+    async fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        trace::introspect_async("mongodb", None, "table names", async {
+            Ok(self.list_collection_names().await?.into_iter().filter(|n| !n.starts_with("_")).collect())
+        }).await
+    }
+
+    async fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<mongo::ColumnType>> {
         let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
-        collections.delete_one(doc!{ "name": table_name }).await?;
+        trace::introspect_async("mongodb", Some(table_name), "table", async {
+            let table_def = collections.find_one(doc!{ "name": table_name }).await?;
+            table_def.ok_or_else(|| Error::missing_metadata(table_name))
+        }).await
+    }
+
+    async fn create_enum(&mut self, _recorder: &mut Recorder<'_>, _enum_def: &EnumDef) -> Result<()> {
         Ok(())
     }
 
-    async fn create_table(&mut self, table_def: &TableDef<Self::ColumnType>) -> Result<(), Self::Err> {
-        self.create_collection(table_def.name.as_ref()).await?;
-        let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
-        collections.insert_one(table_def).await?;
-        for index in &table_def.indexes {
-            self.create_index(&table_def.name, index).await?;
-        }
+    async fn delete_enum(&mut self, _recorder: &mut Recorder<'_>, _enum_name: &str) -> Result<()> {
         Ok(())
     }
 
-    async fn drop_table_column(&mut self, table_name: &str, column_name: &str) -> Result<(), Self::Err> {
-        let table: Collection<Bson> = self.collection(table_name);
-        table.update_many(doc!{}, doc!{"$unset": {column_name: 1}}).await?;
-        let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
-        collections.update_one(doc!{"name": table_name}, doc!{
-            "$pull": {"columns": { "name": column_name }}
-        }).await?;
+    async fn add_enum_variant(&mut self, _recorder: &mut Recorder<'_>, _enum_name: &str, _variant_name: &str) -> Result<()> {
         Ok(())
     }
 
-    async fn add_table_column(&mut self, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<(), Self::Err> {
+    async fn delete_table(&mut self, recorder: &mut Recorder<'_>, table_name: &str) -> Result<()> {
+        let command = shell_command(Some(table_name), "drop", []);
+        recorder.statement(command.clone());
+        trace::statement_async("mongodb", Some(table_name), &command, &[], async {
+            self.collection::<Bson>(table_name).drop().await?;
+            let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+            collections.delete_one(doc!{ "name": table_name }).await?;
+            Ok(())
+        }).await
+    }
+
+    async fn create_table(&mut self, recorder: &mut Recorder<'_>, table_def: &TableDef<Self::ColumnType>) -> Result<()> {
+        let command = shell_command(None, "createCollection", [Bson::from(table_def.name.as_ref())]);
+        recorder.statement(command.clone());
+        trace::statement_async("mongodb", Some(&table_def.name), &command, &[], async {
+            self.create_collection(table_def.name.as_ref()).await?;
+            let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+            collections.insert_one(table_def).await?;
+            for index in &table_def.indexes {
+                self.create_index(recorder, &table_def.name, index).await?;
+            }
+            Ok(())
+        }).await
+    }
+
+    async fn drop_table_column(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_name: &str) -> Result<()> {
+        let table: Collection<Bson> = self.collection(table_name);
+        let update = doc!{"$unset": {column_name: 1}};
+        let command = shell_command(Some(table_name), "updateMany", [Bson::Document(doc!{}), Bson::Document(update.clone())]);
+        recorder.statement(command.clone());
+        trace::statement_async("mongodb", Some(table_name), &command, &[], async {
+            table.update_many(doc!{}, update).await?;
+            let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+            collections.update_one(doc!{"name": table_name}, doc!{
+                "$pull": {"columns": { "name": column_name }}
+            }).await?;
+            Ok(())
+        }).await
+    }
+
+    async fn add_table_column(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<()> {
         let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
         let column_def_bson = to_bson(column_def)?;
-        collections.update_one(doc!{"name": table_name}, doc!{
-            "$push": {"columns": {"$each": [column_def_bson]}}
-        }).await?;
-        Ok(())
+        let update = doc!{"$push": {"columns": {"$each": [column_def_bson]}}};
+        let command = shell_command(Some(collections.name()), "updateOne", [Bson::Document(doc!{"name": table_name}), Bson::Document(update.clone())]);
+        recorder.statement(command.clone());
+        trace::statement_async("mongodb", Some(table_name), &command, &[], async {
+            collections.update_one(doc!{"name": table_name}, update).await?;
+            Ok(())
+        }).await
     }
 
-    async fn alter_table_column_type(&mut self, table_name: &str, column_name: &str, column_ty: &Self::ColumnType) -> Result<(), Self::Err> {
+    async fn alter_table_column_type(&mut self, _recorder: &mut Recorder<'_>, _table_name: &str, _column_def: &ColumnDef<Self::ColumnType>) -> Result<()> {
         Ok(())
     }
 
-    async fn alter_table_column_nullable(&mut self, table_name: &str, column_name: &str, nullable: bool) -> Result<(), Self::Err> {
+    async fn alter_table_column_nullable(&mut self, _recorder: &mut Recorder<'_>, _table_name: &str, _column_def: &ColumnDef<Self::ColumnType>) -> Result<()> {
         Ok(())
     }
 
-    async fn alter_table_column_default(&mut self, table_name: &str, column_name: &str, default: Option<&str>) -> Result<(), Self::Err> {
+    async fn alter_table_column_default(&mut self, _recorder: &mut Recorder<'_>, _table_name: &str, _column_name: &str, _default: Option<&str>) -> Result<()> {
         Ok(())
     }
 
-    async fn create_index(&mut self, table_name: &str, index_def: &IndexDef) -> Result<(), Self::Err> {
+    async fn create_index(&mut self, recorder: &mut Recorder<'_>, table_name: &str, index_def: &IndexDef) -> Result<()> {
         let index_def_bson = to_bson(index_def)?;
         let mut keys = doc!{};
         for column in &index_def.columns {
             keys.insert(column.name.as_ref(), column.order.as_i32());
         }
-        let options = IndexOptions::builder().name(index_def.name.to_string()).build();
-        let index_model = IndexModel::builder().keys(keys).options(options).build();
+        let command = shell_command(Some(table_name), "createIndex", [Bson::Document(keys.clone()), Bson::Document(doc!{"name": index_def.name.as_ref(), "unique": index_def.unique})]);
+        recorder.statement(command.clone());
+        trace::statement_async("mongodb", Some(table_name), &command, &[], async {
+            let options = IndexOptions::builder().name(index_def.name.to_string()).unique(index_def.unique).build();
+            let index_model = IndexModel::builder().keys(keys).options(options).build();
+            let collection: Collection<Bson> = self.collection(table_name);
+            collection.create_index(index_model).await?;
+            let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+            collections.update_one(doc!{
+                "name": table_name,
+                "indexes": {"$not": {"$elemMatch":{"name":index_def.name.as_ref()}}}
+            }, doc!{
+                "$push": {"indexes": {"$each": [index_def_bson]}}
+            }).await?;
+            Ok(())
+        }).await
+    }
+
+    async fn drop_index(&mut self, recorder: &mut Recorder<'_>, table_name: &str, index_name: &str) -> Result<()> {
         let collection: Collection<Bson> = self.collection(table_name);
-        collection.create_index(index_model).await?;
-        let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
-        collections.update_one(doc!{
-            "name": table_name,
-            "indexes": {"$not": {"$elemMatch":{"name":index_def.name.as_ref()}}}
-        }, doc!{
-            "$push": {"indexes": {"$each": [index_def_bson]}}
-        }).await?;
+        let command = shell_command(Some(table_name), "dropIndex", [Bson::from(index_name)]);
+        recorder.statement(command.clone());
+        trace::statement_async("mongodb", Some(table_name), &command, &[], async {
+            collection.drop_index(index_name).await?;
+            let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+            collections.update_one(doc!{
+                "name": table_name,
+                "indexes": {"$elemMatch":{"name":index_name}}
+            }, doc!{
+                "$pull": {"indexes": {"name": index_name}}
+            }).await?;
+            Ok(())
+        }).await
+    }
+
+    // MongoDB has no foreign keys, they are only recorded so that the next migration sees them.
+    async fn create_table_foreign_keys(&mut self, _recorder: &mut Recorder<'_>, _table_def: &TableDef<Self::ColumnType>) -> Result<()> {
         Ok(())
     }
 
-    async fn drop_index(&mut self, table_name: &str, index_name: &str) -> Result<(), Self::Err> {
-        let collection: Collection<Bson> = self.collection(table_name);
-        collection.drop_index(index_name).await?;
+    async fn create_foreign_key(&mut self, recorder: &mut Recorder<'_>, table_name: &str, foreign_key_def: &ForeignKeyDef) -> Result<()> {
+        let foreign_key_def_bson = to_bson(foreign_key_def)?;
         let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
-        collections.update_one(doc!{
-            "name": table_name,
-            "indexes": {"$elemMatch":{"name":index_name}}
-        }, doc!{
-            "$pull": {"indexes": {"name": index_name}}
-        }).await?;
-        Ok(())
+        let update = doc!{"$push": {"foreign_keys": {"$each": [foreign_key_def_bson]}}};
+        let command = shell_command(Some(collections.name()), "updateOne", [Bson::Document(doc!{"name": table_name}), Bson::Document(update.clone())]);
+        recorder.statement(command.clone());
+        trace::statement_async("mongodb", Some(table_name), &command, &[], async {
+            collections.update_one(doc!{"name": table_name}, update).await?;
+            Ok(())
+        }).await
+    }
+
+    async fn drop_foreign_key(&mut self, recorder: &mut Recorder<'_>, table_name: &str, foreign_key_name: &str) -> Result<()> {
+        let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+        let update = doc!{"$pull": {"foreign_keys": {"name": foreign_key_name}}};
+        let command = shell_command(Some(collections.name()), "updateOne", [Bson::Document(doc!{"name": table_name}), Bson::Document(update.clone())]);
+        recorder.statement(command.clone());
+        trace::statement_async("mongodb", Some(table_name), &command, &[], async {
+            collections.update_one(doc!{"name": table_name}, update).await?;
+            Ok(())
+        }).await
     }
 }
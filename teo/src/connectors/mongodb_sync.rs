@@ -0,0 +1,275 @@
+use mongodb::{IndexModel, bson::{Bson, Document, doc, to_bson}, options::IndexOptions, sync::{Collection, Database}};
+use crate::{connection::{SyncConnection, SyncExecutor}, connectors::value::bson::{inserted_keys, to_document}, error::{Error, Result}, migration::{ColumnDef, EnumDef, ForeignKeyDef, IndexDef, MigrationObserver, MigrationReport, Recorder, SyncMigration, TableDef}, query::{Aggregate, Delete, Select, Update, Upsert, mongo::{aggregate_pipeline, aggregate_values, filter_document, find_options, method_command, shell_command, upsert_documents}}, trace, types::{Entity, FromRow, Schema, Value}};
+use teo_column_type::mongo;
+
+impl SyncConnection for Database {
+
+    fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        SyncMigration::migrate::<S>(self, observer)
+    }
+}
+
+impl SyncExecutor for Database {
+
+    fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+        let collection: Collection<Document> = self.collection(E::table_name());
+        let documents: Vec<Document> = rows.iter().map(|row| to_document(row.clone())).collect();
+        trace::statement("mongodb", Some(E::table_name()), &method_command(E::table_name(), "insertMany"), &[], || {
+            let result = collection.insert_many(documents).run()?;
+            inserted_keys::<E>(result.inserted_ids)
+        })
+    }
+
+    fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow {
+        let collection: Collection<Document> = self.collection(select.table);
+        let find = collection.find(filter_document(select.filter.as_ref())).with_options(find_options(select));
+        trace::statement("mongodb", Some(select.table), &method_command(select.table, "find"), &[], || {
+            let mut entities = vec![];
+            for document in find.run()? {
+                entities.push(E::from_row(&document?)?);
+            }
+            Ok(entities)
+        })
+    }
+
+    // The synchronous database has no handle on its client, so rows are upserted one by one
+    // instead of with a bulk write.
+    fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        let collection: Collection<Document> = self.collection(upsert.table);
+        let documents = upsert_documents(upsert)?;
+        trace::statement("mongodb", Some(upsert.table), &method_command(upsert.table, "updateOne"), &[], || {
+            let mut count = 0;
+            for (filter, update) in documents {
+                let result = collection.update_one(filter, update).upsert(true).run()?;
+                count += result.matched_count + u64::from(result.upserted_id.is_some());
+            }
+            Ok(count)
+        })
+    }
+
+    fn update(&mut self, update: &Update) -> Result<u64> {
+        let collection: Collection<Document> = self.collection(update.table);
+        let changes = to_document(update.values.clone());
+        trace::statement("mongodb", Some(update.table), &method_command(update.table, "updateMany"), &[], || {
+            let result = collection.update_many(filter_document(update.filter.as_ref()), doc!{ "$set": changes }).run()?;
+            Ok(result.matched_count)
+        })
+    }
+
+    fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        let collection: Collection<Document> = self.collection(delete.table);
+        let filter = filter_document(delete.filter.as_ref());
+        let method = if delete.single { "deleteOne" } else { "deleteMany" };
+        trace::statement("mongodb", Some(delete.table), &method_command(delete.table, method), &[], || {
+            let result = if delete.single {
+                collection.delete_one(filter).run()?
+            } else {
+                collection.delete_many(filter).run()?
+            };
+            Ok(result.deleted_count)
+        })
+    }
+
+    fn delete_returning<E>(&mut self, _delete: &Delete) -> Result<Vec<E>> where E: FromRow {
+        Err(Error::unsupported("mongodb", "delete returning"))
+    }
+
+    fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        let collection: Collection<Document> = self.collection(aggregate.table);
+        trace::statement("mongodb", Some(aggregate.table), &method_command(aggregate.table, "aggregate"), &[], || {
+            let documents = collection.aggregate(aggregate_pipeline(aggregate)).run()?.collect::<std::result::Result<Vec<Document>, _>>()?;
+            aggregate_values(aggregate, documents)
+        })
+    }
+
+    fn query_raw<E>(&mut self, _q: &str, _params: &[Value]) -> Result<Vec<E>> where E: FromRow {
+        Err(Error::unsupported("mongodb", "raw sql"))
+    }
+
+    fn execute_raw(&mut self, _q: &str, _params: &[Value]) -> Result<u64> {
+        Err(Error::unsupported("mongodb", "raw sql"))
+    }
+
+    fn max_params(&self) -> usize {
+        usize::MAX
+    }
+}
+
+impl SyncMigration for Database {
+
+    type ColumnType = mongo::ColumnType;
+
+    fn exist_enum_names(&mut self) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    fn exist_enum_def(&mut self, _enum_name: &'static str) -> Result<EnumDef> {
+        Err(Error::unsupported("mongodb", "introspect enum"))
+    }
+
+    fn defined_table_defs<S>(&self) -> Vec<TableDef<Self::ColumnType>> where S: Schema {
+        S::mongo_table_defs()
+    }
+
+    fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        trace::introspect("mongodb", None, "table names", || {
+            Ok(self.list_collection_names().run()?.into_iter().filter(|n| !n.starts_with("_")).collect())
+        })
+    }
+
+    fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<mongo::ColumnType>> {
+        let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+        trace::introspect("mongodb", Some(table_name), "table", || {
+            let table_def = collections.find_one(doc!{ "name": table_name }).run()?;
+            table_def.ok_or_else(|| Error::missing_metadata(table_name))
+        })
+    }
+
+    fn create_enum(&mut self, _recorder: &mut Recorder<'_>, _enum_def: &EnumDef) -> Result<()> {
+        Ok(())
+    }
+
+    fn delete_enum(&mut self, _recorder: &mut Recorder<'_>, _enum_name: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn add_enum_variant(&mut self, _recorder: &mut Recorder<'_>, _enum_name: &str, _variant_name: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn delete_table(&mut self, recorder: &mut Recorder<'_>, table_name: &str) -> Result<()> {
+        let command = shell_command(Some(table_name), "drop", []);
+        recorder.statement(command.clone());
+        trace::statement("mongodb", Some(table_name), &command, &[], || {
+            self.collection::<Bson>(table_name).drop().run()?;
+            let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+            collections.delete_one(doc!{ "name": table_name }).run()?;
+            Ok(())
+        })
+    }
+
+    fn create_table(&mut self, recorder: &mut Recorder<'_>, table_def: &TableDef<Self::ColumnType>) -> Result<()> {
+        let command = shell_command(None, "createCollection", [Bson::from(table_def.name.as_ref())]);
+        recorder.statement(command.clone());
+        trace::statement("mongodb", Some(&table_def.name), &command, &[], || {
+            self.create_collection(table_def.name.as_ref()).run()?;
+            let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+            collections.insert_one(table_def).run()?;
+            for index in &table_def.indexes {
+                self.create_index(recorder, &table_def.name, index)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn drop_table_column(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_name: &str) -> Result<()> {
+        let table: Collection<Bson> = self.collection(table_name);
+        let update = doc!{"$unset": {column_name: 1}};
+        let command = shell_command(Some(table_name), "updateMany", [Bson::Document(doc!{}), Bson::Document(update.clone())]);
+        recorder.statement(command.clone());
+        trace::statement("mongodb", Some(table_name), &command, &[], || {
+            table.update_many(doc!{}, update).run()?;
+            let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+            collections.update_one(doc!{"name": table_name}, doc!{
+                "$pull": {"columns": { "name": column_name }}
+            }).run()?;
+            Ok(())
+        })
+    }
+
+    fn add_table_column(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<()> {
+        let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+        let column_def_bson = to_bson(column_def)?;
+        let update = doc!{"$push": {"columns": {"$each": [column_def_bson]}}};
+        let command = shell_command(Some(collections.name()), "updateOne", [Bson::Document(doc!{"name": table_name}), Bson::Document(update.clone())]);
+        recorder.statement(command.clone());
+        trace::statement("mongodb", Some(table_name), &command, &[], || {
+            collections.update_one(doc!{"name": table_name}, update).run()?;
+            Ok(())
+        })
+    }
+
+    fn alter_table_column_type(&mut self, _recorder: &mut Recorder<'_>, _table_name: &str, _column_def: &ColumnDef<Self::ColumnType>) -> Result<()> {
+        Ok(())
+    }
+
+    fn alter_table_column_nullable(&mut self, _recorder: &mut Recorder<'_>, _table_name: &str, _column_def: &ColumnDef<Self::ColumnType>) -> Result<()> {
+        Ok(())
+    }
+
+    fn alter_table_column_default(&mut self, _recorder: &mut Recorder<'_>, _table_name: &str, _column_name: &str, _default: Option<&str>) -> Result<()> {
+        Ok(())
+    }
+
+    fn create_index(&mut self, recorder: &mut Recorder<'_>, table_name: &str, index_def: &IndexDef) -> Result<()> {
+        let index_def_bson = to_bson(index_def)?;
+        let mut keys = doc!{};
+        for column in &index_def.columns {
+            keys.insert(column.name.as_ref(), column.order.as_i32());
+        }
+        let command = shell_command(Some(table_name), "createIndex", [Bson::Document(keys.clone()), Bson::Document(doc!{"name": index_def.name.as_ref(), "unique": index_def.unique})]);
+        recorder.statement(command.clone());
+        trace::statement("mongodb", Some(table_name), &command, &[], || {
+            let options = IndexOptions::builder().name(index_def.name.to_string()).unique(index_def.unique).build();
+            let index_model = IndexModel::builder().keys(keys).options(options).build();
+            let collection: Collection<Bson> = self.collection(table_name);
+            collection.create_index(index_model).run()?;
+            let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+            collections.update_one(doc!{
+                "name": table_name,
+                "indexes": {"$not": {"$elemMatch":{"name":index_def.name.as_ref()}}}
+            }, doc!{
+                "$push": {"indexes": {"$each": [index_def_bson]}}
+            }).run()?;
+            Ok(())
+        })
+    }
+
+    fn drop_index(&mut self, recorder: &mut Recorder<'_>, table_name: &str, index_name: &str) -> Result<()> {
+        let collection: Collection<Bson> = self.collection(table_name);
+        let command = shell_command(Some(table_name), "dropIndex", [Bson::from(index_name)]);
+        recorder.statement(command.clone());
+        trace::statement("mongodb", Some(table_name), &command, &[], || {
+            collection.drop_index(index_name).run()?;
+            let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+            collections.update_one(doc!{
+                "name": table_name,
+                "indexes": {"$elemMatch":{"name":index_name}}
+            }, doc!{
+                "$pull": {"indexes": {"name": index_name}}
+            }).run()?;
+            Ok(())
+        })
+    }
+
+    // MongoDB has no foreign keys, they are only recorded so that the next migration sees them.
+    fn create_table_foreign_keys(&mut self, _recorder: &mut Recorder<'_>, _table_def: &TableDef<Self::ColumnType>) -> Result<()> {
+        Ok(())
+    }
+
+    fn create_foreign_key(&mut self, recorder: &mut Recorder<'_>, table_name: &str, foreign_key_def: &ForeignKeyDef) -> Result<()> {
+        let foreign_key_def_bson = to_bson(foreign_key_def)?;
+        let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+        let update = doc!{"$push": {"foreign_keys": {"$each": [foreign_key_def_bson]}}};
+        let command = shell_command(Some(collections.name()), "updateOne", [Bson::Document(doc!{"name": table_name}), Bson::Document(update.clone())]);
+        recorder.statement(command.clone());
+        trace::statement("mongodb", Some(table_name), &command, &[], || {
+            collections.update_one(doc!{"name": table_name}, update).run()?;
+            Ok(())
+        })
+    }
+
+    fn drop_foreign_key(&mut self, recorder: &mut Recorder<'_>, table_name: &str, foreign_key_name: &str) -> Result<()> {
+        let collections: Collection<TableDef<Self::ColumnType>> = self.collection("_teo_collections");
+        let update = doc!{"$pull": {"foreign_keys": {"name": foreign_key_name}}};
+        let command = shell_command(Some(collections.name()), "updateOne", [Bson::Document(doc!{"name": table_name}), Bson::Document(update.clone())]);
+        recorder.statement(command.clone());
+        trace::statement("mongodb", Some(table_name), &command, &[], || {
+            collections.update_one(doc!{"name": table_name}, update).run()?;
+            Ok(())
+        })
+    }
+}
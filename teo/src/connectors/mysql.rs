@@ -0,0 +1,269 @@
+use ::mysql::{Conn, Params, PooledConn, Row, prelude::{FromValue, Queryable}};
+use crate::{connection::{IsolationLevel, SyncConnection, SyncExecutor, SyncTransactional}, dialect::{Dialect, MySQL, MySQLColumnRow, MySQLIndexRow}, error::{Error, Result}, migration::{MigrationObserver, MigrationReport, SyncMigration, SyncSQLMigration, TableDef}, query::{Aggregate, Delete, Select, Update, Upsert, sql::SyncSQLExecutor}, types::{Entity, FromRow, Schema, Value}};
+use teo_column_type::mysql;
+
+impl SyncConnection for Conn {
+
+    fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        migrate::<_, S>(self, observer)
+    }
+}
+
+impl SyncConnection for PooledConn {
+
+    fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        migrate::<_, S>(self, observer)
+    }
+}
+
+impl SyncExecutor for Conn {
+
+    fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        SyncSQLExecutor::insert_rows::<E>(self, rows)
+    }
+
+    fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow {
+        SyncSQLExecutor::find_entities(self, select)
+    }
+
+    fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        SyncSQLExecutor::upsert_entities(self, upsert)
+    }
+
+    fn update(&mut self, update: &Update) -> Result<u64> {
+        SyncSQLExecutor::update_entities(self, update)
+    }
+
+    fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        SyncSQLExecutor::delete_entities(self, delete)
+    }
+
+    fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow {
+        SyncSQLExecutor::delete_entities_returning(self, delete)
+    }
+
+    fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        SyncSQLExecutor::aggregate_values(self, aggregate)
+    }
+
+    fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow {
+        SyncSQLExecutor::query_raw_entities(self, q, params)
+    }
+
+    fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        SyncSQLExecutor::execute_raw_statement(self, q, params)
+    }
+
+    fn max_params(&self) -> usize {
+        MySQL::default().max_params()
+    }
+}
+
+impl SyncExecutor for PooledConn {
+
+    fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        SyncSQLExecutor::insert_rows::<E>(self.as_mut(), rows)
+    }
+
+    fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow {
+        SyncSQLExecutor::find_entities(self.as_mut(), select)
+    }
+
+    fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        SyncSQLExecutor::upsert_entities(self.as_mut(), upsert)
+    }
+
+    fn update(&mut self, update: &Update) -> Result<u64> {
+        SyncSQLExecutor::update_entities(self.as_mut(), update)
+    }
+
+    fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        SyncSQLExecutor::delete_entities(self.as_mut(), delete)
+    }
+
+    fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow {
+        SyncSQLExecutor::delete_entities_returning(self.as_mut(), delete)
+    }
+
+    fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        SyncSQLExecutor::aggregate_values(self.as_mut(), aggregate)
+    }
+
+    fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow {
+        SyncSQLExecutor::query_raw_entities(self.as_mut(), q, params)
+    }
+
+    fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        SyncSQLExecutor::execute_raw_statement(self.as_mut(), q, params)
+    }
+
+    fn max_params(&self) -> usize {
+        MySQL::default().max_params()
+    }
+}
+
+impl SyncTransactional for Conn {
+
+    fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        SyncSQLExecutor::begin_transaction(self, isolation)
+    }
+
+    fn commit(&mut self) -> Result<()> {
+        SyncSQLExecutor::transaction_statement(self, "commit")
+    }
+
+    fn rollback(&mut self) -> Result<()> {
+        SyncSQLExecutor::transaction_statement(self, "rollback")
+    }
+
+    fn savepoint(&mut self, name: &str) -> Result<()> {
+        SyncSQLExecutor::savepoint_statement(self, "savepoint", name)
+    }
+
+    fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        SyncSQLExecutor::savepoint_statement(self, "release savepoint", name)
+    }
+
+    fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        SyncSQLExecutor::savepoint_statement(self, "rollback to savepoint", name)
+    }
+}
+
+impl SyncTransactional for PooledConn {
+
+    fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        SyncSQLExecutor::begin_transaction(self.as_mut(), isolation)
+    }
+
+    fn commit(&mut self) -> Result<()> {
+        SyncSQLExecutor::transaction_statement(self.as_mut(), "commit")
+    }
+
+    fn rollback(&mut self) -> Result<()> {
+        SyncSQLExecutor::transaction_statement(self.as_mut(), "rollback")
+    }
+
+    fn savepoint(&mut self, name: &str) -> Result<()> {
+        SyncSQLExecutor::savepoint_statement(self.as_mut(), "savepoint", name)
+    }
+
+    fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        SyncSQLExecutor::savepoint_statement(self.as_mut(), "release savepoint", name)
+    }
+
+    fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        SyncSQLExecutor::savepoint_statement(self.as_mut(), "rollback to savepoint", name)
+    }
+}
+
+impl SyncSQLExecutor for Conn {
+
+    type Dialect = MySQL;
+
+    fn execute(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        self.exec_drop(q, params_from(params))?;
+        Ok(self.affected_rows())
+    }
+
+    fn query_column(&mut self, q: &str, params: &[Value]) -> Result<Vec<Value>> {
+        let rows: Vec<Row> = self.exec(q, params_from(params))?;
+        Ok(rows.into_iter().map(|mut row| row.take(0).map(value::from_mysql_value).unwrap_or(Value::Null)).collect())
+    }
+
+    fn query_entities<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow {
+        let rows: Vec<Row> = self.exec(q, params_from(params))?;
+        rows.iter().map(|row| E::from_row(row)).collect()
+    }
+
+    fn query_rows(&mut self, q: &str, params: &[Value]) -> Result<Vec<Vec<Value>>> {
+        let rows: Vec<Row> = self.exec(q, params_from(params))?;
+        Ok(rows.into_iter().map(|row| row.unwrap().into_iter().map(value::from_mysql_value).collect()).collect())
+    }
+
+    fn execute_batch(&mut self, q: &str) -> Result<()> {
+        self.query_drop(q)?;
+        Ok(())
+    }
+
+    fn last_insert_id(&mut self) -> Result<Value> {
+        Ok(Value::I64(Conn::last_insert_id(self) as i64))
+    }
+}
+
+mod value {
+    impl_mysql_value!(mysql);
+}
+
+fn params_from(params: &[Value]) -> Params {
+    Params::from(params.iter().map(value::to_mysql_value).collect::<Vec<_>>())
+}
+
+fn migrate<Q, S>(conn: &mut Q, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where Q: Queryable, S: Schema {
+    let dialect = dialect(conn)?;
+    SyncMigration::migrate::<S>(&mut Migrator { conn, dialect }, observer)
+}
+
+fn dialect<Q>(conn: &mut Q) -> Result<MySQL> where Q: Queryable {
+    let version: Option<String> = conn.query_first(MySQL::version_statement())?;
+    Ok(MySQL::from_server_version(&version.unwrap_or_default()))
+}
+
+struct Migrator<'a, Q> {
+    conn: &'a mut Q,
+    dialect: MySQL,
+}
+
+impl<Q> SyncSQLMigration for Migrator<'_, Q> where Q: Queryable {
+
+    type Dialect = MySQL;
+
+    #[inline]
+    fn dialect(&self) -> MySQL {
+        self.dialect
+    }
+
+    fn execute_without_params(&mut self, q: &str) -> Result<()> {
+        self.conn.exec_drop(q, ())?;
+        Ok(())
+    }
+
+    fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        let rows: Vec<Row> = self.conn.query(self.dialect.table_names_statement())?;
+        self.dialect.table_names(rows.iter().map(|row| row.get_opt(0).and_then(|name| name.ok())).collect())
+    }
+
+    fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<mysql::ColumnType>> {
+        let columns_statement = self.dialect.table_columns_statement(table_name);
+        let column_rows: Vec<Row> = self.conn.query(&columns_statement)?;
+        let columns = column_rows.iter().map(|row| Ok(MySQLColumnRow {
+            name: get(row, table_name, "Field")?,
+            ty: get(row, table_name, "Type")?,
+            nullable: get(row, table_name, "Null")?,
+            default: get(row, table_name, "Default")?,
+            extra: get(row, table_name, "Extra")?,
+            auto_increment: get::<i64>(row, table_name, "AutoIncrement")? != 0,
+        })).collect::<Result<_>>()?;
+        let check_clauses: Vec<String> = match self.dialect.json_columns_statement(table_name) {
+            Some(json_statement) => self.conn.query(&json_statement)?,
+            None => Vec::new(),
+        };
+        let index_statement = self.dialect.table_indexes_statement(table_name);
+        let index_rows: Vec<Row> = self.conn.query(&index_statement)?;
+        let indexes = index_rows.iter().map(|row| Ok(MySQLIndexRow {
+            name: get(row, table_name, "Key_name")?,
+            column: get(row, table_name, "Column_name")?,
+            collation: get(row, table_name, "Collation")?,
+            non_unique: get::<i64>(row, table_name, "Non_unique")? != 0,
+        })).collect::<Result<_>>()?;
+        let foreign_keys_statement = self.dialect.table_foreign_keys_statement(table_name);
+        let foreign_keys: Vec<(String, String, String, String)> = self.conn.query(&foreign_keys_statement)?;
+        self.dialect.table_def(table_name, columns, &check_clauses, indexes, foreign_keys)
+    }
+}
+
+fn get<T>(row: &Row, table_name: &str, field: &str) -> Result<T> where T: FromValue {
+    match row.get_opt(field) {
+        Some(Ok(value)) => Ok(value),
+        _ => Err(Error::invalid_catalog(table_name, field)),
+    }
+}
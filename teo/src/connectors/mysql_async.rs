@@ -1,137 +1,280 @@
-use std::{borrow::Cow, str::FromStr};
-
-use mysql_async::{Conn, Error, Row, prelude::Queryable};
-use crate::{connection::AsyncConnection, migration::{AsyncMigration, ColumnDef, EnumDef, IndexColumnDef, IndexDef, TableDef}, types::{Schema, SortOrder}};
+use futures::{SinkExt, StreamExt, TryFutureExt, stream::BoxStream};
+use mysql_async::{Conn, Params, Pool, Row, prelude::{FromValue, Queryable}};
+use crate::{connection::{AsyncConnection, AsyncExecutor, AsyncTransactional, IsolationLevel, StreamExecutor, channel_stream}, dialect::{Dialect, MySQL, MySQLColumnRow, MySQLIndexRow}, error::{Error, Result}, migration::{AsyncMigration, AsyncSQLMigration, MigrationObserver, MigrationReport, TableDef}, query::{Aggregate, Delete, Select, Update, Upsert, sql::{AsyncSQLExecutor, select_statement}}, trace, types::{Entity, FromRow, Schema, Value}};
 use teo_column_type::mysql;
 
 impl AsyncConnection for Conn {
 
-    type Err = Error;
+    async fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        let version: Option<String> = self.query_first(MySQL::version_statement()).await?;
+        let dialect = MySQL::from_server_version(&version.unwrap_or_default());
+        AsyncMigration::migrate::<S>(&mut Migrator { conn: self, dialect }, observer).await
+    }
+}
+
+impl AsyncConnection for Pool {
 
-    async fn migrate<S>(&mut self) -> Result<(), Self::Err> where S: Schema {
-        AsyncMigration::migrate::<S>(self).await
+    async fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        let mut conn = self.get_conn().await?;
+        AsyncConnection::migrate_with_observer::<S>(&mut conn, observer).await
     }
 }
 
-impl AsyncMigration for Conn {
+impl AsyncExecutor for Conn {
 
-    type Err = Error;
+    async fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        AsyncSQLExecutor::insert_rows::<E>(self, rows).await
+    }
 
-    type ColumnType = mysql::ColumnType;
+    async fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow + Send {
+        AsyncSQLExecutor::find_entities(self, select).await
+    }
 
-    #[inline]
-    async fn execute_without_params(&mut self, q: &str) -> Result<(), Self::Err> {
-        self.exec_drop(q, ()).await
+    async fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        AsyncSQLExecutor::upsert_entities(self, upsert).await
     }
 
-    #[inline]
-    fn ident_quote_char() -> &'static str {
-        "`"
+    async fn update(&mut self, update: &Update) -> Result<u64> {
+        AsyncSQLExecutor::update_entities(self, update).await
     }
 
-    #[inline]
-    fn string_quote_char() -> &'static str {
-        "'"
+    async fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        AsyncSQLExecutor::delete_entities(self, delete).await
     }
 
-    async fn exist_enum_names(&mut self) -> Result<Vec<String>, Self::Err> {
-        Ok(Vec::new())
+    async fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow + Send {
+        AsyncSQLExecutor::delete_entities_returning(self, delete).await
     }
 
-    fn enum_create_statement(&self, _enum_def: &EnumDef) -> String {
-        unreachable!()
+    async fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        AsyncSQLExecutor::aggregate_values(self, aggregate).await
     }
 
-    fn enum_drop_statement(&self, _enum_name: &str) -> String {
-        unreachable!()
+    async fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow + Send {
+        AsyncSQLExecutor::query_raw_entities(self, q, params).await
     }
 
-    fn add_enum_variant_statement(&self, _enum_name: &str, _variant_name: &str) -> String {
-        unreachable!()
+    async fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        AsyncSQLExecutor::execute_raw_statement(self, q, params).await
     }
 
-    async fn exist_enum_def(&mut self, _enum_name: &'static str) -> Result<EnumDef, Self::Err> {
-        unreachable!()
+    fn max_params(&self) -> usize {
+        MySQL::default().max_params()
     }
+}
 
-    async fn exist_table_names(&mut self) -> Result<Vec<String>, Self::Err> {
-        let statement = "show tables";
-        let rows: Vec<Row> = self.query(statement).await?;
-        let mut table_names = Vec::new();
-        for row in rows {
-            let name: String = row.get_opt(0).unwrap().unwrap();
-            table_names.push(name);
-        }
-        Ok(table_names)
+impl AsyncExecutor for Pool {
+
+    async fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        let mut conn = self.get_conn().await?;
+        AsyncExecutor::insert::<E>(&mut conn, rows).await
     }
 
-    fn create_table_statement(&self, table_def: &TableDef<Self::ColumnType>) -> String {
-        let columns: Vec<String> = table_def.columns.iter().map(|c| self.column_statement(c)).collect();
-        let columns_joined = columns.join(",");
-        format!(r#"create table if not exists {}{}{}({})"#,
-            Self::ident_quote_char(),
-            table_def.name,
-            Self::ident_quote_char(),
-            columns_joined)
+    async fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow + Send {
+        let mut conn = self.get_conn().await?;
+        AsyncExecutor::find(&mut conn, select).await
     }
 
-    fn column_statement(&self, column_def: &ColumnDef<Self::ColumnType>) -> String {
-        let not_null = if column_def.nullable { "" } else { " not null" };
-        let default = if let Some(default) = &column_def.default { format!(" default {}", default) } else { "".to_owned() };
-        format!(r#"{}{}{} {}{}{}"#,
-            Self::ident_quote_char(),
-            column_def.name,
-            Self::ident_quote_char(),
-            column_def.ty.to_string(),
-            not_null,
-            default)
+    async fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        let mut conn = self.get_conn().await?;
+        AsyncExecutor::upsert(&mut conn, upsert).await
     }
 
-    #[inline]
-    fn defined_table_defs<S>(&self) -> Vec<TableDef<mysql::ColumnType>> where S: Schema {
-        S::mysql_table_defs()
-    }
-
-    async fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<Self::ColumnType>, Self::Err> {
-        let columns_statement = format!("describe `{table_name}`");
-        let column_rows: Vec<Row> = self.query(&columns_statement).await?;
-        let mut columns = vec![];
-        for row in &column_rows {
-            let name: String = row.get_opt("Field").unwrap().unwrap();
-            let ty: String = row.get_opt("Type").unwrap().unwrap();
-            let nullable: String = row.get_opt("Null").unwrap().unwrap();
-            columns.push(ColumnDef {
-                name: Cow::Owned(name),
-                ty: mysql::ColumnType::from_str(&ty).unwrap(),
-                nullable: nullable == "YES",
-                default: None
-            });
-        }
-        let index_statement = format!("show indexes from `{table_name}`");
-        let index_rows: Vec<Row> = self.query(&index_statement).await?;
-        let mut indexes = vec![];
-        for row in &index_rows {
-            let index_name: String = row.get_opt("Key_name").unwrap().unwrap();
-            let column_name: String = row.get_opt("Column_name").unwrap().unwrap();
-            let order: String = row.get_opt("Collation").unwrap().unwrap();
-            let _non_unique: bool = row.get_opt("Non_unique").unwrap().unwrap();
-            let column = IndexColumnDef {
-                name: Cow::Owned(column_name),
-                order: if &order == "D" { SortOrder::Desc } else { SortOrder::Asc }
-            };
-            if let Some(position) = indexes.iter().position(|i: &IndexDef| &i.name == &index_name) {
-                indexes.get_mut(position).unwrap().columns.push(column);
-            } else {
-                indexes.push(IndexDef {
-                    name: Cow::Owned(index_name.clone()),
-                    columns: vec![column]
-                })
+    async fn update(&mut self, update: &Update) -> Result<u64> {
+        let mut conn = self.get_conn().await?;
+        AsyncExecutor::update(&mut conn, update).await
+    }
+
+    async fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        let mut conn = self.get_conn().await?;
+        AsyncExecutor::delete(&mut conn, delete).await
+    }
+
+    async fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow + Send {
+        let mut conn = self.get_conn().await?;
+        AsyncExecutor::delete_returning(&mut conn, delete).await
+    }
+
+    async fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        let mut conn = self.get_conn().await?;
+        AsyncExecutor::aggregate(&mut conn, aggregate).await
+    }
+
+    async fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow + Send {
+        let mut conn = self.get_conn().await?;
+        AsyncExecutor::query_raw(&mut conn, q, params).await
+    }
+
+    async fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        let mut conn = self.get_conn().await?;
+        AsyncExecutor::execute_raw(&mut conn, q, params).await
+    }
+
+    fn max_params(&self) -> usize {
+        MySQL::default().max_params()
+    }
+}
+
+impl StreamExecutor for Conn {
+
+    fn stream<'a, E>(&'a mut self, select: &Select) -> BoxStream<'a, Result<E>> where E: FromRow + Send + 'static {
+        let statement = select_statement(MySQL::default(), select);
+        let traced = trace::statement_stream(MySQL::default().name(), Some(select.table), &statement.sql, &statement.params);
+        traced(async move {
+            let rows = self.exec_stream::<Row, _, _>(statement.sql, params_from(&statement.params)).await?;
+            Ok::<_, Error>(rows.map(|row| E::from_row(&row?)))
+        }.try_flatten_stream().boxed())
+    }
+}
+
+impl StreamExecutor for Pool {
+
+    fn stream<'a, E>(&'a mut self, select: &Select) -> BoxStream<'a, Result<E>> where E: FromRow + Send + 'static {
+        let statement = select_statement(MySQL::default(), select);
+        let traced = trace::statement_stream(MySQL::default().name(), Some(select.table), &statement.sql, &statement.params);
+        traced(channel_stream(move |mut sender| async move {
+            let mut conn = self.get_conn().await?;
+            let mut rows = conn.exec_stream::<Row, _, _>(statement.sql, params_from(&statement.params)).await?;
+            while let Some(row) = rows.next().await {
+                if sender.send(row.map_err(Error::from).and_then(|row| E::from_row(&row))).await.is_err() {
+                    break;
+                }
             }
+            Ok(())
+        }))
+    }
+}
+
+impl AsyncTransactional for Conn {
+
+    async fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        AsyncSQLExecutor::begin_transaction(self, isolation).await
+    }
+
+    async fn commit(&mut self) -> Result<()> {
+        AsyncSQLExecutor::transaction_statement(self, "commit").await
+    }
+
+    async fn rollback(&mut self) -> Result<()> {
+        AsyncSQLExecutor::transaction_statement(self, "rollback").await
+    }
+
+    async fn savepoint(&mut self, name: &str) -> Result<()> {
+        AsyncSQLExecutor::savepoint_statement(self, "savepoint", name).await
+    }
+
+    async fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        AsyncSQLExecutor::savepoint_statement(self, "release savepoint", name).await
+    }
+
+    async fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        AsyncSQLExecutor::savepoint_statement(self, "rollback to savepoint", name).await
+    }
+}
+
+impl AsyncSQLExecutor for Conn {
+
+    type Dialect = MySQL;
+
+    async fn execute(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        self.exec_drop(q, params_from(params)).await?;
+        Ok(self.affected_rows())
+    }
+
+    async fn query_column(&mut self, q: &str, params: &[Value]) -> Result<Vec<Value>> {
+        let rows: Vec<Row> = self.exec(q, params_from(params)).await?;
+        Ok(rows.into_iter().map(|mut row| row.take(0).map(value::from_mysql_value).unwrap_or(Value::Null)).collect())
+    }
+
+    async fn query_entities<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow + Send {
+        let rows: Vec<Row> = self.exec(q, params_from(params)).await?;
+        rows.iter().map(|row| E::from_row(row)).collect()
+    }
+
+    async fn query_rows(&mut self, q: &str, params: &[Value]) -> Result<Vec<Vec<Value>>> {
+        let rows: Vec<Row> = self.exec(q, params_from(params)).await?;
+        Ok(rows.into_iter().map(|row| row.unwrap().into_iter().map(value::from_mysql_value).collect()).collect())
+    }
+
+    async fn execute_batch(&mut self, q: &str) -> Result<()> {
+        self.query_drop(q).await?;
+        Ok(())
+    }
+
+    async fn last_insert_id(&mut self) -> Result<Value> {
+        match Conn::last_insert_id(self) {
+            Some(id) => Ok(Value::I64(id as i64)),
+            None => Ok(Value::Null),
         }
-        Ok(TableDef {
-            name: Cow::Owned(table_name.to_string()),
-            columns,
-            indexes,
-        })
+    }
+}
+
+mod value {
+    impl_mysql_value!(mysql_async);
+}
+
+fn params_from(params: &[Value]) -> Params {
+    Params::from(params.iter().map(value::to_mysql_value).collect::<Vec<_>>())
+}
+
+struct Migrator<'a> {
+    conn: &'a mut Conn,
+    dialect: MySQL,
+}
+
+impl AsyncSQLMigration for Migrator<'_> {
+
+    type Dialect = MySQL;
+
+    #[inline]
+    fn dialect(&self) -> MySQL {
+        self.dialect
+    }
+
+    #[inline]
+    async fn execute_without_params(&mut self, q: &str) -> Result<()> {
+        self.conn.exec_drop(q, ()).await?;
+        Ok(())
+    }
+
+    async fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        let statement = self.dialect.table_names_statement();
+        let rows: Vec<Row> = self.conn.query(statement).await?;
+        self.dialect.table_names(rows.iter().map(|row| row.get_opt(0).and_then(|name| name.ok())).collect())
+    }
+
+    async fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<mysql::ColumnType>> {
+        let columns_statement = self.dialect.table_columns_statement(table_name);
+        let column_rows: Vec<Row> = self.conn.query(&columns_statement).await?;
+        let columns = column_rows.iter().map(|row| Ok(MySQLColumnRow {
+            name: get(row, table_name, "Field")?,
+            ty: get(row, table_name, "Type")?,
+            nullable: get(row, table_name, "Null")?,
+            default: get(row, table_name, "Default")?,
+            extra: get(row, table_name, "Extra")?,
+            auto_increment: get::<i64>(row, table_name, "AutoIncrement")? != 0,
+        })).collect::<Result<_>>()?;
+        let check_clauses: Vec<String> = match self.dialect.json_columns_statement(table_name) {
+            Some(json_statement) => self.conn.query(&json_statement).await?,
+            None => Vec::new(),
+        };
+        let index_statement = self.dialect.table_indexes_statement(table_name);
+        let index_rows: Vec<Row> = self.conn.query(&index_statement).await?;
+        let indexes = index_rows.iter().map(|row| Ok(MySQLIndexRow {
+            name: get(row, table_name, "Key_name")?,
+            column: get(row, table_name, "Column_name")?,
+            collation: get(row, table_name, "Collation")?,
+            non_unique: get::<i64>(row, table_name, "Non_unique")? != 0,
+        })).collect::<Result<_>>()?;
+        let foreign_keys_statement = self.dialect.table_foreign_keys_statement(table_name);
+        let foreign_keys: Vec<(String, String, String, String)> = self.conn.query(&foreign_keys_statement).await?;
+        self.dialect.table_def(table_name, columns, &check_clauses, indexes, foreign_keys)
+    }
+}
+
+fn get<T>(row: &Row, table_name: &str, field: &str) -> Result<T> where T: FromValue {
+    match row.get_opt(field) {
+        Some(Ok(value)) => Ok(value),
+        _ => Err(Error::invalid_catalog(table_name, field)),
     }
 }
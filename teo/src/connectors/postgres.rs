@@ -0,0 +1,230 @@
+use std::{borrow::Cow, str::FromStr};
+use teo_column_type::postgres;
+use ::postgres::{Client, types::ToSql};
+use crate::{connection::{IsolationLevel, SyncConnection, SyncExecutor, SyncTransactional}, dialect::{Dialect, Postgres}, error::{Error, Result}, migration::{collect_foreign_keys, ColumnDef, EnumDef, IndexDef, MigrationObserver, MigrationReport, SyncMigration, SyncSQLMigration, TableDef}, query::{Aggregate, Delete, Select, Update, Upsert, sql::SyncSQLExecutor}, types::{Entity, FromRow, Schema, Value}};
+
+impl SyncConnection for Client {
+
+    fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        SyncMigration::migrate::<S>(self, observer)
+    }
+}
+
+impl SyncExecutor for Client {
+
+    fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        SyncSQLExecutor::insert_rows::<E>(self, rows)
+    }
+
+    fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow {
+        SyncSQLExecutor::find_entities(self, select)
+    }
+
+    fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        SyncSQLExecutor::upsert_entities(self, upsert)
+    }
+
+    fn update(&mut self, update: &Update) -> Result<u64> {
+        SyncSQLExecutor::update_entities(self, update)
+    }
+
+    fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        SyncSQLExecutor::delete_entities(self, delete)
+    }
+
+    fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow {
+        SyncSQLExecutor::delete_entities_returning(self, delete)
+    }
+
+    fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        SyncSQLExecutor::aggregate_values(self, aggregate)
+    }
+
+    fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow {
+        SyncSQLExecutor::query_raw_entities(self, q, params)
+    }
+
+    fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        SyncSQLExecutor::execute_raw_statement(self, q, params)
+    }
+
+    fn max_params(&self) -> usize {
+        Postgres.max_params()
+    }
+}
+
+impl SyncTransactional for Client {
+
+    fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        SyncSQLExecutor::begin_transaction(self, isolation)
+    }
+
+    fn commit(&mut self) -> Result<()> {
+        SyncSQLExecutor::transaction_statement(self, "commit")
+    }
+
+    fn rollback(&mut self) -> Result<()> {
+        SyncSQLExecutor::transaction_statement(self, "rollback")
+    }
+
+    fn savepoint(&mut self, name: &str) -> Result<()> {
+        SyncSQLExecutor::savepoint_statement(self, "savepoint", name)
+    }
+
+    fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        SyncSQLExecutor::savepoint_statement(self, "release savepoint", name)
+    }
+
+    fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        SyncSQLExecutor::savepoint_statement(self, "rollback to savepoint", name)
+    }
+}
+
+impl SyncSQLExecutor for Client {
+
+    type Dialect = Postgres;
+
+    fn execute(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p as &(dyn ToSql + Sync)).collect();
+        Ok(Client::execute(self, q, &params)?)
+    }
+
+    fn query_column(&mut self, q: &str, params: &[Value]) -> Result<Vec<Value>> {
+        let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p as &(dyn ToSql + Sync)).collect();
+        let rows = self.query(q, &params)?;
+        let mut values = vec![];
+        for row in rows {
+            values.push(row.try_get(0)?);
+        }
+        Ok(values)
+    }
+
+    fn query_entities<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow {
+        let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p as &(dyn ToSql + Sync)).collect();
+        let rows = self.query(q, &params)?;
+        rows.iter().map(|row| E::from_row(row)).collect()
+    }
+
+    fn query_rows(&mut self, q: &str, params: &[Value]) -> Result<Vec<Vec<Value>>> {
+        let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p as &(dyn ToSql + Sync)).collect();
+        let rows = self.query(q, &params)?;
+        rows.iter().map(|row| (0..row.len()).map(|index| Ok(row.try_get(index)?)).collect()).collect()
+    }
+
+    fn execute_batch(&mut self, q: &str) -> Result<()> {
+        self.batch_execute(q)?;
+        Ok(())
+    }
+}
+
+impl SyncSQLMigration for Client {
+
+    type Dialect = Postgres;
+
+    #[inline]
+    fn dialect(&self) -> Postgres {
+        Postgres
+    }
+
+    #[inline]
+    fn execute_without_params(&mut self, q: &str) -> Result<()> {
+        self.execute(q, &[])?;
+        Ok(())
+    }
+
+    fn exist_enum_names(&mut self) -> Result<Vec<String>> {
+        let statement = self.dialect().enum_names_statement();
+        let rows = self.query(statement, &[])?;
+        let mut enum_names = vec![];
+        for row in rows {
+            let enum_name = row.try_get::<&str, String>("enum_type")?;
+            if !enum_name.starts_with("_") {
+                enum_names.push(enum_name);
+            }
+        }
+        Ok(enum_names)
+    }
+
+    fn exist_enum_def(&mut self, enum_name: &'static str) -> Result<EnumDef> {
+        let statement = self.dialect().enum_variants_statement(enum_name);
+        let rows = self.query(&statement, &[])?;
+        let mut variants = vec![];
+        for row in rows {
+            let variant: String = row.try_get("variant")?;
+            variants.push(Cow::Owned(variant));
+        }
+        Ok(EnumDef {
+            name: enum_name,
+            variants,
+        })
+    }
+
+    fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        let statement = self.dialect().table_names_statement();
+        let rows = self.query(
+            statement,
+            &[],
+        )?;
+        let mut table_names = vec![];
+        for row in rows {
+            let table_name = row.try_get::<&str, String>("tablename")?;
+            table_names.push(table_name);
+        }
+        Ok(table_names)
+    }
+
+    fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<postgres::ColumnType>> {
+        let columns_statement = self.dialect().table_columns_statement(table_name);
+        let column_rows = self.query(
+            &columns_statement,
+            &[]
+        )?;
+        let mut columns = vec![];
+        for row in &column_rows {
+            let name: String = row.try_get("column_name")?;
+            let column_default: Option<String> = row.try_get("column_default")?;
+            let is_nullable: String = row.try_get("is_nullable")?;
+            let udt_name: String = row.try_get("udt_name")?;
+            let ty = postgres::ColumnType::from_str(&udt_name).map_err(|e| Error::unknown_column_type(table_name, &name, e))?;
+            columns.push(ColumnDef {
+                name: Cow::Owned(name),
+                ty,
+                nullable: is_nullable == "YES",
+                default: column_default.map(Cow::Owned),
+                auto_increment: false,
+                on_update: None,
+            });
+        }
+        let indexes_statement = self.dialect().table_indexes_statement(table_name);
+        let index_rows = self.query(
+            &indexes_statement,
+            &[]
+        )?;
+        let mut indexes = vec![];
+        for index_row in &index_rows {
+            let name: String = index_row.try_get("indexname")?;
+            let def: String = index_row.try_get("indexdef")?;
+            indexes.push(IndexDef {
+                name: Cow::Owned(name),
+                columns: self.dialect().index_columns(&def),
+                unique: self.dialect().index_unique(&def),
+                predicate: self.dialect().index_predicate(&def).map(Cow::Owned),
+            });
+        }
+        let foreign_keys_statement = self.dialect().table_foreign_keys_statement(table_name);
+        let foreign_key_rows = self.query(
+            &foreign_keys_statement,
+            &[]
+        )?;
+        let mut foreign_keys = vec![];
+        for row in &foreign_key_rows {
+            foreign_keys.push((row.try_get(0)?, row.try_get(1)?, row.try_get(2)?, row.try_get(3)?));
+        }
+        Ok(TableDef {
+            name: Cow::Owned(table_name.to_string()),
+            columns,
+            indexes,
+            foreign_keys: collect_foreign_keys(table_name, foreign_keys, true),
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use r2d2::{ManageConnection, PooledConnection};
+use crate::{connection::{IsolationLevel, SyncConnection, SyncExecutor, SyncTransactional}, error::Result, migration::{MigrationObserver, MigrationReport}, query::{Aggregate, Delete, Select, Update, Upsert}, types::{Entity, FromRow, Schema, Value}};
+
+impl<M> SyncConnection for PooledConnection<M> where M: ManageConnection, M::Connection: SyncConnection {
+
+    fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        (**self).migrate_with_observer::<S>(observer)
+    }
+}
+
+impl<M> SyncExecutor for PooledConnection<M> where M: ManageConnection, M::Connection: SyncExecutor {
+
+    fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        (**self).insert::<E>(rows)
+    }
+
+    fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow {
+        (**self).find(select)
+    }
+
+    fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        (**self).upsert(upsert)
+    }
+
+    fn update(&mut self, update: &Update) -> Result<u64> {
+        (**self).update(update)
+    }
+
+    fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        (**self).delete(delete)
+    }
+
+    fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow {
+        (**self).delete_returning(delete)
+    }
+
+    fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        (**self).aggregate(aggregate)
+    }
+
+    fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow {
+        (**self).query_raw(q, params)
+    }
+
+    fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        (**self).execute_raw(q, params)
+    }
+
+    fn max_params(&self) -> usize {
+        (**self).max_params()
+    }
+}
+
+impl<M> SyncTransactional for PooledConnection<M> where M: ManageConnection, M::Connection: SyncTransactional {
+
+    fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        (**self).begin(isolation)
+    }
+
+    fn commit(&mut self) -> Result<()> {
+        (**self).commit()
+    }
+
+    fn rollback(&mut self) -> Result<()> {
+        (**self).rollback()
+    }
+
+    fn savepoint(&mut self, name: &str) -> Result<()> {
+        (**self).savepoint(name)
+    }
+
+    fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        (**self).release_savepoint(name)
+    }
+
+    fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        (**self).rollback_to_savepoint(name)
+    }
+}
@@ -1,148 +1,212 @@
 use std::{borrow::Cow, str::FromStr};
 
-use rusqlite::{Connection, Error};
+use rusqlite::{Connection, limits::Limit, params_from_iter};
 use teo_column_type::sqlite;
-use crate::{connection::SyncConnection, migration::{ColumnDef, IndexColumnDef, IndexDef, SyncMigration, TableDef}, types::{Schema, SortOrder}};
+use crate::{connection::{IsolationLevel, SyncConnection, SyncExecutor, SyncTransactional}, dialect::{Dialect, SQLite}, error::{Error, Result}, migration::{collect_foreign_keys, ColumnDef, IndexDef, MigrationObserver, MigrationReport, SyncMigration, SyncSQLMigration, TableDef}, query::{Aggregate, Delete, Select, Update, Upsert, sql::SyncSQLExecutor}, types::{Entity, FromRow, Schema, Value}};
 
 impl SyncConnection for Connection {
 
-    type Err = Error;
-
-    fn migrate<S>(&mut self) -> Result<(), Self::Err> where S: Schema {
-        SyncMigration::migrate::<S>(self)
+    fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        SyncMigration::migrate::<S>(self, observer)
     }
 }
 
-impl SyncMigration for Connection {
+impl SyncExecutor for Connection {
 
-    type Err = Error;
+    fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        SyncSQLExecutor::insert_rows::<E>(self, rows)
+    }
 
-    type ColumnType = sqlite::ColumnType;
+    fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow {
+        SyncSQLExecutor::find_entities(self, select)
+    }
 
-    fn execute_without_params(&mut self, q: &str) -> Result<(), Self::Err> {
-        self.execute(q, ()).map(|_| ())
+    fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        SyncSQLExecutor::upsert_entities(self, upsert)
     }
 
-    fn ident_quote_char() -> &'static str {
-        "\""
+    fn update(&mut self, update: &Update) -> Result<u64> {
+        SyncSQLExecutor::update_entities(self, update)
     }
 
-    fn string_quote_char() -> &'static str {
-        "'"
+    fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        SyncSQLExecutor::delete_entities(self, delete)
     }
 
-    fn exist_enum_names(&mut self) -> Result<Vec<String>, Self::Err> {
-        Ok(Vec::new())
+    fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow {
+        SyncSQLExecutor::delete_entities_returning(self, delete)
     }
 
-    fn enum_create_statement(&self, _enum_def: &crate::migration::EnumDef) -> String {
-        unreachable!()
+    fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        SyncSQLExecutor::aggregate_values(self, aggregate)
     }
 
-    fn enum_drop_statement(&self, _enum_name: &str) -> String {
-        unreachable!()
+    fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow {
+        SyncSQLExecutor::query_raw_entities(self, q, params)
     }
 
-    fn add_enum_variant_statement(&self, _enum_name: &str, _variant_name: &str) -> String {
-        unreachable!()
+    fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        SyncSQLExecutor::execute_raw_statement(self, q, params)
     }
 
-    fn exist_enum_def(&mut self, _enum_name: &'static str) -> Result<crate::migration::EnumDef, Self::Err> {
-        unreachable!()
+    // Builds can lower or raise the default limit, so it's read from the connection.
+    fn max_params(&self) -> usize {
+        self.limit(Limit::SQLITE_LIMIT_VARIABLE_NUMBER).map_or(SQLite.max_params(), |limit| limit as usize)
     }
+}
 
-    fn exist_table_names(&mut self) -> Result<Vec<String>, Self::Err> {
-        let mut statement = self.prepare("select name from sqlite_master where type='table'")?;
-        let rows = statement.query_map((), |row| {
-            let name: String = row.get(0)?;
-            Ok(name)
-        })?;
-        let table_names = rows.filter_map(Result::ok).filter_map(|name| {
-            if name.starts_with("_") {
-                None
-            } else {
-                Some(name)
-            }
-        }).collect();
-        Ok(table_names)
+impl SyncTransactional for Connection {
+
+    fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        SyncSQLExecutor::begin_transaction(self, isolation)
     }
 
-    fn create_table_statement(&self, table_def: &TableDef<Self::ColumnType>) -> String {
-        let columns: Vec<String> = table_def.columns.iter().map(|c| self.column_statement(c)).collect();
-        let columns_joined = columns.join(",");
-        format!(r#"create table if not exists {}{}{}({})"#,
-            Self::ident_quote_char(),
-            table_def.name,
-            Self::ident_quote_char(),
-            columns_joined)
+    fn commit(&mut self) -> Result<()> {
+        SyncSQLExecutor::transaction_statement(self, "commit")
     }
 
-    fn column_statement(&self, column_def: &crate::migration::ColumnDef<Self::ColumnType>) -> String {
-        let not_null = if column_def.nullable { "" } else { " not null" };
-        let default = if let Some(default) = &column_def.default { format!(" default {}", default) } else { "".to_owned() };
-        format!(r#"{}{}{} {}{}{}"#,
-            Self::ident_quote_char(),
-            column_def.name,
-            Self::ident_quote_char(),
-            column_def.ty.to_string(),
-            not_null,
-            default)
+    fn rollback(&mut self) -> Result<()> {
+        SyncSQLExecutor::transaction_statement(self, "rollback")
     }
 
+    fn savepoint(&mut self, name: &str) -> Result<()> {
+        SyncSQLExecutor::savepoint_statement(self, "savepoint", name)
+    }
+
+    fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        SyncSQLExecutor::savepoint_statement(self, "release savepoint", name)
+    }
+
+    fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        SyncSQLExecutor::savepoint_statement(self, "rollback to savepoint", name)
+    }
+}
+
+impl SyncSQLExecutor for Connection {
+
+    type Dialect = SQLite;
+
+    fn execute(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        Ok(Connection::execute(self, q, params_from_iter(params))? as u64)
+    }
+
+    fn query_column(&mut self, q: &str, params: &[Value]) -> Result<Vec<Value>> {
+        let mut statement = self.prepare(q)?;
+        let rows = statement.query_map(params_from_iter(params), |row| row.get(0))?;
+        let mut values = vec![];
+        for value in rows {
+            values.push(value?);
+        }
+        Ok(values)
+    }
+
+    fn query_entities<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow {
+        let mut statement = self.prepare(q)?;
+        let rows = statement.query_map(params_from_iter(params), |row| Ok(E::from_row(row)))?;
+        let mut entities = vec![];
+        for entity in rows {
+            entities.push(entity??);
+        }
+        Ok(entities)
+    }
+
+    fn query_rows(&mut self, q: &str, params: &[Value]) -> Result<Vec<Vec<Value>>> {
+        let mut statement = self.prepare(q)?;
+        let count = statement.column_count();
+        let rows = statement.query_map(params_from_iter(params), |row| (0..count).map(|index| row.get(index)).collect::<rusqlite::Result<Vec<Value>>>())?;
+        let mut values = vec![];
+        for row in rows {
+            values.push(row?);
+        }
+        Ok(values)
+    }
+
+    fn execute_batch(&mut self, q: &str) -> Result<()> {
+        Connection::execute_batch(self, q)?;
+        Ok(())
+    }
+}
+
+impl SyncSQLMigration for Connection {
+
+    type Dialect = SQLite;
+
     #[inline]
-    fn defined_table_defs<S>(&self) -> Vec<TableDef<sqlite::ColumnType>> where S: Schema {
-        S::sqlite_table_defs()
+    fn dialect(&self) -> SQLite {
+        SQLite
+    }
+
+    fn execute_without_params(&mut self, q: &str) -> Result<()> {
+        Connection::execute(self, q, ())?;
+        Ok(())
     }
 
-    fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<Self::ColumnType>, Self::Err> {
-        let column_sql = format!("pragma table_info({}{}{})",
-            Self::ident_quote_char(),
-            table_name,
-            Self::ident_quote_char());
+    fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        let mut statement = self.prepare(self.dialect().table_names_statement())?;
+        let rows = statement.query_map((), |row| {
+            let name: String = row.get(0)?;
+            Ok(name)
+        })?;
+        let mut table_names = vec![];
+        for name in rows {
+            let name = name?;
+            if !name.starts_with("_") {
+                table_names.push(name);
+            }
+        }
+        Ok(table_names)
+    }
+
+    fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<sqlite::ColumnType>> {
+        let column_sql = self.dialect().table_columns_statement(table_name);
         let mut column_statement = self.prepare(&column_sql)?;
-        let columns = column_statement.query_map((), |row| {
+        let column_rows = column_statement.query_map((), |row| {
             let name: String = row.get("name")?;
             let ty: String = row.get("type")?;
             let notnull: bool = row.get("notnull")?;
-            Ok(ColumnDef {
+            let default: Option<String> = row.get("dflt_value")?;
+            Ok((name, ty, notnull, default))
+        })?;
+        let mut columns = vec![];
+        for column_row in column_rows {
+            let (name, ty, notnull, default) = column_row?;
+            let ty = sqlite::ColumnType::from_str(&ty).map_err(|e| Error::unknown_column_type(table_name, &name, e))?;
+            columns.push(ColumnDef {
                 name: Cow::Owned(name),
-                ty: sqlite::ColumnType::from_str(&ty).unwrap(),
+                ty,
                 nullable: !notnull,
-                default: None,
-            })
-        })?.filter_map(Result::ok).collect();
-        let indexes_sql = format!("select * from sqlite_master where type = 'index' and tbl_name = '{}' and not name like 'sqlite%'", table_name);
+                default: self.dialect().column_default(default).map(Cow::Owned),
+                auto_increment: false,
+                on_update: None,
+            });
+        }
+        let indexes_sql = self.dialect().table_indexes_statement(table_name);
         let mut indexes_statement = self.prepare(&indexes_sql)?;
         let indexes_rows = indexes_statement.query_map((), |row| {
             let name: String = row.get("name")?;
             let sql: String = row.get("sql")?;
             Ok((name, sql))
         })?;
-        let indexes: Vec<IndexDef> = indexes_rows.filter_map(Result::ok).filter_map(|(name, sql)| {
-            let columns = if let Some(start) = sql.find('(') && let Some(end) = sql.find(')') {
-                let cols_def = &sql[start + 1..end];
-                cols_def.split(",").map(|col| {
-                    let trimmed = col.trim();
-                    let mut name_and_order = trimmed.split(" ");
-                    let name = name_and_order.nth(0).unwrap().trim();
-                    let order = name_and_order.nth(1).unwrap().trim();
-                    IndexColumnDef {
-                        name: Cow::Owned(name.to_string()),
-                        order: SortOrder::from_str(order).unwrap()
-                    }
-                }).collect()
-            } else {
-                vec![]
-            };
-            Some(IndexDef {
-                name: Cow::Owned(name.to_string()),
-                columns
-            })
-        }).collect();
+        let mut indexes = vec![];
+        for index_row in indexes_rows {
+            let (name, sql) = index_row?;
+            indexes.push(IndexDef {
+                name: Cow::Owned(name),
+                columns: self.dialect().index_columns(&sql),
+                unique: self.dialect().index_unique(&sql),
+                predicate: self.dialect().index_predicate(&sql).map(Cow::Owned),
+            });
+        }
+        let foreign_keys_sql = self.dialect().table_foreign_keys_statement(table_name);
+        let mut foreign_keys_statement = self.prepare(&foreign_keys_sql)?;
+        let foreign_keys = foreign_keys_statement.query_map((), |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })?.collect::<rusqlite::Result<Vec<_>>>()?;
         Ok(TableDef {
             name: Cow::Owned(table_name.to_string()),
             columns,
             indexes,
+            foreign_keys: collect_foreign_keys(table_name, foreign_keys, false),
         })
     }
 }
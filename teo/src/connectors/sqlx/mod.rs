@@ -0,0 +1,29 @@
+macro_rules! impl_async_connection {
+    ($database:ty, $connection:ty) => {
+        impl crate::connection::AsyncConnection for ::sqlx::Pool<$database> {
+
+            async fn migrate_with_observer<S>(&mut self, observer: &mut dyn crate::migration::MigrationObserver) -> crate::error::Result<crate::migration::MigrationReport> where S: crate::types::Schema {
+                let mut connection = self.acquire().await?;
+                <$connection as crate::connection::AsyncConnection>::migrate_with_observer::<S>(&mut *connection, observer).await
+            }
+        }
+
+        impl crate::connection::AsyncConnection for ::sqlx::pool::PoolConnection<$database> {
+
+            async fn migrate_with_observer<S>(&mut self, observer: &mut dyn crate::migration::MigrationObserver) -> crate::error::Result<crate::migration::MigrationReport> where S: crate::types::Schema {
+                <$connection as crate::connection::AsyncConnection>::migrate_with_observer::<S>(&mut **self, observer).await
+            }
+        }
+
+        impl crate::connection::AsyncConnection for ::sqlx::Transaction<'_, $database> {
+
+            async fn migrate_with_observer<S>(&mut self, observer: &mut dyn crate::migration::MigrationObserver) -> crate::error::Result<crate::migration::MigrationReport> where S: crate::types::Schema {
+                <$connection as crate::connection::AsyncConnection>::migrate_with_observer::<S>(&mut **self, observer).await
+            }
+        }
+    };
+}
+
+mod mysql;
+mod postgres;
+mod sqlite;
@@ -0,0 +1,92 @@
+use sqlx::{AssertSqlSafe, ColumnIndex, MySqlConnection, Row, mysql::MySqlRow};
+use teo_column_type::mysql;
+use crate::{connection::AsyncConnection, dialect::{MySQL, MySQLColumnRow, MySQLIndexRow}, error::{Error, Result}, migration::{AsyncMigration, AsyncSQLMigration, MigrationObserver, MigrationReport, TableDef}, types::Schema};
+
+impl_async_connection!(sqlx::MySql, MySqlConnection);
+
+impl AsyncConnection for MySqlConnection {
+
+    async fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        let version: Option<String> = sqlx::query_scalar(MySQL::version_statement()).fetch_optional(&mut *self).await?;
+        let dialect = MySQL::from_server_version(&version.unwrap_or_default());
+        AsyncMigration::migrate::<S>(&mut Migrator { conn: self, dialect }, observer).await
+    }
+}
+
+struct Migrator<'a> {
+    conn: &'a mut MySqlConnection,
+    dialect: MySQL,
+}
+
+impl AsyncSQLMigration for Migrator<'_> {
+
+    type Dialect = MySQL;
+
+    #[inline]
+    fn dialect(&self) -> MySQL {
+        self.dialect
+    }
+
+    #[inline]
+    async fn execute_without_params(&mut self, q: &str) -> Result<()> {
+        sqlx::raw_sql(AssertSqlSafe(q)).execute(&mut *self.conn).await?;
+        Ok(())
+    }
+
+    async fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        let statement = self.dialect.table_names_statement();
+        let rows = sqlx::query(statement).fetch_all(&mut *self.conn).await?;
+        self.dialect.table_names(rows.iter().map(|row| text(row, 0).flatten()).collect())
+    }
+
+    async fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<mysql::ColumnType>> {
+        let columns_statement = self.dialect.table_columns_statement(table_name);
+        let column_rows = sqlx::query(AssertSqlSafe(columns_statement)).fetch_all(&mut *self.conn).await?;
+        let columns = column_rows.iter().map(|row| Ok(MySQLColumnRow {
+            name: get(row, table_name, "Field")?,
+            ty: get(row, table_name, "Type")?,
+            nullable: get(row, table_name, "Null")?,
+            default: text(row, "Default").flatten(),
+            extra: get(row, table_name, "Extra")?,
+            auto_increment: row.try_get::<i64, _>("AutoIncrement").map_err(|_| Error::invalid_catalog(table_name, "AutoIncrement"))? != 0,
+        })).collect::<Result<_>>()?;
+        let check_clauses = match self.dialect.json_columns_statement(table_name) {
+            Some(json_statement) => {
+                let json_rows = sqlx::query(AssertSqlSafe(json_statement)).fetch_all(&mut *self.conn).await?;
+                json_rows.iter().filter_map(|row| text(row, 0).flatten()).collect()
+            },
+            None => Vec::new(),
+        };
+        let index_statement = self.dialect.table_indexes_statement(table_name);
+        let index_rows = sqlx::query(AssertSqlSafe(index_statement)).fetch_all(&mut *self.conn).await?;
+        let indexes = index_rows.iter().map(|row| Ok(MySQLIndexRow {
+            name: get(row, table_name, "Key_name")?,
+            column: get(row, table_name, "Column_name")?,
+            collation: text(row, "Collation").ok_or_else(|| Error::invalid_catalog(table_name, "Collation"))?,
+            non_unique: row.try_get::<i64, _>("Non_unique").map_err(|_| Error::invalid_catalog(table_name, "Non_unique"))? != 0,
+        })).collect::<Result<_>>()?;
+        let foreign_keys_statement = self.dialect.table_foreign_keys_statement(table_name);
+        let foreign_key_rows = sqlx::query(AssertSqlSafe(foreign_keys_statement)).fetch_all(&mut *self.conn).await?;
+        let mut foreign_keys = vec![];
+        for row in &foreign_key_rows {
+            let column = |index: usize| text(row, index).flatten().ok_or_else(|| Error::invalid_catalog(table_name, "foreign key"));
+            foreign_keys.push((column(0)?, column(1)?, column(2)?, column(3)?));
+        }
+        self.dialect.table_def(table_name, columns, &check_clauses, indexes, foreign_keys)
+    }
+}
+
+// MySQL reports some catalog columns as binary strings, so fall back to bytes.
+fn text<I>(row: &MySqlRow, index: I) -> Option<Option<String>> where I: ColumnIndex<MySqlRow> + Copy {
+    if let Ok(value) = row.try_get::<Option<String>, _>(index) {
+        Some(value)
+    } else if let Ok(value) = row.try_get::<Option<Vec<u8>>, _>(index) {
+        value.map(String::from_utf8).transpose().ok()
+    } else {
+        None
+    }
+}
+
+fn get(row: &MySqlRow, table_name: &str, field: &str) -> Result<String> {
+    text(row, field).flatten().ok_or_else(|| Error::invalid_catalog(table_name, field))
+}
@@ -0,0 +1,113 @@
+use std::{borrow::Cow, str::FromStr};
+use sqlx::{AssertSqlSafe, PgConnection, Row};
+use teo_column_type::postgres;
+use crate::{connection::AsyncConnection, dialect::{Dialect, Postgres}, error::{Error, Result}, migration::{collect_foreign_keys, AsyncMigration, AsyncSQLMigration, ColumnDef, EnumDef, IndexDef, MigrationObserver, MigrationReport, TableDef}, types::Schema};
+
+impl_async_connection!(sqlx::Postgres, PgConnection);
+
+impl AsyncConnection for PgConnection {
+
+    async fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        AsyncMigration::migrate::<S>(self, observer).await
+    }
+}
+
+impl AsyncSQLMigration for PgConnection {
+
+    type Dialect = Postgres;
+
+    #[inline]
+    fn dialect(&self) -> Postgres {
+        Postgres
+    }
+
+    #[inline]
+    async fn execute_without_params(&mut self, q: &str) -> Result<()> {
+        sqlx::raw_sql(AssertSqlSafe(q)).execute(&mut *self).await?;
+        Ok(())
+    }
+
+    async fn exist_enum_names(&mut self) -> Result<Vec<String>> {
+        let statement = self.dialect().enum_names_statement();
+        let rows = sqlx::query(statement).fetch_all(&mut *self).await?;
+        let mut enum_names = vec![];
+        for row in rows {
+            let enum_name: String = row.try_get("enum_type")?;
+            if !enum_name.starts_with("_") {
+                enum_names.push(enum_name);
+            }
+        }
+        Ok(enum_names)
+    }
+
+    async fn exist_enum_def(&mut self, enum_name: &'static str) -> Result<EnumDef> {
+        let statement = self.dialect().enum_variants_statement(enum_name);
+        let rows = sqlx::query(AssertSqlSafe(statement)).fetch_all(&mut *self).await?;
+        let mut variants = vec![];
+        for row in rows {
+            let variant: String = row.try_get("variant")?;
+            variants.push(Cow::Owned(variant));
+        }
+        Ok(EnumDef {
+            name: enum_name,
+            variants,
+        })
+    }
+
+    async fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        let statement = self.dialect().table_names_statement();
+        let rows = sqlx::query(statement).fetch_all(&mut *self).await?;
+        let mut table_names = vec![];
+        for row in rows {
+            let table_name: String = row.try_get("tablename")?;
+            table_names.push(table_name);
+        }
+        Ok(table_names)
+    }
+
+    async fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<postgres::ColumnType>> {
+        let columns_statement = self.dialect().table_columns_statement(table_name);
+        let column_rows = sqlx::query(AssertSqlSafe(columns_statement)).fetch_all(&mut *self).await?;
+        let mut columns = vec![];
+        for row in &column_rows {
+            let name: String = row.try_get("column_name")?;
+            let column_default: Option<String> = row.try_get("column_default")?;
+            let is_nullable: String = row.try_get("is_nullable")?;
+            let udt_name: String = row.try_get("udt_name")?;
+            let ty = postgres::ColumnType::from_str(&udt_name).map_err(|e| Error::unknown_column_type(table_name, &name, e))?;
+            columns.push(ColumnDef {
+                name: Cow::Owned(name),
+                ty,
+                nullable: is_nullable == "YES",
+                default: column_default.map(Cow::Owned),
+                auto_increment: false,
+                on_update: None,
+            });
+        }
+        let indexes_statement = self.dialect().table_indexes_statement(table_name);
+        let index_rows = sqlx::query(AssertSqlSafe(indexes_statement)).fetch_all(&mut *self).await?;
+        let mut indexes = vec![];
+        for index_row in &index_rows {
+            let name: String = index_row.try_get("indexname")?;
+            let def: String = index_row.try_get("indexdef")?;
+            indexes.push(IndexDef {
+                name: Cow::Owned(name),
+                columns: self.dialect().index_columns(&def),
+                unique: self.dialect().index_unique(&def),
+                predicate: self.dialect().index_predicate(&def).map(Cow::Owned),
+            });
+        }
+        let foreign_keys_statement = self.dialect().table_foreign_keys_statement(table_name);
+        let foreign_key_rows = sqlx::query(AssertSqlSafe(foreign_keys_statement)).fetch_all(&mut *self).await?;
+        let mut foreign_keys = vec![];
+        for row in &foreign_key_rows {
+            foreign_keys.push((row.try_get(0)?, row.try_get(1)?, row.try_get(2)?, row.try_get(3)?));
+        }
+        Ok(TableDef {
+            name: Cow::Owned(table_name.to_string()),
+            columns,
+            indexes,
+            foreign_keys: collect_foreign_keys(table_name, foreign_keys, true),
+        })
+    }
+}
@@ -0,0 +1,88 @@
+use std::{borrow::Cow, str::FromStr};
+use sqlx::{AssertSqlSafe, Row, SqliteConnection};
+use teo_column_type::sqlite;
+use crate::{connection::AsyncConnection, dialect::{Dialect, SQLite}, error::{Error, Result}, migration::{collect_foreign_keys, AsyncMigration, AsyncSQLMigration, ColumnDef, IndexDef, MigrationObserver, MigrationReport, TableDef}, types::Schema};
+
+impl_async_connection!(sqlx::Sqlite, SqliteConnection);
+
+impl AsyncConnection for SqliteConnection {
+
+    async fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        AsyncMigration::migrate::<S>(self, observer).await
+    }
+}
+
+impl AsyncSQLMigration for SqliteConnection {
+
+    type Dialect = SQLite;
+
+    #[inline]
+    fn dialect(&self) -> SQLite {
+        SQLite
+    }
+
+    #[inline]
+    async fn execute_without_params(&mut self, q: &str) -> Result<()> {
+        sqlx::raw_sql(AssertSqlSafe(q)).execute(&mut *self).await?;
+        Ok(())
+    }
+
+    async fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        let statement = self.dialect().table_names_statement();
+        let rows = sqlx::query(statement).fetch_all(&mut *self).await?;
+        let mut table_names = vec![];
+        for row in rows {
+            let name: String = row.try_get(0)?;
+            if !name.starts_with("_") {
+                table_names.push(name);
+            }
+        }
+        Ok(table_names)
+    }
+
+    async fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<sqlite::ColumnType>> {
+        let column_statement = self.dialect().table_columns_statement(table_name);
+        let column_rows = sqlx::query(AssertSqlSafe(column_statement)).fetch_all(&mut *self).await?;
+        let mut columns = vec![];
+        for row in &column_rows {
+            let name: String = row.try_get("name")?;
+            let ty: String = row.try_get("type")?;
+            let notnull: i64 = row.try_get("notnull")?;
+            let default: Option<String> = row.try_get("dflt_value")?;
+            let ty = sqlite::ColumnType::from_str(&ty).map_err(|e| Error::unknown_column_type(table_name, &name, e))?;
+            columns.push(ColumnDef {
+                name: Cow::Owned(name),
+                ty,
+                nullable: notnull == 0,
+                default: self.dialect().column_default(default).map(Cow::Owned),
+                auto_increment: false,
+                on_update: None,
+            });
+        }
+        let indexes_statement = self.dialect().table_indexes_statement(table_name);
+        let index_rows = sqlx::query(AssertSqlSafe(indexes_statement)).fetch_all(&mut *self).await?;
+        let mut indexes = vec![];
+        for row in &index_rows {
+            let name: String = row.try_get("name")?;
+            let sql: String = row.try_get("sql")?;
+            indexes.push(IndexDef {
+                name: Cow::Owned(name),
+                columns: self.dialect().index_columns(&sql),
+                unique: self.dialect().index_unique(&sql),
+                predicate: self.dialect().index_predicate(&sql).map(Cow::Owned),
+            });
+        }
+        let foreign_keys_statement = self.dialect().table_foreign_keys_statement(table_name);
+        let foreign_key_rows = sqlx::query(AssertSqlSafe(foreign_keys_statement)).fetch_all(&mut *self).await?;
+        let mut foreign_keys = vec![];
+        for row in &foreign_key_rows {
+            foreign_keys.push((row.try_get(0)?, row.try_get(1)?, row.try_get(2)?, row.try_get(3)?));
+        }
+        Ok(TableDef {
+            name: Cow::Owned(table_name.to_string()),
+            columns,
+            indexes,
+            foreign_keys: collect_foreign_keys(table_name, foreign_keys, false),
+        })
+    }
+}
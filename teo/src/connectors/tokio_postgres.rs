@@ -1,40 +1,152 @@
 use std::{borrow::Cow, str::FromStr};
 use teo_column_type::postgres;
-use tokio_postgres::{Client, Error};
-use crate::{connection::AsyncConnection, migration::{AsyncMigration, ColumnDef, EnumDef, IndexColumnDef, IndexDef, TableDef}, types::{Schema, SortOrder}};
+use futures::{StreamExt, TryFutureExt, stream::BoxStream};
+use tokio_postgres::{Client, types::ToSql};
+use crate::{connection::{AsyncConnection, AsyncExecutor, AsyncTransactional, IsolationLevel, StreamExecutor}, dialect::{Dialect, Postgres}, error::{Error, Result}, migration::{collect_foreign_keys, AsyncMigration, AsyncSQLMigration, ColumnDef, EnumDef, IndexDef, MigrationObserver, MigrationReport, TableDef}, query::{Aggregate, Delete, Select, Update, Upsert, sql::{AsyncSQLExecutor, select_statement}}, trace, types::{Entity, FromRow, Schema, Value}};
 
 impl AsyncConnection for Client {
 
-    type Err = Error;
+    async fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        AsyncMigration::migrate::<S>(self, observer).await
+    }
+}
+
+impl AsyncExecutor for Client {
+
+    async fn insert<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        AsyncSQLExecutor::insert_rows::<E>(self, rows).await
+    }
+
+    async fn find<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow + Send {
+        AsyncSQLExecutor::find_entities(self, select).await
+    }
+
+    async fn upsert(&mut self, upsert: &Upsert) -> Result<u64> {
+        AsyncSQLExecutor::upsert_entities(self, upsert).await
+    }
 
-    async fn migrate<S>(&mut self) -> Result<(), Self::Err> where S: Schema {
-        AsyncMigration::migrate::<S>(self).await
+    async fn update(&mut self, update: &Update) -> Result<u64> {
+        AsyncSQLExecutor::update_entities(self, update).await
+    }
+
+    async fn delete(&mut self, delete: &Delete) -> Result<u64> {
+        AsyncSQLExecutor::delete_entities(self, delete).await
+    }
+
+    async fn delete_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow + Send {
+        AsyncSQLExecutor::delete_entities_returning(self, delete).await
+    }
+
+    async fn aggregate(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        AsyncSQLExecutor::aggregate_values(self, aggregate).await
+    }
+
+    async fn query_raw<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow + Send {
+        AsyncSQLExecutor::query_raw_entities(self, q, params).await
+    }
+
+    async fn execute_raw(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        AsyncSQLExecutor::execute_raw_statement(self, q, params).await
+    }
+
+    fn max_params(&self) -> usize {
+        Postgres.max_params()
     }
 }
 
-impl AsyncMigration for Client {
+impl StreamExecutor for Client {
 
-    type Err = Error;
+    fn stream<'a, E>(&'a mut self, select: &Select) -> BoxStream<'a, Result<E>> where E: FromRow + Send + 'static {
+        let statement = select_statement(Postgres, select);
+        let traced = trace::statement_stream(Postgres.name(), Some(select.table), &statement.sql, &statement.params);
+        traced(async move {
+            let rows = Client::query_raw(self, &statement.sql, &statement.params).await?;
+            Ok::<_, Error>(rows.map(|row| E::from_row(&row?)))
+        }.try_flatten_stream().boxed())
+    }
+}
 
-    type ColumnType = postgres::ColumnType;
+impl AsyncTransactional for Client {
 
-    #[inline]
-    async fn execute_without_params(&mut self, q: &str) -> Result<(), Self::Err> {
-        self.execute(q, &[]).await.map(|_| ())
+    async fn begin(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        AsyncSQLExecutor::begin_transaction(self, isolation).await
+    }
+
+    async fn commit(&mut self) -> Result<()> {
+        AsyncSQLExecutor::transaction_statement(self, "commit").await
     }
 
+    async fn rollback(&mut self) -> Result<()> {
+        AsyncSQLExecutor::transaction_statement(self, "rollback").await
+    }
+
+    async fn savepoint(&mut self, name: &str) -> Result<()> {
+        AsyncSQLExecutor::savepoint_statement(self, "savepoint", name).await
+    }
+
+    async fn release_savepoint(&mut self, name: &str) -> Result<()> {
+        AsyncSQLExecutor::savepoint_statement(self, "release savepoint", name).await
+    }
+
+    async fn rollback_to_savepoint(&mut self, name: &str) -> Result<()> {
+        AsyncSQLExecutor::savepoint_statement(self, "rollback to savepoint", name).await
+    }
+}
+
+impl AsyncSQLExecutor for Client {
+
+    type Dialect = Postgres;
+
+    async fn execute(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p as &(dyn ToSql + Sync)).collect();
+        Ok(Client::execute(self, q, &params).await?)
+    }
+
+    async fn query_column(&mut self, q: &str, params: &[Value]) -> Result<Vec<Value>> {
+        let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p as &(dyn ToSql + Sync)).collect();
+        let rows = self.query(q, &params).await?;
+        let mut values = vec![];
+        for row in rows {
+            values.push(row.try_get(0)?);
+        }
+        Ok(values)
+    }
+
+    async fn query_entities<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow + Send {
+        let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p as &(dyn ToSql + Sync)).collect();
+        let rows = self.query(q, &params).await?;
+        rows.iter().map(|row| E::from_row(row)).collect()
+    }
+
+    async fn query_rows(&mut self, q: &str, params: &[Value]) -> Result<Vec<Vec<Value>>> {
+        let params: Vec<&(dyn ToSql + Sync)> = params.iter().map(|p| p as &(dyn ToSql + Sync)).collect();
+        let rows = self.query(q, &params).await?;
+        rows.iter().map(|row| (0..row.len()).map(|index| Ok(row.try_get(index)?)).collect()).collect()
+    }
+
+    async fn execute_batch(&mut self, q: &str) -> Result<()> {
+        self.batch_execute(q).await?;
+        Ok(())
+    }
+}
+
+impl AsyncSQLMigration for Client {
+
+    type Dialect = Postgres;
+
     #[inline]
-    fn ident_quote_char() -> &'static str {
-        "\""
+    fn dialect(&self) -> Postgres {
+        Postgres
     }
 
     #[inline]
-    fn string_quote_char() -> &'static str {
-        "'"
+    async fn execute_without_params(&mut self, q: &str) -> Result<()> {
+        self.execute(q, &[]).await?;
+        Ok(())
     }
 
-    async fn exist_enum_names(&mut self) -> Result<Vec<String>, Self::Err> {
-        let statement = r#"select distinct pg_type.typname as enum_type from pg_type join pg_enum on pg_enum.enumtypid = pg_type.oid"#;
+    async fn exist_enum_names(&mut self) -> Result<Vec<String>> {
+        let statement = self.dialect().enum_names_statement();
         let rows = self.query(statement, &[]).await?;
         let mut enum_names = vec![];
         for row in rows {
@@ -46,31 +158,8 @@ impl AsyncMigration for Client {
         Ok(enum_names)
     }
 
-    fn enum_create_statement(&self, enum_def: &crate::migration::EnumDef) -> String {
-        let variants: Vec<String> = enum_def.variants.iter().map(|v| format!("{}{}{}", Self::string_quote_char(), v, Self::string_quote_char())).collect();
-        format!(r#"create type {}{}{} as enum({})"#,
-            Self::ident_quote_char(),
-            enum_def.name,
-            Self::ident_quote_char(),
-            variants.join(","))
-    }
-
-    fn enum_drop_statement(&self, enum_name: &str) -> String {
-        format!(r#"drop type if exists {}{}{}"#, Self::ident_quote_char(), enum_name, Self::ident_quote_char())
-    }
-
-    fn add_enum_variant_statement(&self, enum_name: &str, variant_name: &str) -> String {
-        format!(r#"alter type {}{}{} add value {}{}{}"#,
-            Self::ident_quote_char(),
-            enum_name,
-            Self::ident_quote_char(),
-            Self::string_quote_char(),
-            variant_name,
-            Self::string_quote_char())
-    }
-
-    async fn exist_enum_def(&mut self, enum_name: &'static str) -> Result<EnumDef, Self::Err> {
-        let statement = format!("select pg_enum.enumlabel as variant from pg_type join pg_enum on pg_enum.enumtypid = pg_type.oid where pg_type.typname = '{}'", enum_name);
+    async fn exist_enum_def(&mut self, enum_name: &'static str) -> Result<EnumDef> {
+        let statement = self.dialect().enum_variants_statement(enum_name);
         let rows = self.query(&statement, &[]).await?;
         let mut variants = vec![];
         for row in rows {
@@ -83,8 +172,8 @@ impl AsyncMigration for Client {
         })
     }
 
-    async fn exist_table_names(&mut self) -> Result<Vec<String>, Self::Err> {
-        let statement = "select tablename from pg_catalog.pg_tables where schemaname != 'pg_catalog' and schemaname != 'information_schema'";
+    async fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        let statement = self.dialect().table_names_statement();
         let rows = self.query(
             statement,
             &[],
@@ -97,35 +186,8 @@ impl AsyncMigration for Client {
         Ok(table_names)
     }
 
-    fn create_table_statement(&self, table_def: &TableDef<Self::ColumnType>) -> String {
-        let columns: Vec<String> = table_def.columns.iter().map(|c| self.column_statement(c)).collect();
-        let columns_joined = columns.join(",");
-        format!(r#"create table if not exists {}{}{}({})"#,
-            Self::ident_quote_char(),
-            table_def.name,
-            Self::ident_quote_char(),
-            columns_joined)
-    }
-
-    fn column_statement(&self, column_def: &ColumnDef<Self::ColumnType>) -> String {
-        let not_null = if column_def.nullable { "" } else { " not null" };
-        let default = if let Some(default) = &column_def.default { format!(" default {}", default) } else { "".to_owned() };
-        format!(r#"{}{}{} {}{}{}"#,
-            Self::ident_quote_char(),
-            column_def.name,
-            Self::ident_quote_char(),
-            column_def.ty.to_string(),
-            not_null,
-            default)
-    }
-
-    #[inline]
-    fn defined_table_defs<S>(&self) -> Vec<TableDef<postgres::ColumnType>> where S: Schema {
-        S::postgres_table_defs()
-    }
-
-    async fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<Self::ColumnType>, Self::Err> {
-        let columns_statement = format!("select * from information_schema.columns where table_name = '{table_name}'");
+    async fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<postgres::ColumnType>> {
+        let columns_statement = self.dialect().table_columns_statement(table_name);
         let column_rows = self.query(
             &columns_statement,
             &[]
@@ -136,62 +198,46 @@ impl AsyncMigration for Client {
             let column_default: Option<String> = row.try_get("column_default")?;
             let is_nullable: String = row.try_get("is_nullable")?;
             let udt_name: String = row.try_get("udt_name")?;
+            let ty = postgres::ColumnType::from_str(&udt_name).map_err(|e| Error::unknown_column_type(table_name, &name, e))?;
             columns.push(ColumnDef {
                 name: Cow::Owned(name),
-                ty: postgres::ColumnType::from_str(&udt_name).unwrap(),
+                ty,
                 nullable: is_nullable == "YES",
-                default: column_default.map(Cow::Owned)
+                default: column_default.map(Cow::Owned),
+                auto_increment: false,
+                on_update: None,
             });
         }
-        let indexes_statement = format!("select * from pg_indexes where tablename = '{table_name}' and not indexname like '%_pkey'");
+        let indexes_statement = self.dialect().table_indexes_statement(table_name);
         let index_rows = self.query(
             &indexes_statement,
             &[]
         ).await?;
         let mut indexes = vec![];
         for index_row in &index_rows {
+            let name: String = index_row.try_get("indexname")?;
             let def: String = index_row.try_get("indexdef")?;
-            let cols = if let Some(start) = def.find('(') && let Some(end) = def.find(')') {
-                let cols_def = &def[start + 1..end];
-                cols_def.split(",").map(|s| {
-                    let trimmed = s.trim();
-                    if trimmed.contains(" ") {
-                        let mut name_and_order = trimmed.split(" ");
-                        let name = name_and_order.nth(0).unwrap().trim();
-                        let order = name_and_order.nth(1).unwrap().trim();
-                        let sort_order = if order == "DESC" { SortOrder::Desc } else { SortOrder::Asc };
-                        let clear_name = if name.starts_with("\"") && name.ends_with("\"") {
-                            &name[1..name.len() - 1]
-                        } else {
-                            name
-                        };
-                        IndexColumnDef { name: Cow::Owned(clear_name.to_string()), order: sort_order }
-                    } else {
-                        if trimmed.starts_with("\"") && trimmed.ends_with("\"") {
-                            IndexColumnDef {
-                                name: Cow::Owned(trimmed[1..trimmed.len() - 1].to_string()),
-                                order: SortOrder::Asc,
-                            }
-                        } else {
-                            IndexColumnDef {
-                                name: Cow::Owned(trimmed.to_string()),
-                                order: SortOrder::Asc,
-                            }
-                        }
-                    }
-                }).collect()
-            } else {
-                vec![]
-            };
             indexes.push(IndexDef {
-                name: Cow::Owned(index_row.get("indexname")),
-                columns: cols,
+                name: Cow::Owned(name),
+                columns: self.dialect().index_columns(&def),
+                unique: self.dialect().index_unique(&def),
+                predicate: self.dialect().index_predicate(&def).map(Cow::Owned),
             });
         }
+        let foreign_keys_statement = self.dialect().table_foreign_keys_statement(table_name);
+        let foreign_key_rows = self.query(
+            &foreign_keys_statement,
+            &[]
+        ).await?;
+        let mut foreign_keys = vec![];
+        for row in &foreign_key_rows {
+            foreign_keys.push((row.try_get(0)?, row.try_get(1)?, row.try_get(2)?, row.try_get(3)?));
+        }
         Ok(TableDef {
             name: Cow::Owned(table_name.to_string()),
             columns,
             indexes,
+            foreign_keys: collect_foreign_keys(table_name, foreign_keys, true),
         })
     }
 }
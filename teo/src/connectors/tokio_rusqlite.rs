@@ -0,0 +1,72 @@
+use futures::{SinkExt, executor::block_on, stream::BoxStream};
+use rusqlite::params_from_iter;
+use teo_column_type::sqlite;
+use tokio_rusqlite::Connection;
+use crate::{connection::{AsyncConnection, StreamExecutor, channel_stream}, dialect::{Dialect, SQLite}, error::{Error, Result}, migration::{AsyncMigration, AsyncSQLMigration, MigrationObserver, MigrationReport, SyncSQLMigration, TableDef}, query::{Select, sql::select_statement}, trace, types::{FromRow, Schema}};
+
+impl AsyncConnection for Connection {
+
+    async fn migrate_with_observer<S>(&mut self, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where S: Schema {
+        AsyncMigration::migrate::<S>(self, observer).await
+    }
+}
+
+// Each statement and catalog lookup runs on the connection's thread, so that the observer is called
+// from the migrating task.
+impl AsyncSQLMigration for Connection {
+
+    type Dialect = SQLite;
+
+    #[inline]
+    fn dialect(&self) -> SQLite {
+        SQLite
+    }
+
+    async fn execute_without_params(&mut self, q: &str) -> Result<()> {
+        let q = q.to_owned();
+        self.call(move |connection| {
+            SyncSQLMigration::execute_without_params(connection, &q)
+        }).await.map_err(call_error)
+    }
+
+    async fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        self.call(|connection| {
+            SyncSQLMigration::exist_table_names(connection)
+        }).await.map_err(call_error)
+    }
+
+    async fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<sqlite::ColumnType>> {
+        let table_name = table_name.to_owned();
+        self.call(move |connection| {
+            SyncSQLMigration::exist_table_def(connection, &table_name)
+        }).await.map_err(call_error)
+    }
+}
+
+impl StreamExecutor for Connection {
+
+    // The rows are read on the connection's thread, which waits while the channel is full.
+    fn stream<'a, E>(&'a mut self, select: &Select) -> BoxStream<'a, Result<E>> where E: FromRow + Send + 'static {
+        let statement = select_statement(SQLite, select);
+        let traced = trace::statement_stream(SQLite.name(), Some(select.table), &statement.sql, &statement.params);
+        traced(channel_stream(move |mut sender| async move {
+            self.call(move |connection| {
+                let mut query = connection.prepare(&statement.sql)?;
+                let mut rows = query.query(params_from_iter(&statement.params))?;
+                while let Some(row) = rows.next()? {
+                    if block_on(sender.send(E::from_row(row))).is_err() {
+                        break;
+                    }
+                }
+                Ok(())
+            }).await.map_err(call_error)
+        }))
+    }
+}
+
+fn call_error(error: tokio_rusqlite::Error<Error>) -> Error {
+    match error {
+        tokio_rusqlite::Error::Error(error) => error,
+        error => Error::Driver(Box::new(error)),
+    }
+}
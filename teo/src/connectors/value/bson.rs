@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+use mongodb::bson::{Binary, Bson, Document, spec::BinarySubtype};
+use crate::{error::{Error, Result}, types::{Entity, Value}};
+
+pub(crate) fn to_bson(value: &Value) -> Bson {
+    match value {
+        Value::Null => Bson::Null,
+        Value::Bool(value) => Bson::Boolean(*value),
+        Value::I32(value) => Bson::Int32(*value),
+        Value::I64(value) => Bson::Int64(*value),
+        Value::F32(value) => Bson::Double(*value as f64),
+        Value::F64(value) => Bson::Double(*value),
+        Value::String(value) => Bson::String(value.clone()),
+        Value::Bytes(value) => Bson::Binary(Binary { subtype: BinarySubtype::Generic, bytes: value.clone() }),
+        #[cfg(feature = "uuid")]
+        Value::Uuid(value) => Bson::Binary(Binary::from(mongodb::bson::Uuid::from_bytes(*value.as_bytes()))),
+        #[cfg(feature = "chrono")]
+        Value::DateTime(value) => Bson::DateTime(mongodb::bson::DateTime::from_millis(value.timestamp_millis())),
+        #[cfg(feature = "bigdecimal")]
+        Value::Decimal(value) => value.to_string().parse().map(Bson::Decimal128).unwrap_or_else(|_| Bson::String(value.to_string())),
+        Value::ObjectId(value) => Bson::ObjectId(*value),
+    }
+}
+
+pub(crate) fn from_bson(bson: Bson) -> Result<Value> {
+    Ok(match bson {
+        Bson::Null => Value::Null,
+        Bson::Boolean(value) => Value::Bool(value),
+        Bson::Int32(value) => Value::I32(value),
+        Bson::Int64(value) => Value::I64(value),
+        Bson::Double(value) => Value::F64(value),
+        Bson::String(value) => Value::String(value),
+        Bson::ObjectId(value) => Value::ObjectId(value),
+        #[cfg(feature = "uuid")]
+        Bson::Binary(binary) if binary.subtype == BinarySubtype::Uuid => Value::Uuid(uuid::Uuid::from_slice(&binary.bytes).map_err(|e| Error::Driver(Box::new(e)))?),
+        Bson::Binary(binary) => Value::Bytes(binary.bytes),
+        Bson::Decimal128(value) => Value::String(value.to_string()),
+        #[cfg(feature = "chrono")]
+        Bson::DateTime(value) => Value::DateTime(chrono::DateTime::from_timestamp_millis(value.timestamp_millis()).unwrap_or(if value.timestamp_millis() < 0 { chrono::DateTime::<chrono::Utc>::MIN_UTC } else { chrono::DateTime::<chrono::Utc>::MAX_UTC })),
+        bson => Err(Error::Driver(format!("unsupported bson type {:?}", bson.element_type()).into()))?,
+    })
+}
+
+impl crate::types::Row for Document {
+
+    fn value(&self, column: &str) -> Result<Option<Value>> {
+        self.get(column).cloned().map(from_bson).transpose()
+    }
+}
+
+pub(crate) fn to_document(values: Vec<(&'static str, Value)>) -> Document {
+    values.into_iter().map(|(key, value)| (key.to_owned(), to_bson(&value))).collect()
+}
+
+pub(crate) fn inserted_keys<E>(inserted_ids: HashMap<usize, Bson>) -> Result<Vec<Value>> where E: Entity {
+    if E::auto_increment_column() != Some("_id") {
+        return Ok(Vec::new());
+    }
+    let mut inserted_ids: Vec<(usize, Bson)> = inserted_ids.into_iter().collect();
+    inserted_ids.sort_by_key(|(index, _)| *index);
+    inserted_ids.into_iter().map(|(_, id)| from_bson(id)).collect()
+}
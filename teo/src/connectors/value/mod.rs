@@ -0,0 +1,9 @@
+#[cfg(any(feature = "tokio-postgres", feature = "postgres_sync"))]
+mod postgres;
+#[cfg(any(feature = "mysql_async", feature = "mysql_sync"))]
+#[macro_use]
+mod mysql;
+#[cfg(feature = "rusqlite")]
+mod sqlite;
+#[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+pub(crate) mod bson;
@@ -0,0 +1,63 @@
+// `mysql` and `mysql_async` depend on different versions of `mysql_common`, so the
+// conversions are generated once for each crate.
+macro_rules! impl_mysql_value {
+    ($mysql:ident) => {
+        pub(crate) fn to_mysql_value(value: &crate::types::Value) -> $mysql::Value {
+            use crate::types::Value;
+            match value {
+                Value::Null => $mysql::Value::NULL,
+                Value::Bool(value) => $mysql::Value::Int(*value as i64),
+                Value::I32(value) => $mysql::Value::Int(*value as i64),
+                Value::I64(value) => $mysql::Value::Int(*value),
+                Value::F32(value) => $mysql::Value::Float(*value),
+                Value::F64(value) => $mysql::Value::Double(*value),
+                Value::String(value) => $mysql::Value::Bytes(value.as_bytes().to_vec()),
+                Value::Bytes(value) => $mysql::Value::Bytes(value.clone()),
+                #[cfg(feature = "uuid")]
+                Value::Uuid(value) => $mysql::Value::Bytes(value.to_string().into_bytes()),
+                #[cfg(feature = "chrono")]
+                Value::DateTime(value) => {
+                    use chrono::{Datelike, Timelike};
+                    $mysql::Value::Date(value.year() as u16, value.month() as u8, value.day() as u8, value.hour() as u8, value.minute() as u8, value.second() as u8, value.timestamp_subsec_micros())
+                },
+                #[cfg(feature = "bigdecimal")]
+                Value::Decimal(value) => $mysql::Value::Bytes(value.to_string().into_bytes()),
+                #[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+                Value::ObjectId(value) => $mysql::Value::Bytes(value.to_hex().into_bytes()),
+            }
+        }
+
+        impl crate::types::Row for $mysql::Row {
+
+            fn value(&self, column: &str) -> crate::Result<Option<crate::types::Value>> {
+                match self.columns_ref().iter().position(|c| c.name_str() == column) {
+                    Some(index) => Ok(Some(self.as_ref(index).cloned().map(from_mysql_value).unwrap_or(crate::types::Value::Null))),
+                    None => Ok(None),
+                }
+            }
+        }
+
+        pub(crate) fn from_mysql_value(value: $mysql::Value) -> crate::types::Value {
+            use crate::types::Value;
+            match value {
+                $mysql::Value::NULL => Value::Null,
+                $mysql::Value::Int(value) => Value::I64(value),
+                $mysql::Value::UInt(value) => i64::try_from(value).map(Value::I64).unwrap_or(Value::String(value.to_string())),
+                $mysql::Value::Float(value) => Value::F32(value),
+                $mysql::Value::Double(value) => Value::F64(value),
+                $mysql::Value::Bytes(value) => match String::from_utf8(value) {
+                    Ok(value) => Value::String(value),
+                    Err(error) => Value::Bytes(error.into_bytes()),
+                },
+                #[cfg(feature = "chrono")]
+                $mysql::Value::Date(year, month, day, hour, minute, second, micros) => {
+                    chrono::NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+                        .and_then(|date| date.and_hms_micro_opt(hour as u32, minute as u32, second as u32, micros))
+                        .map(|date| Value::DateTime(date.and_utc()))
+                        .unwrap_or(Value::Null)
+                },
+                value => Value::String(value.as_sql(true).trim_matches('\'').to_owned()),
+            }
+        }
+    };
+}
@@ -0,0 +1,164 @@
+use std::error::Error;
+use bytes::BytesMut;
+#[cfg(not(feature = "tokio-postgres"))]
+use postgres::{Row, types::{FromSql, IsNull, ToSql, Type, to_sql_checked}};
+#[cfg(feature = "tokio-postgres")]
+use tokio_postgres::{Row, types::{FromSql, IsNull, ToSql, Type, to_sql_checked}};
+use crate::types::Value;
+
+impl crate::types::Row for Row {
+
+    fn value(&self, column: &str) -> crate::Result<Option<Value>> {
+        match self.columns().iter().position(|c| c.name() == column) {
+            Some(index) => Ok(Some(self.try_get(index)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl ToSql for Value {
+
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        match self {
+            Value::Null => Ok(IsNull::Yes),
+            Value::Bool(value) => value.to_sql(ty, out),
+            Value::I32(value) => value.to_sql(ty, out),
+            Value::I64(value) => value.to_sql(ty, out),
+            Value::F32(value) => value.to_sql(ty, out),
+            Value::F64(value) => value.to_sql(ty, out),
+            Value::String(value) => value.to_sql(ty, out),
+            Value::Bytes(value) => value.to_sql(ty, out),
+            #[cfg(feature = "uuid")]
+            Value::Uuid(value) => value.to_sql(ty, out),
+            #[cfg(feature = "chrono")]
+            Value::DateTime(value) => value.to_sql(ty, out),
+            #[cfg(feature = "bigdecimal")]
+            Value::Decimal(value) => if *ty == Type::NUMERIC {
+                decimal_to_sql(value, out)
+            } else {
+                value.to_string().to_sql(ty, out)
+            },
+            #[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+            Value::ObjectId(value) => value.to_hex().to_sql(ty, out),
+        }
+    }
+
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Value {
+
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(if *ty == Type::BOOL {
+            Value::Bool(bool::from_sql(ty, raw)?)
+        } else if *ty == Type::INT2 {
+            Value::I32(i16::from_sql(ty, raw)? as i32)
+        } else if *ty == Type::INT4 {
+            Value::I32(i32::from_sql(ty, raw)?)
+        } else if *ty == Type::INT8 {
+            Value::I64(i64::from_sql(ty, raw)?)
+        } else if *ty == Type::FLOAT4 {
+            Value::F32(f32::from_sql(ty, raw)?)
+        } else if *ty == Type::FLOAT8 {
+            Value::F64(f64::from_sql(ty, raw)?)
+        } else if *ty == Type::BYTEA {
+            Value::Bytes(Vec::<u8>::from_sql(ty, raw)?)
+        } else if <String as FromSql>::accepts(ty) {
+            Value::String(String::from_sql(ty, raw)?)
+        } else {
+            Self::from_sql_extended(ty, raw)?
+        })
+    }
+
+    fn from_sql_null(_ty: &Type) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Value::Null)
+    }
+
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+}
+
+impl Value {
+
+    fn from_sql_extended(ty: &Type, raw: &[u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        #[cfg(feature = "uuid")]
+        if *ty == Type::UUID {
+            return Ok(Value::Uuid(uuid::Uuid::from_sql(ty, raw)?));
+        }
+        #[cfg(feature = "chrono")]
+        if *ty == Type::TIMESTAMPTZ {
+            return Ok(Value::DateTime(chrono::DateTime::<chrono::Utc>::from_sql(ty, raw)?));
+        }
+        #[cfg(feature = "chrono")]
+        if *ty == Type::TIMESTAMP {
+            return Ok(Value::DateTime(chrono::NaiveDateTime::from_sql(ty, raw)?.and_utc()));
+        }
+        #[cfg(feature = "bigdecimal")]
+        if *ty == Type::NUMERIC {
+            return Ok(Value::Decimal(decimal_from_sql(raw)?));
+        }
+        let _ = raw;
+        Err(format!("unsupported postgres type `{ty}`").into())
+    }
+}
+
+// Postgres sends `numeric` as base 10000 digits with a weight for the first digit.
+#[cfg(feature = "bigdecimal")]
+fn decimal_from_sql(raw: &[u8]) -> Result<bigdecimal::BigDecimal, Box<dyn Error + Sync + Send>> {
+    use bigdecimal::{BigDecimal, num_bigint::BigInt};
+    let read = |index: usize| -> Result<u16, Box<dyn Error + Sync + Send>> {
+        raw.get(index * 2..index * 2 + 2).map(|b| u16::from_be_bytes([b[0], b[1]])).ok_or_else(|| "invalid numeric".into())
+    };
+    let ndigits = read(0)? as usize;
+    let weight = read(1)? as i16 as i64;
+    let sign = read(2)?;
+    let dscale = read(3)? as i64;
+    if sign == 0xC000 {
+        return Err("numeric NaN can't be represented as a decimal".into());
+    }
+    let mut digits = BigInt::from(0);
+    for index in 0..ndigits {
+        digits = digits * 10000 + read(4 + index)?;
+    }
+    if sign == 0x4000 {
+        digits = -digits;
+    }
+    let scale = 4 * (ndigits as i64 - 1 - weight);
+    Ok(BigDecimal::new(digits, scale).with_scale(dscale))
+}
+
+#[cfg(feature = "bigdecimal")]
+fn decimal_to_sql(value: &bigdecimal::BigDecimal, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+    use bigdecimal::num_bigint::Sign;
+    let (digits, scale) = value.as_bigint_and_exponent();
+    let sign: u16 = if digits.sign() == Sign::Minus { 0x4000 } else { 0 };
+    let dscale = scale.max(0) as u16;
+    let mut exponent = -scale;
+    let shift = exponent.rem_euclid(4);
+    exponent -= shift;
+    let mut decimal = digits.magnitude().to_string();
+    decimal.extend(std::iter::repeat_n('0', shift as usize));
+    let padding = (4 - decimal.len() % 4) % 4;
+    decimal.insert_str(0, &"0".repeat(padding));
+    let mut groups: Vec<u16> = decimal.as_bytes().chunks(4).map(|c| c.iter().fold(0, |acc, d| acc * 10 + (d - b'0') as u16)).collect();
+    let leading = groups.iter().take_while(|g| **g == 0).count();
+    groups.drain(..leading);
+    let weight = exponent / 4 + groups.len() as i64 - 1;
+    while groups.last() == Some(&0) {
+        groups.pop();
+    }
+    let (weight, sign) = if groups.is_empty() { (0, 0) } else { (weight, sign) };
+    out.extend_from_slice(&(groups.len() as u16).to_be_bytes());
+    out.extend_from_slice(&(weight as i16).to_be_bytes());
+    out.extend_from_slice(&sign.to_be_bytes());
+    out.extend_from_slice(&dscale.to_be_bytes());
+    for group in groups {
+        out.extend_from_slice(&group.to_be_bytes());
+    }
+    Ok(IsNull::No)
+}
@@ -0,0 +1,49 @@
+use rusqlite::{Row, types::{FromSql, FromSqlResult, ToSql, ToSqlOutput, ValueRef}};
+use crate::types::Value;
+
+impl crate::types::Row for Row<'_> {
+
+    fn value(&self, column: &str) -> crate::Result<Option<Value>> {
+        match self.as_ref().column_index(column) {
+            Ok(index) => Ok(Some(self.get(index)?)),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+impl ToSql for Value {
+
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(match self {
+            Value::Null => ToSqlOutput::Borrowed(ValueRef::Null),
+            Value::Bool(value) => ToSqlOutput::from(*value),
+            Value::I32(value) => ToSqlOutput::from(*value),
+            Value::I64(value) => ToSqlOutput::from(*value),
+            Value::F32(value) => ToSqlOutput::from(*value as f64),
+            Value::F64(value) => ToSqlOutput::from(*value),
+            Value::String(value) => ToSqlOutput::Borrowed(ValueRef::Text(value.as_bytes())),
+            Value::Bytes(value) => ToSqlOutput::Borrowed(ValueRef::Blob(value)),
+            #[cfg(feature = "uuid")]
+            Value::Uuid(value) => ToSqlOutput::from(value.to_string()),
+            #[cfg(feature = "chrono")]
+            Value::DateTime(value) => ToSqlOutput::from(value.to_rfc3339()),
+            #[cfg(feature = "bigdecimal")]
+            Value::Decimal(value) => ToSqlOutput::from(value.to_string()),
+            #[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+            Value::ObjectId(value) => ToSqlOutput::from(value.to_hex()),
+        })
+    }
+}
+
+impl FromSql for Value {
+
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        Ok(match value {
+            ValueRef::Null => Value::Null,
+            ValueRef::Integer(value) => Value::I64(value),
+            ValueRef::Real(value) => Value::F64(value),
+            ValueRef::Text(_) => Value::String(String::column_result(value)?),
+            ValueRef::Blob(value) => Value::Bytes(value.to_vec()),
+        })
+    }
+}
@@ -0,0 +1,312 @@
+use std::{borrow::Cow, fmt::Display};
+use crate::{connection::IsolationLevel, error::{Error, Result}, migration::{ColumnDef, EnumDef, ForeignKeyDef, IndexColumnDef, IndexDef, TableDef}, types::{Schema, SortOrder}};
+
+pub(crate) trait Dialect: Copy + Send + Sync {
+
+    type ColumnType: Send + Sync + PartialEq + Display;
+
+    fn name(&self) -> &'static str;
+
+    fn ident_quote_char(&self) -> &'static str {
+        "\""
+    }
+
+    fn string_quote_char(&self) -> &'static str {
+        "'"
+    }
+
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_owned()
+    }
+
+    fn supports_returning(&self) -> bool {
+        false
+    }
+
+    fn max_params(&self) -> usize {
+        65535
+    }
+
+    fn table_defs<S>(&self) -> Vec<TableDef<Self::ColumnType>> where S: Schema;
+
+    fn enum_create_statement(&self, _enum_def: &EnumDef) -> Result<String> {
+        Err(Error::unsupported(self.name(), "create enum"))
+    }
+
+    fn enum_drop_statement(&self, _enum_name: &str) -> Result<String> {
+        Err(Error::unsupported(self.name(), "drop enum"))
+    }
+
+    fn add_enum_variant_statement(&self, _enum_name: &str, _variant_name: &str) -> Result<String> {
+        Err(Error::unsupported(self.name(), "add enum variant"))
+    }
+
+    fn drop_table_statement(&self, table_name: &str) -> Result<String> {
+        Ok(format!("drop table if exists {}{}{}", self.ident_quote_char(), table_name, self.ident_quote_char()))
+    }
+
+    fn create_table_statement(&self, table_def: &TableDef<Self::ColumnType>) -> Result<String> {
+        let mut columns: Vec<String> = table_def.columns.iter().map(|c| self.new_column_statement(c)).collect();
+        if self.inline_foreign_keys() {
+            columns.extend(table_def.foreign_keys.iter().map(|f| self.foreign_key_clause(f)));
+        }
+        let columns_joined = columns.join(",");
+        Ok(format!(r#"create table if not exists {}{}{}({})"#,
+            self.ident_quote_char(),
+            table_def.name,
+            self.ident_quote_char(),
+            columns_joined))
+    }
+
+    fn column_statement(&self, column_def: &ColumnDef<Self::ColumnType>) -> String {
+        let not_null = if column_def.nullable { "" } else { " not null" };
+        let default = if let Some(default) = &column_def.default { format!(" default {}", default) } else { "".to_owned() };
+        let on_update = if let Some(on_update) = &column_def.on_update { format!(" on update {}", on_update) } else { "".to_owned() };
+        let auto_increment = if column_def.auto_increment { self.auto_increment_clause() } else { "" };
+        format!(r#"{}{}{} {}{}{}{}{}"#,
+            self.ident_quote_char(),
+            column_def.name,
+            self.ident_quote_char(),
+            column_def.ty,
+            not_null,
+            default,
+            on_update,
+            auto_increment)
+    }
+
+    fn auto_increment_clause(&self) -> &'static str {
+        ""
+    }
+
+    // Alters restate existing columns with `column_statement`, this defines new ones.
+    fn new_column_statement(&self, column_def: &ColumnDef<Self::ColumnType>) -> String {
+        self.column_statement(column_def)
+    }
+
+    fn index_columns(&self, index_definition: &str) -> Vec<IndexColumnDef> {
+        let (index_definition, _) = split_index_predicate(index_definition);
+        let (Some(start), Some(end)) = (index_definition.find('('), index_definition.rfind(')')) else {
+            return vec![];
+        };
+        index_definition[start + 1..end].split(',').map(|column| {
+            let mut name_and_order = column.split_whitespace();
+            let name = name_and_order.next().unwrap_or_default();
+            let name = name.strip_prefix(self.ident_quote_char()).and_then(|n| n.strip_suffix(self.ident_quote_char())).unwrap_or(name);
+            let order = if name_and_order.next().is_some_and(|o| o.eq_ignore_ascii_case("desc")) { SortOrder::Desc } else { SortOrder::Asc };
+            IndexColumnDef { name: Cow::Owned(name.to_string()), order }
+        }).collect()
+    }
+
+    fn index_unique(&self, index_definition: &str) -> bool {
+        index_definition.trim_start().get(..13).is_some_and(|prefix| prefix.eq_ignore_ascii_case("create unique"))
+    }
+
+    // SQLite keeps the predicate as written.
+    fn index_predicate(&self, index_definition: &str) -> Option<String> {
+        split_index_predicate(index_definition).1.map(|predicate| predicate.trim().to_owned())
+    }
+
+    fn partial_indexes(&self) -> bool {
+        false
+    }
+
+    fn index_column_statement(&self, index_column_def: &IndexColumnDef) -> String {
+        format!("{}{}{} {}", self.ident_quote_char(), index_column_def.name, self.ident_quote_char(), index_column_def.order.as_ref())
+    }
+
+    fn create_index_statement(&self, table_name: &str, index_def: &IndexDef) -> Result<String> {
+        let columns: Vec<String> = index_def.columns.iter().map(|c| self.index_column_statement(c)).collect();
+        let columns_joined = columns.join(",");
+        let predicate = match &index_def.predicate {
+            Some(_) if !self.partial_indexes() => return Err(Error::unsupported(self.name(), "partial index")),
+            Some(predicate) => format!(" where {predicate}"),
+            None => String::new(),
+        };
+        Ok(format!("create {}index {}{}{} on {}{}{}({}){}",
+            if index_def.unique { "unique " } else { "" },
+            self.ident_quote_char(),
+            index_def.name,
+            self.ident_quote_char(),
+            self.ident_quote_char(),
+            table_name,
+            self.ident_quote_char(),
+            columns_joined,
+            predicate))
+    }
+
+    fn drop_table_column_statement(&self, table_name: &str, column_name: &str) -> Result<String> {
+        Ok(format!(r#"alter table {}{}{} drop column {}{}{}"#,
+            self.ident_quote_char(),
+            table_name,
+            self.ident_quote_char(),
+            self.ident_quote_char(),
+            column_name,
+            self.ident_quote_char()))
+    }
+
+    fn add_table_column_statement(&self, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<String> {
+        Ok(format!("alter table {}{}{} add {}",
+            self.ident_quote_char(),
+            table_name,
+            self.ident_quote_char(),
+            self.new_column_statement(column_def)))
+    }
+
+    fn alter_table_column_type_statement(&self, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<String> {
+        Ok(format!("alter table {}{}{} alter column {}{}{} type {}",
+            self.ident_quote_char(),
+            table_name,
+            self.ident_quote_char(),
+            self.ident_quote_char(),
+            column_def.name,
+            self.ident_quote_char(),
+            column_def.ty))
+    }
+
+    fn alter_table_column_set_not_null_statement(&self, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<String> {
+        Ok(format!("alter table {}{}{} alter column {}{}{} set not null",
+            self.ident_quote_char(),
+            table_name,
+            self.ident_quote_char(),
+            self.ident_quote_char(),
+            column_def.name,
+            self.ident_quote_char()))
+    }
+
+    fn alter_table_column_drop_not_null_statement(&self, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<String> {
+        Ok(format!("alter table {}{}{} alter column {}{}{} drop not null",
+            self.ident_quote_char(),
+            table_name,
+            self.ident_quote_char(),
+            self.ident_quote_char(),
+            column_def.name,
+            self.ident_quote_char()))
+    }
+
+    fn alter_table_column_set_default_statement(&self, table_name: &str, column_name: &str, default: &str) -> Result<String> {
+        Ok(format!("alter table {}{}{} alter column {}{}{} set default {}",
+            self.ident_quote_char(),
+            table_name,
+            self.ident_quote_char(),
+            self.ident_quote_char(),
+            column_name,
+            self.ident_quote_char(),
+            default))
+    }
+
+    fn alter_table_column_drop_default_statement(&self, table_name: &str, column_name: &str) -> Result<String> {
+        Ok(format!("alter table {}{}{} alter column {}{}{} drop default",
+            self.ident_quote_char(),
+            table_name,
+            self.ident_quote_char(),
+            self.ident_quote_char(),
+            column_name,
+            self.ident_quote_char()))
+    }
+
+    fn default_values_statement(&self, table_name: &str) -> String {
+        format!("insert into {}{}{} default values", self.ident_quote_char(), table_name, self.ident_quote_char())
+    }
+
+    fn insert_statement(&self, table_name: &str, columns: &[&str], rows: usize, returning: Option<&str>) -> String {
+        let q = self.ident_quote_char();
+        let mut statement = if columns.is_empty() {
+            self.default_values_statement(table_name)
+        } else {
+            let columns_joined = columns.iter().map(|c| format!("{q}{c}{q}")).collect::<Vec<_>>().join(",");
+            let values_joined = (0..rows).map(|row| {
+                let placeholders = (0..columns.len()).map(|column| self.placeholder(row * columns.len() + column + 1)).collect::<Vec<_>>().join(",");
+                format!("({placeholders})")
+            }).collect::<Vec<_>>().join(",");
+            format!("insert into {q}{table_name}{q}({columns_joined}) values {values_joined}")
+        };
+        if let Some(returning) = returning {
+            statement.push_str(&format!(" returning {q}{returning}{q}"));
+        }
+        statement
+    }
+
+    fn upsert_clause(&self, _columns: &[&str], conflict: &[&str], update: &[&str]) -> String {
+        let q = self.ident_quote_char();
+        let conflict = conflict.iter().map(|c| format!("{q}{c}{q}")).collect::<Vec<_>>().join(",");
+        if update.is_empty() && conflict.is_empty() {
+            " on conflict do nothing".to_owned()
+        } else if update.is_empty() {
+            format!(" on conflict ({conflict}) do nothing")
+        } else {
+            let assignments = update.iter().map(|c| format!("{q}{c}{q} = excluded.{q}{c}{q}")).collect::<Vec<_>>().join(",");
+            format!(" on conflict ({conflict}) do update set {assignments}")
+        }
+    }
+
+    fn begin_statements(&self, isolation: Option<IsolationLevel>) -> Result<Vec<String>> {
+        Ok(vec![match isolation {
+            Some(isolation) => format!("begin isolation level {}", isolation.as_ref()),
+            None => "begin".to_owned(),
+        }])
+    }
+
+    fn float_cast(&self, expression: &str) -> String {
+        format!("cast({expression} as double precision)")
+    }
+
+    // Whether a backslash escapes the next character of a quoted string.
+    fn backslash_escapes(&self) -> bool {
+        false
+    }
+
+    // Whether `$tag$ … $tag$` quotes a string.
+    fn dollar_quotes(&self) -> bool {
+        false
+    }
+
+    // Used when rows are skipped without a limit, some databases require both.
+    fn unbounded_limit(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn limit_clause(&self, limit: Option<u64>, offset: Option<u64>) -> String {
+        let mut clause = match (limit, offset, self.unbounded_limit()) {
+            (Some(limit), _, _) => format!(" limit {limit}"),
+            (None, Some(_), Some(unbounded)) => format!(" limit {unbounded}"),
+            _ => String::new(),
+        };
+        if let Some(offset) = offset {
+            clause.push_str(&format!(" offset {offset}"));
+        }
+        clause
+    }
+
+    // Databases which can't add a foreign key to an existing table declare them in `create table`.
+    fn inline_foreign_keys(&self) -> bool {
+        false
+    }
+
+    fn foreign_key_clause(&self, foreign_key_def: &ForeignKeyDef) -> String {
+        let q = self.ident_quote_char();
+        let columns = foreign_key_def.columns.iter().map(|c| format!("{q}{c}{q}")).collect::<Vec<_>>().join(",");
+        let referenced_columns = foreign_key_def.referenced_columns.iter().map(|c| format!("{q}{c}{q}")).collect::<Vec<_>>().join(",");
+        format!("constraint {q}{}{q} foreign key ({columns}) references {q}{}{q}({referenced_columns})", foreign_key_def.name, foreign_key_def.referenced_table)
+    }
+
+    fn add_foreign_key_statement(&self, table_name: &str, foreign_key_def: &ForeignKeyDef) -> Result<String> {
+        let q = self.ident_quote_char();
+        Ok(format!("alter table {q}{table_name}{q} add {}", self.foreign_key_clause(foreign_key_def)))
+    }
+
+    fn drop_foreign_key_statement(&self, table_name: &str, foreign_key_name: &str) -> Result<String> {
+        let q = self.ident_quote_char();
+        Ok(format!("alter table {q}{table_name}{q} drop constraint {q}{foreign_key_name}{q}"))
+    }
+
+    fn drop_index_statement(&self, _table_name: &str, index_name: &str) -> Result<String> {
+        Ok(format!("drop index if exists {}{}{}", self.ident_quote_char(), index_name, self.ident_quote_char()))
+    }
+}
+
+pub(super) fn split_index_predicate(index_definition: &str) -> (&str, Option<&str>) {
+    match index_definition.to_ascii_lowercase().find(" where ") {
+        Some(start) => (&index_definition[..start], Some(&index_definition[start + 7..])),
+        None => (index_definition, None),
+    }
+}
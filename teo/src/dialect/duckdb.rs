@@ -0,0 +1,53 @@
+use teo_column_type::duckdb::ColumnType;
+use crate::{dialect::Dialect, error::{Error, Result}, migration::{ForeignKeyDef, TableDef}, types::Schema};
+
+#[derive(Clone, Copy, Default)]
+pub(crate) struct DuckDB;
+
+impl Dialect for DuckDB {
+
+    type ColumnType = ColumnType;
+
+    #[inline]
+    fn name(&self) -> &'static str {
+        "duckdb"
+    }
+
+    #[inline]
+    fn table_defs<S>(&self) -> Vec<TableDef<ColumnType>> where S: Schema {
+        S::duckdb_table_defs()
+    }
+
+    #[inline]
+    fn inline_foreign_keys(&self) -> bool {
+        true
+    }
+
+    fn add_foreign_key_statement(&self, _table_name: &str, _foreign_key_def: &ForeignKeyDef) -> Result<String> {
+        Err(Error::unsupported(self.name(), "add foreign key"))
+    }
+
+    fn drop_foreign_key_statement(&self, _table_name: &str, _foreign_key_name: &str) -> Result<String> {
+        Err(Error::unsupported(self.name(), "drop foreign key"))
+    }
+}
+
+impl DuckDB {
+
+    pub(crate) fn table_names_statement(&self) -> &'static str {
+        "select table_name from information_schema.tables where table_schema = current_schema() and table_type = 'BASE TABLE'"
+    }
+
+    pub(crate) fn table_columns_statement(&self, table_name: &str) -> String {
+        format!("select column_name, data_type, is_nullable, column_default from information_schema.columns where table_schema = current_schema() and table_name = '{}' order by ordinal_position", table_name)
+    }
+
+    pub(crate) fn table_indexes_statement(&self, table_name: &str) -> String {
+        format!("select index_name, sql from duckdb_indexes() where schema_name = current_schema() and table_name = '{}'", table_name)
+    }
+
+    // DuckDB doesn't keep constraint names, foreign keys are grouped by their index instead.
+    pub(crate) fn table_foreign_keys_statement(&self, table_name: &str) -> String {
+        format!("select cast(constraint_index as varchar), unnest(constraint_column_names), referenced_table, unnest(referenced_column_names) from duckdb_constraints() where schema_name = current_schema() and table_name = '{}' and constraint_type = 'FOREIGN KEY' order by constraint_index", table_name)
+    }
+}
@@ -0,0 +1,20 @@
+#[allow(clippy::module_inception)]
+mod dialect;
+#[cfg(feature = "duckdb")]
+mod duckdb;
+#[cfg(feature = "mysql")]
+mod mysql;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+pub(crate) use dialect::Dialect;
+#[cfg(feature = "duckdb")]
+pub(crate) use duckdb::DuckDB;
+#[cfg(feature = "mysql")]
+pub(crate) use mysql::{MySQL, MySQLColumnRow, MySQLIndexRow};
+#[cfg(feature = "postgres")]
+pub(crate) use postgres::Postgres;
+#[cfg(feature = "sqlite")]
+pub(crate) use sqlite::SQLite;
@@ -0,0 +1,238 @@
+use std::{borrow::Cow, str::FromStr};
+use teo_column_type::mysql::{ColumnType, Variant};
+use crate::{connection::IsolationLevel, dialect::Dialect, error::{Error, Result}, migration::{collect_foreign_keys, ColumnDef, IndexColumnDef, IndexDef, TableDef}, types::{Schema, SortOrder}};
+
+// The variant only changes DDL, executors always use the default one.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct MySQL {
+    variant: Variant,
+}
+
+impl Dialect for MySQL {
+
+    type ColumnType = ColumnType;
+
+    #[inline]
+    fn name(&self) -> &'static str {
+        match self.variant {
+            Variant::MySQL => "mysql",
+            Variant::MariaDB => "mariadb",
+        }
+    }
+
+    #[inline]
+    fn ident_quote_char(&self) -> &'static str {
+        "`"
+    }
+
+    #[inline]
+    fn auto_increment_clause(&self) -> &'static str {
+        " auto_increment"
+    }
+
+    // The key is only declared once, redeclaring it on `modify column` fails.
+    fn new_column_statement(&self, column_def: &ColumnDef<ColumnType>) -> String {
+        let statement = self.column_statement(column_def);
+        if column_def.auto_increment { statement + " primary key" } else { statement }
+    }
+
+    #[inline]
+    fn table_defs<S>(&self) -> Vec<TableDef<ColumnType>> where S: Schema {
+        S::mysql_table_defs()
+    }
+
+    fn alter_table_column_type_statement(&self, table_name: &str, column_def: &ColumnDef<ColumnType>) -> Result<String> {
+        Ok(format!("alter table `{}` modify column {}", table_name, self.column_statement(column_def)))
+    }
+
+    fn alter_table_column_set_not_null_statement(&self, table_name: &str, column_def: &ColumnDef<ColumnType>) -> Result<String> {
+        self.alter_table_column_type_statement(table_name, column_def)
+    }
+
+    fn alter_table_column_drop_not_null_statement(&self, table_name: &str, column_def: &ColumnDef<ColumnType>) -> Result<String> {
+        self.alter_table_column_type_statement(table_name, column_def)
+    }
+
+    fn default_values_statement(&self, table_name: &str) -> String {
+        format!("insert into `{}` () values ()", table_name)
+    }
+
+    fn begin_statements(&self, isolation: Option<IsolationLevel>) -> Result<Vec<String>> {
+        let mut statements = Vec::new();
+        if let Some(isolation) = isolation {
+            statements.push(format!("set transaction isolation level {}", isolation.as_ref()));
+        }
+        statements.push("start transaction".to_owned());
+        Ok(statements)
+    }
+
+    // Casting to double needs MySQL 8.0.17, decimals are parsed from their text instead.
+    fn float_cast(&self, expression: &str) -> String {
+        expression.to_owned()
+    }
+
+    // Unless the server runs with `NO_BACKSLASH_ESCAPES`.
+    fn backslash_escapes(&self) -> bool {
+        true
+    }
+
+    fn unbounded_limit(&self) -> Option<&'static str> {
+        Some("18446744073709551615")
+    }
+
+    // MySQL can't name the conflict target, a collision on any unique key updates the row. Rows
+    // are kept by assigning a column to itself, the first inserted one when there's no target.
+    fn upsert_clause(&self, columns: &[&str], conflict: &[&str], update: &[&str]) -> String {
+        let kept = if conflict.is_empty() { &columns[..columns.len().min(1)] } else { conflict };
+        let assignments = if update.is_empty() {
+            kept.iter().map(|c| format!("`{c}` = `{c}`")).collect::<Vec<_>>().join(",")
+        } else {
+            update.iter().map(|c| format!("`{c}` = values(`{c}`)")).collect::<Vec<_>>().join(",")
+        };
+        format!(" on duplicate key update {assignments}")
+    }
+
+    fn drop_index_statement(&self, table_name: &str, index_name: &str) -> Result<String> {
+        match self.variant {
+            Variant::MySQL => Ok(format!("drop index `{}` on `{}`", index_name, table_name)),
+            Variant::MariaDB => Ok(format!("drop index if exists `{}` on `{}`", index_name, table_name)),
+        }
+    }
+
+    fn drop_foreign_key_statement(&self, table_name: &str, foreign_key_name: &str) -> Result<String> {
+        Ok(format!("alter table `{}` drop foreign key `{}`", table_name, foreign_key_name))
+    }
+}
+
+impl MySQL {
+
+    pub(crate) fn new(variant: Variant) -> Self {
+        Self { variant }
+    }
+
+    pub(crate) fn from_server_version(version: &str) -> Self {
+        Self::new(Variant::from_server_version(version))
+    }
+
+    pub(crate) fn version_statement() -> &'static str {
+        "select version()"
+    }
+
+    pub(crate) fn table_names_statement(&self) -> &'static str {
+        match self.variant {
+            Variant::MySQL => "show tables",
+            // MariaDB lists sequences alongside tables.
+            Variant::MariaDB => "show full tables where Table_type = 'BASE TABLE'",
+        }
+    }
+
+    pub(crate) fn table_names(&self, names: Vec<Option<String>>) -> Result<Vec<String>> {
+        names.into_iter().map(|name| name.ok_or_else(|| Error::invalid_catalog("information_schema.tables", "table_name"))).collect()
+    }
+
+    pub(crate) fn table_columns_statement(&self, table_name: &str) -> String {
+        format!("select column_name as `Field`, column_type as `Type`, is_nullable as `Null`, column_default as `Default`, extra as `Extra`, extra like '%auto_increment%' as `AutoIncrement` from information_schema.columns where table_schema = database() and table_name = '{}' order by ordinal_position", table_name)
+    }
+
+    pub(crate) fn table_indexes_statement(&self, table_name: &str) -> String {
+        format!("show indexes from `{}`", table_name)
+    }
+
+    pub(crate) fn table_foreign_keys_statement(&self, table_name: &str) -> String {
+        format!("select constraint_name, column_name, referenced_table_name, referenced_column_name from information_schema.key_column_usage where table_schema = database() and table_name = '{}' and referenced_table_name is not null order by constraint_name, ordinal_position", table_name)
+    }
+
+    // MariaDB stores JSON as LONGTEXT guarded by a `json_valid` check constraint.
+    pub(crate) fn json_columns_statement(&self, table_name: &str) -> Option<String> {
+        match self.variant {
+            Variant::MySQL => None,
+            Variant::MariaDB => Some(format!("select check_clause from information_schema.check_constraints where constraint_schema = database() and table_name = '{}' and check_clause like 'json_valid(%'", table_name)),
+        }
+    }
+
+    /// Builds the definition of an existing table out of the catalog rows. The primary key isn't
+    /// part of table definitions, its index is left out.
+    pub(crate) fn table_def(&self, table_name: &str, column_rows: Vec<MySQLColumnRow>, check_clauses: &[String], index_rows: Vec<MySQLIndexRow>, foreign_keys: Vec<(String, String, String, String)>) -> Result<TableDef<ColumnType>> {
+        let mut columns = Vec::new();
+        for row in column_rows {
+            let ty = ColumnType::from_str(&row.ty).map_err(|e| Error::unknown_column_type(table_name, &row.name, e))?;
+            let (default, on_update) = self.column_default(row.default, &row.extra);
+            columns.push(ColumnDef {
+                name: Cow::Owned(row.name),
+                ty,
+                nullable: row.nullable == "YES",
+                default: default.map(Cow::Owned),
+                auto_increment: row.auto_increment,
+                on_update: on_update.map(Cow::Owned),
+            });
+        }
+        self.resolve_json_columns(&mut columns, check_clauses);
+        let mut indexes: Vec<IndexDef> = Vec::new();
+        for row in index_rows {
+            if row.name == "PRIMARY" {
+                continue;
+            }
+            let column = IndexColumnDef {
+                name: Cow::Owned(row.column),
+                order: if row.collation.as_deref() == Some("D") { SortOrder::Desc } else { SortOrder::Asc },
+            };
+            if let Some(index) = indexes.iter_mut().find(|i| i.name == row.name) {
+                index.columns.push(column);
+            } else {
+                indexes.push(IndexDef {
+                    name: Cow::Owned(row.name),
+                    columns: vec![column],
+                    unique: !row.non_unique,
+                    predicate: None,
+                });
+            }
+        }
+        Ok(TableDef {
+            name: Cow::Owned(table_name.to_string()),
+            columns,
+            indexes,
+            foreign_keys: collect_foreign_keys(table_name, foreign_keys, true),
+        })
+    }
+
+    // Timestamp defaults come in either case, with the `on update` clause among the extras.
+    // MariaDB quotes literal defaults and spells a missing one `NULL`.
+    fn column_default(&self, default: Option<String>, extra: &str) -> (Option<String>, Option<String>) {
+        let default = match (self.variant, default) {
+            (Variant::MariaDB, Some(default)) if default == "NULL" => None,
+            (Variant::MariaDB, Some(default)) if default.len() >= 2 && default.starts_with('\'') && default.ends_with('\'') => Some(default[1..default.len() - 1].replace("''", "'")),
+            (_, default) => default,
+        };
+        let default = default.map(|default| if default.to_ascii_lowercase().starts_with("current_timestamp") { default.to_ascii_lowercase() } else { default });
+        let extra = extra.to_ascii_lowercase();
+        let on_update = extra.find("on update ").map(|start| extra[start + 10..].trim().to_owned());
+        (default, on_update)
+    }
+
+    fn resolve_json_columns(&self, columns: &mut [ColumnDef<ColumnType>], check_clauses: &[String]) {
+        let json_columns: Vec<&str> = check_clauses.iter().filter_map(|c| c.strip_prefix("json_valid(`")?.strip_suffix("`)")).collect();
+        for column in columns {
+            if column.ty == ColumnType::LongText && json_columns.contains(&column.name.as_ref()) {
+                column.ty = ColumnType::JSON;
+            }
+        }
+    }
+}
+
+/// A row of `table_columns_statement`.
+pub(crate) struct MySQLColumnRow {
+    pub(crate) name: String,
+    pub(crate) ty: String,
+    pub(crate) nullable: String,
+    pub(crate) default: Option<String>,
+    pub(crate) extra: String,
+    pub(crate) auto_increment: bool,
+}
+
+/// A row of `table_indexes_statement`.
+pub(crate) struct MySQLIndexRow {
+    pub(crate) name: String,
+    pub(crate) column: String,
+    pub(crate) collation: Option<String>,
+    pub(crate) non_unique: bool,
+}
@@ -0,0 +1,131 @@
+use teo_column_type::postgres::ColumnType;
+use crate::{dialect::{Dialect, dialect::split_index_predicate}, error::Result, migration::{EnumDef, TableDef}, types::Schema};
+
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Postgres;
+
+impl Dialect for Postgres {
+
+    type ColumnType = ColumnType;
+
+    #[inline]
+    fn name(&self) -> &'static str {
+        "postgres"
+    }
+
+    #[inline]
+    fn partial_indexes(&self) -> bool {
+        true
+    }
+
+    // Postgres wraps the predicate in parentheses and only quotes identifiers which need it, the
+    // quotes are put back so that it compares equal to the defined predicate.
+    fn index_predicate(&self, index_definition: &str) -> Option<String> {
+        let predicate = split_index_predicate(index_definition).1?.trim();
+        let predicate = predicate.strip_prefix('(').and_then(|p| p.strip_suffix(')')).unwrap_or(predicate);
+        let mut quoted = String::with_capacity(predicate.len());
+        let mut chars = predicate.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' | '"' => {
+                    quoted.push(c);
+                    for e in chars.by_ref() {
+                        quoted.push(e);
+                        if e == c {
+                            break;
+                        }
+                    }
+                },
+                c if c.is_alphabetic() || c == '_' => {
+                    let mut word = String::from(c);
+                    while let Some(&e) = chars.peek() && (e.is_alphanumeric() || e == '_' || e == '$') {
+                        word.push(e);
+                        chars.next();
+                    }
+                    if ["is", "not", "null", "and", "or", "true", "false"].iter().any(|k| word.eq_ignore_ascii_case(k)) {
+                        quoted.push_str(&word);
+                    } else {
+                        quoted.push_str(&format!("\"{word}\""));
+                    }
+                },
+                c => quoted.push(c),
+            }
+        }
+        Some(quoted)
+    }
+
+    #[inline]
+    fn placeholder(&self, index: usize) -> String {
+        format!("${index}")
+    }
+
+    #[inline]
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn dollar_quotes(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn auto_increment_clause(&self) -> &'static str {
+        " generated by default as identity"
+    }
+
+    #[inline]
+    fn table_defs<S>(&self) -> Vec<TableDef<ColumnType>> where S: Schema {
+        S::postgres_table_defs()
+    }
+
+    fn enum_create_statement(&self, enum_def: &EnumDef) -> Result<String> {
+        let variants: Vec<String> = enum_def.variants.iter().map(|v| format!("{}{}{}", self.string_quote_char(), v, self.string_quote_char())).collect();
+        Ok(format!(r#"create type {}{}{} as enum({})"#,
+            self.ident_quote_char(),
+            enum_def.name,
+            self.ident_quote_char(),
+            variants.join(",")))
+    }
+
+    fn enum_drop_statement(&self, enum_name: &str) -> Result<String> {
+        Ok(format!(r#"drop type if exists {}{}{}"#, self.ident_quote_char(), enum_name, self.ident_quote_char()))
+    }
+
+    fn add_enum_variant_statement(&self, enum_name: &str, variant_name: &str) -> Result<String> {
+        Ok(format!(r#"alter type {}{}{} add value {}{}{}"#,
+            self.ident_quote_char(),
+            enum_name,
+            self.ident_quote_char(),
+            self.string_quote_char(),
+            variant_name,
+            self.string_quote_char()))
+    }
+}
+
+impl Postgres {
+
+    pub(crate) fn enum_names_statement(&self) -> &'static str {
+        "select distinct pg_type.typname::text as enum_type from pg_type join pg_enum on pg_enum.enumtypid = pg_type.oid"
+    }
+
+    pub(crate) fn enum_variants_statement(&self, enum_name: &str) -> String {
+        format!("select pg_enum.enumlabel::text as variant from pg_type join pg_enum on pg_enum.enumtypid = pg_type.oid where pg_type.typname = '{}'", enum_name)
+    }
+
+    pub(crate) fn table_names_statement(&self) -> &'static str {
+        "select tablename::text from pg_catalog.pg_tables where schemaname != 'pg_catalog' and schemaname != 'information_schema'"
+    }
+
+    pub(crate) fn table_columns_statement(&self, table_name: &str) -> String {
+        format!("select column_name::text, column_default::text, is_nullable::text, udt_name::text from information_schema.columns where table_name = '{}'", table_name)
+    }
+
+    pub(crate) fn table_indexes_statement(&self, table_name: &str) -> String {
+        format!("select indexname::text, indexdef::text from pg_indexes where tablename = '{}' and not indexname like '%_pkey'", table_name)
+    }
+
+    pub(crate) fn table_foreign_keys_statement(&self, table_name: &str) -> String {
+        format!("select con.conname::text, att.attname::text, ref.relname::text, ref_att.attname::text from pg_constraint con join pg_class cls on cls.oid = con.conrelid join pg_class ref on ref.oid = con.confrelid cross join lateral unnest(con.conkey, con.confkey) with ordinality as keys(attnum, ref_attnum, position) join pg_attribute att on att.attrelid = con.conrelid and att.attnum = keys.attnum join pg_attribute ref_att on ref_att.attrelid = con.confrelid and ref_att.attnum = keys.ref_attnum where con.contype = 'f' and cls.relname = '{}' order by con.conname, keys.position", table_name)
+    }
+}
@@ -0,0 +1,115 @@
+use teo_column_type::sqlite::ColumnType;
+use crate::{connection::IsolationLevel, dialect::Dialect, error::{Error, Result}, migration::{ColumnDef, ForeignKeyDef, TableDef}, types::Schema};
+
+#[derive(Clone, Copy, Default)]
+pub(crate) struct SQLite;
+
+impl Dialect for SQLite {
+
+    type ColumnType = ColumnType;
+
+    #[inline]
+    fn name(&self) -> &'static str {
+        "sqlite"
+    }
+
+    #[inline]
+    fn partial_indexes(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn supports_returning(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn max_params(&self) -> usize {
+        32766
+    }
+
+    // SQLite transactions are always serializable.
+    fn begin_statements(&self, isolation: Option<IsolationLevel>) -> Result<Vec<String>> {
+        match isolation {
+            None | Some(IsolationLevel::Serializable) => Ok(vec!["begin".to_owned()]),
+            Some(_) => Err(Error::unsupported(self.name(), "isolation level")),
+        }
+    }
+
+    fn float_cast(&self, expression: &str) -> String {
+        format!("cast({expression} as real)")
+    }
+
+    #[inline]
+    fn unbounded_limit(&self) -> Option<&'static str> {
+        Some("-1")
+    }
+
+    #[inline]
+    fn auto_increment_clause(&self) -> &'static str {
+        " primary key autoincrement"
+    }
+
+    #[inline]
+    fn table_defs<S>(&self) -> Vec<TableDef<ColumnType>> where S: Schema {
+        S::sqlite_table_defs()
+    }
+
+    fn alter_table_column_type_statement(&self, _table_name: &str, _column_def: &ColumnDef<ColumnType>) -> Result<String> {
+        Err(Error::unsupported(self.name(), "alter column type"))
+    }
+
+    fn alter_table_column_set_not_null_statement(&self, _table_name: &str, _column_def: &ColumnDef<ColumnType>) -> Result<String> {
+        Err(Error::unsupported(self.name(), "alter column nullability"))
+    }
+
+    fn alter_table_column_drop_not_null_statement(&self, _table_name: &str, _column_def: &ColumnDef<ColumnType>) -> Result<String> {
+        Err(Error::unsupported(self.name(), "alter column nullability"))
+    }
+
+    fn alter_table_column_set_default_statement(&self, _table_name: &str, _column_name: &str, _default: &str) -> Result<String> {
+        Err(Error::unsupported(self.name(), "alter column default"))
+    }
+
+    fn alter_table_column_drop_default_statement(&self, _table_name: &str, _column_name: &str) -> Result<String> {
+        Err(Error::unsupported(self.name(), "alter column default"))
+    }
+
+    #[inline]
+    fn inline_foreign_keys(&self) -> bool {
+        true
+    }
+
+    fn add_foreign_key_statement(&self, _table_name: &str, _foreign_key_def: &ForeignKeyDef) -> Result<String> {
+        Err(Error::unsupported(self.name(), "add foreign key"))
+    }
+
+    fn drop_foreign_key_statement(&self, _table_name: &str, _foreign_key_name: &str) -> Result<String> {
+        Err(Error::unsupported(self.name(), "drop foreign key"))
+    }
+}
+
+impl SQLite {
+
+    pub(crate) fn table_names_statement(&self) -> &'static str {
+        "select name from sqlite_master where type='table' and not name like 'sqlite%'"
+    }
+
+    pub(crate) fn table_columns_statement(&self, table_name: &str) -> String {
+        format!("pragma table_info(\"{}\")", table_name)
+    }
+
+    // Table info leaves out the parentheses expression defaults are declared with.
+    pub(crate) fn column_default(&self, default: Option<String>) -> Option<String> {
+        default.map(|default| if !default.starts_with('\'') && default.contains('(') { format!("({default})") } else { default })
+    }
+
+    pub(crate) fn table_indexes_statement(&self, table_name: &str) -> String {
+        format!("select name, sql from sqlite_master where type = 'index' and tbl_name = '{}' and not name like 'sqlite%'", table_name)
+    }
+
+    // SQLite doesn't keep constraint names, foreign keys are grouped by their id instead.
+    pub(crate) fn table_foreign_keys_statement(&self, table_name: &str) -> String {
+        format!("select cast(id as text), \"from\", \"table\", \"to\" from pragma_foreign_key_list('{}') order by id, seq", table_name)
+    }
+}
@@ -0,0 +1,220 @@
+use std::{error::Error as StdError, fmt::{Display, Formatter, Result as FmtResult}};
+use crate::types::Value;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The database driver returned an error.
+    Driver(Box<dyn StdError + Send + Sync>),
+    /// The database reported a column type which Teo doesn't understand.
+    UnknownColumnType {
+        table: String,
+        column: String,
+        source: teo_column_type::Error,
+    },
+    /// An introspection result is missing a field or has a value of an unexpected type.
+    InvalidCatalog {
+        table: String,
+        field: String,
+    },
+    /// The entity doesn't declare a primary key.
+    MissingPrimaryKey {
+        table: String,
+    },
+    /// Teo's own metadata about a table is missing.
+    MissingMetadata {
+        table: String,
+    },
+    /// The operation can't be expressed in this database dialect.
+    Unsupported {
+        dialect: &'static str,
+        operation: &'static str,
+    },
+    /// A value couldn't be converted to the requested Rust type.
+    InvalidValue {
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// A fetched row doesn't have the column.
+    MissingColumn {
+        column: String,
+    },
+    /// A column of a fetched row couldn't be converted to the field type.
+    InvalidColumnValue {
+        column: String,
+        expected: &'static str,
+        found: &'static str,
+    },
+    /// A pagination cursor is malformed or doesn't match the query ordering.
+    InvalidCursor,
+    /// A cursor was taken from an ordering by a nullable column.
+    NullableCursorColumn {
+        column: String,
+    },
+    /// Hand-written SQL refers to a parameter which wasn't passed.
+    MissingParameter {
+        index: usize,
+    },
+    /// A parameter passed with hand-written SQL isn't referred to.
+    UnusedParameter {
+        index: usize,
+    },
+    /// A relation references columns no unique index covers.
+    NonUniqueReference {
+        table: String,
+        columns: Vec<String>,
+    },
+    /// Nested transactions are savepoints, which keep the isolation level of the outer one.
+    NestedIsolationLevel,
+    /// A change which would lose data was refused.
+    DestructiveChangeDenied {
+        table: String,
+        column: Option<String>,
+    },
+}
+
+impl Error {
+
+    pub(crate) fn unknown_column_type(table: impl Into<String>, column: impl Into<String>, source: teo_column_type::Error) -> Self {
+        Self::UnknownColumnType { table: table.into(), column: column.into(), source }
+    }
+
+    pub(crate) fn invalid_catalog(table: impl Into<String>, field: impl Into<String>) -> Self {
+        Self::InvalidCatalog { table: table.into(), field: field.into() }
+    }
+
+    pub(crate) fn missing_metadata(table: impl Into<String>) -> Self {
+        Self::MissingMetadata { table: table.into() }
+    }
+
+    pub(crate) fn invalid_value(expected: &'static str, found: &Value) -> Self {
+        Self::InvalidValue { expected, found: found.type_name() }
+    }
+
+    pub(crate) fn missing_primary_key(table: impl Into<String>) -> Self {
+        Self::MissingPrimaryKey { table: table.into() }
+    }
+
+    pub(crate) fn missing_column(column: impl Into<String>) -> Self {
+        Self::MissingColumn { column: column.into() }
+    }
+
+    pub(crate) fn nullable_cursor_column(column: impl Into<String>) -> Self {
+        Self::NullableCursorColumn { column: column.into() }
+    }
+
+    pub(crate) fn destructive_change_denied(table: impl Into<String>, column: Option<&str>) -> Self {
+        Self::DestructiveChangeDenied { table: table.into(), column: column.map(str::to_owned) }
+    }
+
+    pub(crate) fn non_unique_reference(table: impl Into<String>, columns: &[impl AsRef<str>]) -> Self {
+        Self::NonUniqueReference { table: table.into(), columns: columns.iter().map(|c| c.as_ref().to_owned()).collect() }
+    }
+
+    pub(crate) fn unsupported(dialect: &'static str, operation: &'static str) -> Self {
+        Self::Unsupported { dialect, operation }
+    }
+}
+
+impl Display for Error {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Error::Driver(error) => write!(f, "driver error: {error}"),
+            Error::UnknownColumnType { table, column, source } => write!(f, "column `{column}` of table `{table}`: {source}"),
+            Error::InvalidCatalog { table, field } => write!(f, "invalid catalog field `{field}` for table `{table}`"),
+            Error::MissingPrimaryKey { table } => write!(f, "table `{table}` has no primary key"),
+            Error::MissingMetadata { table } => write!(f, "missing metadata for table `{table}`"),
+            Error::Unsupported { dialect, operation } => write!(f, "{operation} is not supported by {dialect}"),
+            Error::InvalidValue { expected, found } => write!(f, "expected {expected} value, found {found}"),
+            Error::MissingColumn { column } => write!(f, "missing column `{column}`"),
+            Error::InvalidColumnValue { column, expected, found } => write!(f, "column `{column}`: expected {expected} value, found {found}"),
+            Error::InvalidCursor => write!(f, "invalid cursor"),
+            Error::NullableCursorColumn { column } => write!(f, "cursor column `{column}` is nullable"),
+            Error::MissingParameter { index } => write!(f, "missing parameter `${index}`"),
+            Error::UnusedParameter { index } => write!(f, "unused parameter `${index}`"),
+            Error::NonUniqueReference { table, columns } => write!(f, "columns `{}` of table `{table}` are referenced but not unique", columns.join(", ")),
+            Error::NestedIsolationLevel => write!(f, "nested transactions can't set an isolation level"),
+            Error::DestructiveChangeDenied { table, column } => if let Some(column) = column {
+                write!(f, "destructive change denied on column `{column}` of table `{table}`")
+            } else {
+                write!(f, "destructive change denied on table `{table}`")
+            },
+        }
+    }
+}
+
+impl StdError for Error {
+
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Driver(error) => Some(error.as_ref()),
+            Error::UnknownColumnType { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+impl From<mongodb::error::Error> for Error {
+    fn from(error: mongodb::error::Error) -> Self {
+        Self::Driver(Box::new(error))
+    }
+}
+
+#[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+impl From<mongodb::bson::ser::Error> for Error {
+    fn from(error: mongodb::bson::ser::Error) -> Self {
+        Self::Driver(Box::new(error))
+    }
+}
+
+#[cfg(feature = "mysql_async")]
+impl From<mysql_async::Error> for Error {
+    fn from(error: mysql_async::Error) -> Self {
+        Self::Driver(Box::new(error))
+    }
+}
+
+#[cfg(feature = "tokio-postgres")]
+impl From<tokio_postgres::Error> for Error {
+    fn from(error: tokio_postgres::Error) -> Self {
+        Self::Driver(Box::new(error))
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Self::Driver(Box::new(error))
+    }
+}
+
+#[cfg(feature = "mysql_sync")]
+impl From<mysql::Error> for Error {
+    fn from(error: mysql::Error) -> Self {
+        Self::Driver(Box::new(error))
+    }
+}
+
+#[cfg(all(feature = "postgres_sync", not(feature = "tokio-postgres")))]
+impl From<postgres::Error> for Error {
+    fn from(error: postgres::Error) -> Self {
+        Self::Driver(Box::new(error))
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl From<sqlx::Error> for Error {
+    fn from(error: sqlx::Error) -> Self {
+        Self::Driver(Box::new(error))
+    }
+}
+
+#[cfg(feature = "duckdb")]
+impl From<duckdb::Error> for Error {
+    fn from(error: duckdb::Error) -> Self {
+        Self::Driver(Box::new(error))
+    }
+}
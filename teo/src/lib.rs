@@ -2,11 +2,21 @@ pub extern crate teo_column_type;
 #[cfg(feature = "derive")]
 pub(crate) extern crate teo_derive;
 
+mod error;
 pub mod types;
 pub mod connection;
 pub mod migration;
+pub mod query;
+pub mod raw;
 
+pub(crate) mod dialect;
+#[cfg(feature = "tracing")]
+pub mod trace;
+#[cfg(not(feature = "tracing"))]
+pub(crate) mod trace;
 pub mod connectors;
 
+pub use error::{Error, Result};
+pub use types::Entity;
 #[cfg(feature = "derive")]
 pub use teo_derive::{Entity, Schema};
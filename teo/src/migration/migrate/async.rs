@@ -1,5 +1,9 @@
-use crate::{connection::AsyncConnection, types::Schema};
+use crate::{connection::AsyncConnection, error::Result, migration::{MigrationObserver, MigrationReport}, types::Schema};
 
-pub async fn migrate<C, S>(connection: &mut C) -> Result<(), C::Err> where C: AsyncConnection, S: Schema {
+pub async fn migrate<C, S>(connection: &mut C) -> Result<MigrationReport> where C: AsyncConnection, S: Schema {
     connection.migrate::<S>().await
 }
+
+pub async fn migrate_with_observer<C, S>(connection: &mut C, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where C: AsyncConnection, S: Schema {
+    connection.migrate_with_observer::<S>(observer).await
+}
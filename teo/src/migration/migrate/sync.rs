@@ -1,5 +1,9 @@
-use crate::{connection::SyncConnection, types::Schema};
+use crate::{connection::SyncConnection, error::Result, migration::{MigrationObserver, MigrationReport}, types::Schema};
 
-pub fn migrate<C, S>(connection: &mut C) -> Result<(), C::Err> where C: SyncConnection, S: Schema {
+pub fn migrate<C, S>(connection: &mut C) -> Result<MigrationReport> where C: SyncConnection, S: Schema {
     connection.migrate::<S>()
 }
+
+pub fn migrate_with_observer<C, S>(connection: &mut C, observer: &mut dyn MigrationObserver) -> Result<MigrationReport> where C: SyncConnection, S: Schema {
+    connection.migrate_with_observer::<S>(observer)
+}
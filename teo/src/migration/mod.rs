@@ -1,7 +1,16 @@
 mod types;
 mod migrate;
+#[allow(clippy::module_inception)]
 mod migration;
+mod sql;
+mod relation;
+mod report;
 
-pub use types::{EnumDef, ColumnDef, IndexColumnDef, IndexDef, TableDef};
+pub use types::{EnumDef, ColumnDef, IndexColumnDef, IndexDef, ForeignKeyDef, TableDef};
+pub use relation::{join_table_def, link_table_defs};
+pub(crate) use relation::{check_foreign_keys, collect_foreign_keys};
 pub use migrate::{sync, r#async};
+pub use report::{MigrationObserver, MigrationReport, MigrationStep, MigrationStepKind};
+pub(crate) use report::Recorder;
 pub(crate) use migration::{sync::SyncMigration, r#async::AsyncMigration};
+pub(crate) use sql::{sync::SyncSQLMigration, r#async::AsyncSQLMigration};
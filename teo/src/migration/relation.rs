@@ -0,0 +1,123 @@
+use std::borrow::Cow;
+use super::{ColumnDef, ForeignKeyDef, IndexColumnDef, IndexDef, TableDef};
+use crate::{error::{Error, Result}, types::SortOrder};
+
+pub(crate) fn foreign_key_name(table_name: &str, columns: &[Cow<'static, str>]) -> String {
+    format!("{table_name}_{}_fkey", columns.join("_"))
+}
+
+/// Builds the table linking both sides of a many-to-many relation, column `A` references
+/// the primary key of `a` and column `B` the one of `b`.
+#[doc(hidden)]
+pub fn join_table_def<T>(name: &'static str, a: TableDef<T>, a_key: &'static str, b: TableDef<T>, b_key: &'static str) -> TableDef<T> {
+    let mut columns = Vec::new();
+    let mut foreign_keys = Vec::new();
+    for (column, table_def, key) in [("A", a, a_key), ("B", b, b_key)] {
+        if let Some(key_def) = table_def.columns.into_iter().find(|c| c.name == key) {
+            columns.push(ColumnDef { name: Cow::Borrowed(column), ty: key_def.ty, nullable: false, default: None, auto_increment: false, on_update: None });
+        }
+        let foreign_key_columns = vec![Cow::Borrowed(column)];
+        foreign_keys.push(ForeignKeyDef {
+            name: Cow::Owned(foreign_key_name(name, &foreign_key_columns)),
+            columns: foreign_key_columns,
+            referenced_table: table_def.name,
+            referenced_columns: vec![Cow::Borrowed(key)],
+        });
+    }
+    let index_column = |name| IndexColumnDef { name: Cow::Borrowed(name), order: SortOrder::Asc };
+    TableDef {
+        name: Cow::Borrowed(name),
+        columns,
+        indexes: vec![IndexDef { name: Cow::Owned(format!("{name}_AB_key")), columns: vec![index_column("A"), index_column("B")], unique: true, predicate: None }],
+        foreign_keys,
+    }
+}
+
+/// Drops the join tables declared by both sides of a relation and indexes the columns foreign
+/// keys point from. Table definitions don't declare primary keys, referenced primary key columns
+/// get a unique index databases accept as the target of a foreign key.
+#[doc(hidden)]
+pub fn link_table_defs<T>(table_defs: &mut Vec<TableDef<T>>, primary_columns: &[(&'static str, Option<&'static str>)]) {
+    let mut i = 0;
+    while i < table_defs.len() {
+        if table_defs[..i].iter().any(|t| t.name == table_defs[i].name) {
+            table_defs.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    let mut referenced = Vec::new();
+    for table_def in table_defs.iter_mut() {
+        for foreign_key in &table_def.foreign_keys {
+            referenced.push((foreign_key.referenced_table.clone(), foreign_key.referenced_columns.clone()));
+            let indexed = table_def.indexes.iter().any(|index| {
+                index.columns.len() >= foreign_key.columns.len() && index.columns.iter().zip(&foreign_key.columns).all(|(c, f)| c.name == *f)
+            });
+            if !indexed {
+                table_def.indexes.push(IndexDef {
+                    name: Cow::Owned(format!("{}_{}_idx", table_def.name, foreign_key.columns.join("_"))),
+                    columns: foreign_key.columns.iter().map(|c| IndexColumnDef { name: c.clone(), order: SortOrder::Asc }).collect(),
+                    unique: false,
+                    predicate: None,
+                });
+            }
+        }
+    }
+    for (table_name, columns) in referenced {
+        let primary = primary_columns.iter().any(|(table, column)| *table == table_name && columns.len() == 1 && *column == Some(columns[0].as_ref()));
+        let Some(table_def) = table_defs.iter_mut().find(|t| t.name == table_name) else {
+            continue;
+        };
+        if primary && !unique_index(table_def, &columns) {
+            table_def.indexes.push(IndexDef {
+                name: Cow::Owned(format!("{}_{}_key", table_def.name, columns.join("_"))),
+                columns: columns.into_iter().map(|name| IndexColumnDef { name, order: SortOrder::Asc }).collect(),
+                unique: true,
+                predicate: None,
+            });
+        }
+    }
+}
+
+fn unique_index<T>(table_def: &TableDef<T>, columns: &[Cow<'static, str>]) -> bool {
+    table_def.indexes.iter().any(|index| {
+        index.unique && index.predicate.is_none() && index.columns.len() == columns.len() && index.columns.iter().zip(columns).all(|(c, r)| c.name == *r)
+    })
+}
+
+// Databases only accept foreign keys to unique columns.
+pub(crate) fn check_foreign_keys<T>(table_defs: &[TableDef<T>]) -> Result<()> {
+    for foreign_key in table_defs.iter().flat_map(|t| &t.foreign_keys) {
+        if let Some(table_def) = table_defs.iter().find(|t| t.name == foreign_key.referenced_table) &&
+                !unique_index(table_def, &foreign_key.referenced_columns) {
+            return Err(Error::non_unique_reference(table_def.name.as_ref(), &foreign_key.referenced_columns));
+        }
+    }
+    Ok(())
+}
+
+// Rows are (constraint, column, referenced table, referenced column) ordered by constraint and
+// position. Constraints without a retrievable name are named the way Teo names them.
+pub(crate) fn collect_foreign_keys(table_name: &str, rows: Vec<(String, String, String, String)>, named: bool) -> Vec<ForeignKeyDef> {
+    let mut groups: Vec<(String, ForeignKeyDef)> = Vec::new();
+    for (constraint, column, referenced_table, referenced_column) in rows {
+        match groups.last_mut() {
+            Some((last, foreign_key)) if *last == constraint => {
+                foreign_key.columns.push(Cow::Owned(column));
+                foreign_key.referenced_columns.push(Cow::Owned(referenced_column));
+            },
+            _ => groups.push((constraint.clone(), ForeignKeyDef {
+                name: Cow::Owned(constraint),
+                columns: vec![Cow::Owned(column)],
+                referenced_table: Cow::Owned(referenced_table),
+                referenced_columns: vec![Cow::Owned(referenced_column)],
+            })),
+        }
+    }
+    groups.into_iter().map(|(_, mut foreign_key)| {
+        if !named {
+            foreign_key.name = Cow::Owned(foreign_key_name(table_name, &foreign_key.columns));
+        }
+        foreign_key
+    }).collect()
+}
@@ -0,0 +1,178 @@
+use std::{fmt::{self, Display, Formatter}, time::{Duration, Instant}};
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationStepKind {
+    EnumCreated,
+    EnumAltered,
+    EnumDropped,
+    TableCreated,
+    TableDropped,
+    ColumnAdded,
+    ColumnAltered,
+    ColumnDropped,
+    IndexCreated,
+    IndexRebuilt,
+    IndexDropped,
+    ForeignKeyCreated,
+    ForeignKeyRebuilt,
+    ForeignKeyDropped,
+}
+
+impl Display for MigrationStepKind {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MigrationStepKind::EnumCreated => "enum created",
+            MigrationStepKind::EnumAltered => "enum altered",
+            MigrationStepKind::EnumDropped => "enum dropped",
+            MigrationStepKind::TableCreated => "table created",
+            MigrationStepKind::TableDropped => "table dropped",
+            MigrationStepKind::ColumnAdded => "column added",
+            MigrationStepKind::ColumnAltered => "column altered",
+            MigrationStepKind::ColumnDropped => "column dropped",
+            MigrationStepKind::IndexCreated => "index created",
+            MigrationStepKind::IndexRebuilt => "index rebuilt",
+            MigrationStepKind::IndexDropped => "index dropped",
+            MigrationStepKind::ForeignKeyCreated => "foreign key created",
+            MigrationStepKind::ForeignKeyRebuilt => "foreign key rebuilt",
+            MigrationStepKind::ForeignKeyDropped => "foreign key dropped",
+        })
+    }
+}
+
+/// A schema change applied by a migration. The target is an enum or table name, qualified with
+/// the table for columns, indexes and foreign keys.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationStep {
+    pub kind: MigrationStepKind,
+    pub target: String,
+    pub statements: Vec<String>,
+    pub elapsed: Duration,
+}
+
+impl Display for MigrationStep {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} `{}` in {:?}", self.kind, self.target, self.elapsed)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MigrationReport {
+    pub steps: Vec<MigrationStep>,
+    /// Steps the database can't apply to an existing table, like adding a foreign key on SQLite.
+    /// They come up again on every migration until the table is recreated.
+    pub skipped: Vec<MigrationStep>,
+}
+
+impl MigrationReport {
+
+    /// Whether nothing was applied, skipped steps aren't counted.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.steps.iter().map(|step| step.elapsed).sum()
+    }
+}
+
+/// Receives progress while a migration runs. Steps which turn out to need no statements are not
+/// reported.
+pub trait MigrationObserver: Send {
+
+    fn step_started(&mut self, _kind: MigrationStepKind, _target: &str) { }
+
+    fn step_finished(&mut self, _step: &MigrationStep, _error: Option<&Error>) { }
+
+    /// Asked before a table or column is dropped or a column changes type. Refusing stops the
+    /// migration with `Error::DestructiveChangeDenied`.
+    fn allow_destructive_change(&mut self, _kind: MigrationStepKind, _target: &str) -> bool {
+        true
+    }
+}
+
+impl MigrationObserver for () { }
+
+struct PendingStep {
+    kind: MigrationStepKind,
+    target: String,
+    statements: Vec<String>,
+    start: Instant,
+    skipped: bool,
+}
+
+pub(crate) struct Recorder<'a> {
+    observer: &'a mut dyn MigrationObserver,
+    pending: Option<PendingStep>,
+    report: MigrationReport,
+}
+
+impl<'a> Recorder<'a> {
+
+    pub(crate) fn new(observer: &'a mut dyn MigrationObserver) -> Self {
+        Self { observer, pending: None, report: MigrationReport::default() }
+    }
+
+    pub(crate) fn start(&mut self, kind: MigrationStepKind, target: impl Into<String>) {
+        self.pending = Some(PendingStep { kind, target: target.into(), statements: Vec::new(), start: Instant::now(), skipped: false });
+    }
+
+    // The observer hears of a step on its first statement.
+    pub(crate) fn statement(&mut self, statement: impl Into<String>) {
+        if let Some(pending) = &mut self.pending {
+            if pending.statements.is_empty() {
+                self.observer.step_started(pending.kind, &pending.target);
+            }
+            pending.statements.push(statement.into());
+        }
+    }
+
+    pub(crate) fn skip(&mut self) {
+        if let Some(pending) = &mut self.pending {
+            pending.skipped = true;
+        }
+    }
+
+    pub(crate) fn allow_destructive(&mut self, table: &str, column: Option<&str>) -> Result<()> {
+        let Some(pending) = &self.pending else {
+            return Ok(());
+        };
+        if self.observer.allow_destructive_change(pending.kind, &pending.target) {
+            Ok(())
+        } else {
+            Err(Error::destructive_change_denied(table, column))
+        }
+    }
+
+    pub(crate) fn finish(&mut self, result: Result<()>) -> Result<()> {
+        let Some(pending) = self.pending.take() else {
+            return result;
+        };
+        if pending.skipped {
+            if result.is_ok() {
+                self.report.skipped.push(MigrationStep { kind: pending.kind, target: pending.target, statements: pending.statements, elapsed: pending.start.elapsed() });
+            }
+            return result;
+        }
+        if pending.statements.is_empty() {
+            return result;
+        }
+        let step = MigrationStep {
+            kind: pending.kind,
+            target: pending.target,
+            statements: pending.statements,
+            elapsed: pending.start.elapsed(),
+        };
+        self.observer.step_finished(&step, result.as_ref().err());
+        if result.is_ok() {
+            self.report.steps.push(step);
+        }
+        result
+    }
+
+    pub(crate) fn into_report(self) -> MigrationReport {
+        self.report
+    }
+}
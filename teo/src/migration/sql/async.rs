@@ -0,0 +1,157 @@
+use crate::{dialect::Dialect, error::{Error, Result}, trace, migration::{AsyncMigration, ColumnDef, EnumDef, ForeignKeyDef, IndexDef, Recorder, TableDef}, types::Schema};
+
+pub(crate) trait AsyncSQLMigration: Send + Sync {
+
+    type Dialect: Dialect;
+
+    fn dialect(&self) -> Self::Dialect;
+
+    fn execute_without_params(&mut self, q: &str) -> impl Future<Output = Result<()>> + Send;
+
+    fn exist_enum_names(&mut self) -> impl Future<Output = Result<Vec<String>>> + Send {
+        async { Ok(Vec::new()) }
+    }
+
+    fn exist_enum_def(&mut self, _enum_name: &'static str) -> impl Future<Output = Result<EnumDef>> + Send {
+        async { Err(Error::unsupported(self.dialect().name(), "introspect enum")) }
+    }
+
+    fn exist_table_names(&mut self) -> impl Future<Output = Result<Vec<String>>> + Send;
+
+    fn exist_table_def(&mut self, table_name: &str) -> impl Future<Output = Result<TableDef<<Self::Dialect as Dialect>::ColumnType>>> + Send;
+
+    fn execute_statement(&mut self, recorder: &mut Recorder<'_>, table_name: Option<&str>, q: &str) -> impl Future<Output = Result<()>> + Send {
+        recorder.statement(q);
+        trace::statement_async(self.dialect().name(), table_name, q, &[], self.execute_without_params(q))
+    }
+}
+
+impl<T> AsyncMigration for T where T: AsyncSQLMigration {
+
+    type ColumnType = <T::Dialect as Dialect>::ColumnType;
+
+    #[inline]
+    fn defined_table_defs<S>(&self) -> Vec<TableDef<Self::ColumnType>> where S: Schema {
+        self.dialect().table_defs::<S>()
+    }
+
+    #[inline]
+    fn exist_enum_names(&mut self) -> impl Future<Output = Result<Vec<String>>> + Send {
+        trace::introspect_async(self.dialect().name(), None, "enum names", AsyncSQLMigration::exist_enum_names(self))
+    }
+
+    #[inline]
+    fn exist_enum_def(&mut self, enum_name: &'static str) -> impl Future<Output = Result<EnumDef>> + Send {
+        trace::introspect_async(self.dialect().name(), None, "enum", AsyncSQLMigration::exist_enum_def(self, enum_name))
+    }
+
+    #[inline]
+    fn exist_table_names(&mut self) -> impl Future<Output = Result<Vec<String>>> + Send {
+        trace::introspect_async(self.dialect().name(), None, "table names", AsyncSQLMigration::exist_table_names(self))
+    }
+
+    #[inline]
+    fn exist_table_def(&mut self, table_name: &str) -> impl Future<Output = Result<TableDef<Self::ColumnType>>> + Send {
+        trace::introspect_async(self.dialect().name(), Some(table_name), "table", AsyncSQLMigration::exist_table_def(self, table_name))
+    }
+
+    async fn create_enum(&mut self, recorder: &mut Recorder<'_>, enum_def: &EnumDef) -> Result<()> {
+        let statement = self.dialect().enum_create_statement(enum_def)?;
+        self.execute_statement(recorder, None, &statement).await
+    }
+
+    async fn delete_enum(&mut self, recorder: &mut Recorder<'_>, enum_name: &str) -> Result<()> {
+        let statement = self.dialect().enum_drop_statement(enum_name)?;
+        self.execute_statement(recorder, None, &statement).await
+    }
+
+    async fn add_enum_variant(&mut self, recorder: &mut Recorder<'_>, enum_name: &str, variant_name: &str) -> Result<()> {
+        let add_variant_statement = self.dialect().add_enum_variant_statement(enum_name, variant_name)?;
+        self.execute_statement(recorder, None, &add_variant_statement).await
+    }
+
+    async fn delete_table(&mut self, recorder: &mut Recorder<'_>, table_name: &str) -> Result<()> {
+        let statement = self.dialect().drop_table_statement(table_name)?;
+        self.execute_statement(recorder, Some(table_name), &statement).await
+    }
+
+    async fn create_table(&mut self, recorder: &mut Recorder<'_>, table_def: &TableDef<Self::ColumnType>) -> Result<()> {
+        let statement = self.dialect().create_table_statement(table_def)?;
+        self.execute_statement(recorder, Some(&table_def.name), &statement).await?;
+        for index_def in &table_def.indexes {
+            self.create_index(recorder, &table_def.name, index_def).await?;
+        }
+        Ok(())
+    }
+
+    async fn drop_table_column(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_name: &str) -> Result<()> {
+        let statement = self.dialect().drop_table_column_statement(table_name, column_name)?;
+        self.execute_statement(recorder, Some(table_name), &statement).await
+    }
+
+    async fn add_table_column(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<()> {
+        let statement = self.dialect().add_table_column_statement(table_name, column_def)?;
+        self.execute_statement(recorder, Some(table_name), &statement).await
+    }
+
+    async fn alter_table_column_type(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<()> {
+        let statement = self.dialect().alter_table_column_type_statement(table_name, column_def)?;
+        self.execute_statement(recorder, Some(table_name), &statement).await
+    }
+
+    async fn alter_table_column_nullable(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<()> {
+        let statement = if column_def.nullable {
+            self.dialect().alter_table_column_drop_not_null_statement(table_name, column_def)?
+        } else {
+            self.dialect().alter_table_column_set_not_null_statement(table_name, column_def)?
+        };
+        self.execute_statement(recorder, Some(table_name), &statement).await
+    }
+
+    async fn alter_table_column_default(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_name: &str, default: Option<&str>) -> Result<()> {
+        let statement = if let Some(default) = default {
+            self.dialect().alter_table_column_set_default_statement(table_name, column_name, default)?
+        } else {
+            self.dialect().alter_table_column_drop_default_statement(table_name, column_name)?
+        };
+        self.execute_statement(recorder, Some(table_name), &statement).await
+    }
+
+    async fn create_index(&mut self, recorder: &mut Recorder<'_>, table_name: &str, index_def: &IndexDef) -> Result<()> {
+        let statement = self.dialect().create_index_statement(table_name, index_def)?;
+        self.execute_statement(recorder, Some(table_name), &statement).await
+    }
+
+    async fn drop_index(&mut self, recorder: &mut Recorder<'_>, table_name: &str, index_name: &str) -> Result<()> {
+        let statement = self.dialect().drop_index_statement(table_name, index_name)?;
+        self.execute_statement(recorder, Some(table_name), &statement).await
+    }
+
+    async fn create_table_foreign_keys(&mut self, recorder: &mut Recorder<'_>, table_def: &TableDef<Self::ColumnType>) -> Result<()> {
+        if self.dialect().inline_foreign_keys() {
+            return Ok(());
+        }
+        for foreign_key_def in &table_def.foreign_keys {
+            self.create_foreign_key(recorder, &table_def.name, foreign_key_def).await?;
+        }
+        Ok(())
+    }
+
+    async fn create_foreign_key(&mut self, recorder: &mut Recorder<'_>, table_name: &str, foreign_key_def: &ForeignKeyDef) -> Result<()> {
+        if self.dialect().inline_foreign_keys() {
+            recorder.skip();
+            return Ok(());
+        }
+        let statement = self.dialect().add_foreign_key_statement(table_name, foreign_key_def)?;
+        self.execute_statement(recorder, Some(table_name), &statement).await
+    }
+
+    async fn drop_foreign_key(&mut self, recorder: &mut Recorder<'_>, table_name: &str, foreign_key_name: &str) -> Result<()> {
+        if self.dialect().inline_foreign_keys() {
+            recorder.skip();
+            return Ok(());
+        }
+        let statement = self.dialect().drop_foreign_key_statement(table_name, foreign_key_name)?;
+        self.execute_statement(recorder, Some(table_name), &statement).await
+    }
+}
@@ -0,0 +1,2 @@
+pub(super) mod r#async;
+pub(super) mod sync;
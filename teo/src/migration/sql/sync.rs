@@ -0,0 +1,162 @@
+use crate::{dialect::Dialect, error::{Error, Result}, trace, migration::{ColumnDef, EnumDef, ForeignKeyDef, IndexDef, Recorder, SyncMigration, TableDef}, types::Schema};
+
+pub(crate) trait SyncSQLMigration {
+
+    type Dialect: Dialect;
+
+    fn dialect(&self) -> Self::Dialect;
+
+    fn execute_without_params(&mut self, q: &str) -> Result<()>;
+
+    fn exist_enum_names(&mut self) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    fn exist_enum_def(&mut self, _enum_name: &'static str) -> Result<EnumDef> {
+        Err(Error::unsupported(self.dialect().name(), "introspect enum"))
+    }
+
+    fn exist_table_names(&mut self) -> Result<Vec<String>>;
+
+    fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<<Self::Dialect as Dialect>::ColumnType>>;
+
+    fn execute_statement(&mut self, recorder: &mut Recorder<'_>, table_name: Option<&str>, q: &str) -> Result<()> {
+        recorder.statement(q);
+        let dialect = self.dialect().name();
+        trace::statement(dialect, table_name, q, &[], || self.execute_without_params(q))
+    }
+}
+
+impl<T> SyncMigration for T where T: SyncSQLMigration {
+
+    type ColumnType = <T::Dialect as Dialect>::ColumnType;
+
+    #[inline]
+    fn defined_table_defs<S>(&self) -> Vec<TableDef<Self::ColumnType>> where S: Schema {
+        self.dialect().table_defs::<S>()
+    }
+
+    #[inline]
+    fn exist_enum_names(&mut self) -> Result<Vec<String>> {
+        let dialect = self.dialect().name();
+        trace::introspect(dialect, None, "enum names", || SyncSQLMigration::exist_enum_names(self))
+    }
+
+    #[inline]
+    fn exist_enum_def(&mut self, enum_name: &'static str) -> Result<EnumDef> {
+        let dialect = self.dialect().name();
+        trace::introspect(dialect, None, "enum", || SyncSQLMigration::exist_enum_def(self, enum_name))
+    }
+
+    #[inline]
+    fn exist_table_names(&mut self) -> Result<Vec<String>> {
+        let dialect = self.dialect().name();
+        trace::introspect(dialect, None, "table names", || SyncSQLMigration::exist_table_names(self))
+    }
+
+    #[inline]
+    fn exist_table_def(&mut self, table_name: &str) -> Result<TableDef<Self::ColumnType>> {
+        let dialect = self.dialect().name();
+        trace::introspect(dialect, Some(table_name), "table", || SyncSQLMigration::exist_table_def(self, table_name))
+    }
+
+    fn create_enum(&mut self, recorder: &mut Recorder<'_>, enum_def: &EnumDef) -> Result<()> {
+        let statement = self.dialect().enum_create_statement(enum_def)?;
+        self.execute_statement(recorder, None, &statement)
+    }
+
+    fn delete_enum(&mut self, recorder: &mut Recorder<'_>, enum_name: &str) -> Result<()> {
+        let statement = self.dialect().enum_drop_statement(enum_name)?;
+        self.execute_statement(recorder, None, &statement)
+    }
+
+    fn add_enum_variant(&mut self, recorder: &mut Recorder<'_>, enum_name: &str, variant_name: &str) -> Result<()> {
+        let add_variant_statement = self.dialect().add_enum_variant_statement(enum_name, variant_name)?;
+        self.execute_statement(recorder, None, &add_variant_statement)
+    }
+
+    fn delete_table(&mut self, recorder: &mut Recorder<'_>, table_name: &str) -> Result<()> {
+        let statement = self.dialect().drop_table_statement(table_name)?;
+        self.execute_statement(recorder, Some(table_name), &statement)
+    }
+
+    fn create_table(&mut self, recorder: &mut Recorder<'_>, table_def: &TableDef<Self::ColumnType>) -> Result<()> {
+        let statement = self.dialect().create_table_statement(table_def)?;
+        self.execute_statement(recorder, Some(&table_def.name), &statement)?;
+        for index_def in &table_def.indexes {
+            self.create_index(recorder, &table_def.name, index_def)?;
+        }
+        Ok(())
+    }
+
+    fn drop_table_column(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_name: &str) -> Result<()> {
+        let statement = self.dialect().drop_table_column_statement(table_name, column_name)?;
+        self.execute_statement(recorder, Some(table_name), &statement)
+    }
+
+    fn add_table_column(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<()> {
+        let statement = self.dialect().add_table_column_statement(table_name, column_def)?;
+        self.execute_statement(recorder, Some(table_name), &statement)
+    }
+
+    fn alter_table_column_type(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<()> {
+        let statement = self.dialect().alter_table_column_type_statement(table_name, column_def)?;
+        self.execute_statement(recorder, Some(table_name), &statement)
+    }
+
+    fn alter_table_column_nullable(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_def: &ColumnDef<Self::ColumnType>) -> Result<()> {
+        let statement = if column_def.nullable {
+            self.dialect().alter_table_column_drop_not_null_statement(table_name, column_def)?
+        } else {
+            self.dialect().alter_table_column_set_not_null_statement(table_name, column_def)?
+        };
+        self.execute_statement(recorder, Some(table_name), &statement)
+    }
+
+    fn alter_table_column_default(&mut self, recorder: &mut Recorder<'_>, table_name: &str, column_name: &str, default: Option<&str>) -> Result<()> {
+        let statement = if let Some(default) = default {
+            self.dialect().alter_table_column_set_default_statement(table_name, column_name, default)?
+        } else {
+            self.dialect().alter_table_column_drop_default_statement(table_name, column_name)?
+        };
+        self.execute_statement(recorder, Some(table_name), &statement)
+    }
+
+    fn create_index(&mut self, recorder: &mut Recorder<'_>, table_name: &str, index_def: &IndexDef) -> Result<()> {
+        let statement = self.dialect().create_index_statement(table_name, index_def)?;
+        self.execute_statement(recorder, Some(table_name), &statement)
+    }
+
+    fn drop_index(&mut self, recorder: &mut Recorder<'_>, table_name: &str, index_name: &str) -> Result<()> {
+        let statement = self.dialect().drop_index_statement(table_name, index_name)?;
+        self.execute_statement(recorder, Some(table_name), &statement)
+    }
+
+    fn create_table_foreign_keys(&mut self, recorder: &mut Recorder<'_>, table_def: &TableDef<Self::ColumnType>) -> Result<()> {
+        if self.dialect().inline_foreign_keys() {
+            return Ok(());
+        }
+        for foreign_key_def in &table_def.foreign_keys {
+            self.create_foreign_key(recorder, &table_def.name, foreign_key_def)?;
+        }
+        Ok(())
+    }
+
+    fn create_foreign_key(&mut self, recorder: &mut Recorder<'_>, table_name: &str, foreign_key_def: &ForeignKeyDef) -> Result<()> {
+        if self.dialect().inline_foreign_keys() {
+            recorder.skip();
+            return Ok(());
+        }
+        let statement = self.dialect().add_foreign_key_statement(table_name, foreign_key_def)?;
+        self.execute_statement(recorder, Some(table_name), &statement)
+    }
+
+    fn drop_foreign_key(&mut self, recorder: &mut Recorder<'_>, table_name: &str, foreign_key_name: &str) -> Result<()> {
+        if self.dialect().inline_foreign_keys() {
+            recorder.skip();
+            return Ok(());
+        }
+        let statement = self.dialect().drop_foreign_key_statement(table_name, foreign_key_name)?;
+        self.execute_statement(recorder, Some(table_name), &statement)
+    }
+}
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-#[cfg(feature = "mongodb")]
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use super::super::types::SortOrder;
@@ -11,32 +11,53 @@ pub struct EnumDef {
 }
 
 #[derive(Debug, PartialEq)]
-#[cfg_attr(feature = "mongodb", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ColumnDef<T> {
     pub name: Cow<'static, str>,
     pub ty: T,
     pub nullable: bool,
     pub default: Option<Cow<'static, str>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub auto_increment: bool,
+    /// Assigned whenever the row is updated, only MySQL supports this.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub on_update: Option<Cow<'static, str>>,
 }
 
 #[derive(Debug, PartialEq)]
-#[cfg_attr(feature = "mongodb", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IndexColumnDef {
     pub name: Cow<'static, str>,
     pub order: SortOrder,
 }
 
 #[derive(Debug, PartialEq)]
-#[cfg_attr(feature = "mongodb", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IndexDef {
     pub name: Cow<'static, str>,
     pub columns: Vec<IndexColumnDef>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub unique: bool,
+    /// Limits the index to rows matching the condition, only Postgres and SQLite support this.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub predicate: Option<Cow<'static, str>>,
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ForeignKeyDef {
+    pub name: Cow<'static, str>,
+    pub columns: Vec<Cow<'static, str>>,
+    pub referenced_table: Cow<'static, str>,
+    pub referenced_columns: Vec<Cow<'static, str>>,
 }
 
 #[derive(Debug)]
-#[cfg_attr(feature = "mongodb", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableDef<T> {
     pub name: Cow<'static, str>,
     pub columns: Vec<ColumnDef<T>>,
     pub indexes: Vec<IndexDef>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub foreign_keys: Vec<ForeignKeyDef>,
 }
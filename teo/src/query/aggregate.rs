@@ -0,0 +1,274 @@
+use std::{future::IntoFuture, marker::PhantomData, pin::Pin};
+use crate::{connection::{AsyncExecutor, SyncExecutor}, error::{Error, Result}, types::{Entity, FromValue, Value}};
+use super::{Column, Filter, filter::Condition};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Output {
+    Group(&'static str),
+    Count,
+    Sum(&'static str),
+    Avg(&'static str),
+    Min(&'static str),
+    Max(&'static str),
+}
+
+impl Output {
+
+    pub(crate) fn alias(&self) -> String {
+        match self {
+            Output::Group(column) => column.to_string(),
+            Output::Count => "count".to_owned(),
+            Output::Sum(column) => format!("sum_{column}"),
+            Output::Avg(column) => format!("avg_{column}"),
+            Output::Min(column) => format!("min_{column}"),
+            Output::Max(column) => format!("max_{column}"),
+        }
+    }
+}
+
+pub struct Aggregate {
+    pub(crate) table: &'static str,
+    pub(crate) filter: Option<Condition>,
+    pub(crate) group: Vec<&'static str>,
+    pub(crate) functions: Vec<Output>,
+}
+
+impl Aggregate {
+
+    pub(crate) fn new(table: &'static str) -> Self {
+        Self { table, filter: None, group: Vec::new(), functions: Vec::new() }
+    }
+
+    pub(crate) fn filter(&mut self, condition: Condition) {
+        self.filter = Some(match self.filter.take() {
+            Some(existing) => existing.and(condition),
+            None => condition,
+        });
+    }
+
+    /// Group columns come first, executors return the values of each row in this order.
+    pub(crate) fn outputs(&self) -> Vec<Output> {
+        self.group.iter().map(|column| Output::Group(column)).chain(self.functions.iter().copied()).collect()
+    }
+}
+
+pub struct Count<'a, E, C> {
+    aggregate: Aggregate,
+    with_deleted: bool,
+    connection: &'a mut C,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<'a, E, C> Count<'a, E, C> where E: Entity {
+
+    pub(crate) fn new(connection: &'a mut C) -> Self {
+        let mut aggregate = Aggregate::new(E::table_name());
+        aggregate.functions.push(Output::Count);
+        Self { aggregate, with_deleted: false, connection, _marker: PhantomData }
+    }
+
+    pub fn filter(mut self, filter: Filter<E>) -> Self {
+        self.aggregate.filter(filter.condition);
+        self
+    }
+
+    /// Also counts soft deleted rows.
+    pub fn with_deleted(mut self) -> Self {
+        self.with_deleted = true;
+        self
+    }
+}
+
+impl<E, C> Count<'_, E, C> where E: Entity, C: SyncExecutor {
+
+    pub fn run(self) -> Result<u64> {
+        let aggregate = not_deleted::<E>(self.aggregate, self.with_deleted);
+        count(self.connection.aggregate(&aggregate)?)
+    }
+}
+
+impl<'a, E, C> IntoFuture for Count<'a, E, C> where E: Entity + 'a, C: AsyncExecutor {
+
+    type Output = Result<u64>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<u64>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            let aggregate = not_deleted::<E>(self.aggregate, self.with_deleted);
+            count(self.connection.aggregate(&aggregate).await?)
+        })
+    }
+}
+
+fn not_deleted<E>(mut aggregate: Aggregate, with_deleted: bool) -> Aggregate where E: Entity {
+    if !with_deleted && let Some(condition) = Condition::not_deleted::<E>() {
+        aggregate.filter(condition);
+    }
+    aggregate
+}
+
+fn count(rows: Vec<Vec<Value>>) -> Result<u64> {
+    match rows.into_iter().next().and_then(|row| row.into_iter().next()) {
+        Some(value) => to_count(value),
+        None => Ok(0),
+    }
+}
+
+pub struct AggregateMany<'a, E, C> {
+    aggregate: Aggregate,
+    with_deleted: bool,
+    connection: &'a mut C,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<'a, E, C> AggregateMany<'a, E, C> where E: Entity {
+
+    pub(crate) fn new(connection: &'a mut C) -> Self {
+        Self { aggregate: Aggregate::new(E::table_name()), with_deleted: false, connection, _marker: PhantomData }
+    }
+
+    pub fn filter(mut self, filter: Filter<E>) -> Self {
+        self.aggregate.filter(filter.condition);
+        self
+    }
+
+    /// Also aggregates soft deleted rows.
+    pub fn with_deleted(mut self) -> Self {
+        self.with_deleted = true;
+        self
+    }
+
+    pub fn count(mut self) -> Self {
+        self.aggregate.functions.push(Output::Count);
+        self
+    }
+
+    pub fn sum<T>(mut self, column: Column<E, T>) -> Self {
+        self.aggregate.functions.push(Output::Sum(column.name()));
+        self
+    }
+
+    pub fn avg<T>(mut self, column: Column<E, T>) -> Self {
+        self.aggregate.functions.push(Output::Avg(column.name()));
+        self
+    }
+
+    pub fn min<T>(mut self, column: Column<E, T>) -> Self {
+        self.aggregate.functions.push(Output::Min(column.name()));
+        self
+    }
+
+    pub fn max<T>(mut self, column: Column<E, T>) -> Self {
+        self.aggregate.functions.push(Output::Max(column.name()));
+        self
+    }
+
+    /// Rows are returned ordered by the group columns. Call it again to group by columns of
+    /// different types.
+    pub fn group_by<T>(mut self, columns: impl IntoIterator<Item = Column<E, T>>) -> Self {
+        self.aggregate.group.extend(columns.into_iter().map(|column| column.name()));
+        self
+    }
+}
+
+impl<E, C> AggregateMany<'_, E, C> where E: Entity, C: SyncExecutor {
+
+    pub fn run(self) -> Result<Vec<AggregateRow<E>>> {
+        let aggregate = not_deleted::<E>(self.aggregate, self.with_deleted);
+        let rows = self.connection.aggregate(&aggregate)?;
+        Ok(AggregateRow::from_rows(&aggregate, rows))
+    }
+}
+
+impl<'a, E, C> IntoFuture for AggregateMany<'a, E, C> where E: Entity + 'a, C: AsyncExecutor {
+
+    type Output = Result<Vec<AggregateRow<E>>>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<Vec<AggregateRow<E>>>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            let aggregate = not_deleted::<E>(self.aggregate, self.with_deleted);
+            let rows = self.connection.aggregate(&aggregate).await?;
+            Ok(AggregateRow::from_rows(&aggregate, rows))
+        })
+    }
+}
+
+/// A group of rows, values are read with the columns they were computed from.
+#[derive(Debug, Clone)]
+pub struct AggregateRow<E> {
+    values: Vec<(Output, Value)>,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E> AggregateRow<E> {
+
+    fn from_rows(aggregate: &Aggregate, rows: Vec<Vec<Value>>) -> Vec<Self> {
+        let outputs = aggregate.outputs();
+        rows.into_iter().map(|row| Self { values: outputs.iter().copied().zip(row).collect(), _marker: PhantomData }).collect()
+    }
+
+    fn value(&self, output: Output) -> Result<&Value> {
+        self.values.iter().find(|(o, _)| *o == output).map(|(_, value)| value).ok_or_else(|| Error::missing_column(output.alias()))
+    }
+
+    pub fn count(&self) -> Result<u64> {
+        to_count(self.value(Output::Count)?.clone())
+    }
+
+    /// Read as the column's own type, `None` if there's no non-null value to add up.
+    pub fn sum<T>(&self, column: Column<E, T>) -> Result<Option<T>> where T: FromValue {
+        Option::<T>::from_value(to_sum(self.value(Output::Sum(column.name()))?.clone()))
+    }
+
+    pub fn avg<T>(&self, column: Column<E, T>) -> Result<Option<f64>> {
+        to_f64(self.value(Output::Avg(column.name()))?)
+    }
+
+    pub fn min<T>(&self, column: Column<E, T>) -> Result<Option<T>> where T: FromValue {
+        Option::<T>::from_value(self.value(Output::Min(column.name()))?.clone())
+    }
+
+    pub fn max<T>(&self, column: Column<E, T>) -> Result<Option<T>> where T: FromValue {
+        Option::<T>::from_value(self.value(Output::Max(column.name()))?.clone())
+    }
+
+    pub fn group<T>(&self, column: Column<E, T>) -> Result<T> where T: FromValue {
+        T::from_value(self.value(Output::Group(column.name()))?.clone())
+    }
+}
+
+fn to_count(value: Value) -> Result<u64> {
+    match value {
+        Value::I32(count) if count >= 0 => Ok(count as u64),
+        Value::I64(count) if count >= 0 => Ok(count as u64),
+        value => Err(Error::invalid_value("count", &value)),
+    }
+}
+
+// Integer sums widen to bigint or numeric, MySQL sends them as decimal text.
+fn to_sum(value: Value) -> Value {
+    match value {
+        Value::String(ref string) => string.parse().map(Value::I64).unwrap_or(value),
+        #[cfg(feature = "bigdecimal")]
+        Value::Decimal(ref decimal) if decimal.is_integer() => decimal.to_string().parse().map(Value::I64).unwrap_or(value),
+        value => value,
+    }
+}
+
+// Databases disagree on the result type of averages, MySQL sends decimals as text.
+fn to_f64(value: &Value) -> Result<Option<f64>> {
+    match value {
+        Value::Null => Ok(None),
+        Value::I32(value) => Ok(Some(*value as f64)),
+        Value::I64(value) => Ok(Some(*value as f64)),
+        Value::F32(value) => Ok(Some(*value as f64)),
+        Value::F64(value) => Ok(Some(*value)),
+        Value::String(string) => string.parse().map(Some).map_err(|_| Error::invalid_value("f64", value)),
+        #[cfg(feature = "bigdecimal")]
+        Value::Decimal(decimal) => decimal.to_string().parse().map(Some).map_err(|_| Error::invalid_value("f64", value)),
+        value => Err(Error::invalid_value("f64", value)),
+    }
+}
@@ -0,0 +1,190 @@
+use std::{fmt::{Display, Formatter, Result as FmtResult}, marker::PhantomData, str::FromStr};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as DeError};
+use crate::{error::{self, Error}, types::{Entity, SortOrder, ToValue, Value}};
+use super::Column;
+
+/// An ordering which can be reused to resume a query after the last fetched row.
+pub struct Order<E> {
+    pub(crate) columns: Vec<(&'static str, SortOrder)>,
+    nullable: Option<&'static str>,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E> Order<E> {
+
+    pub fn new() -> Self {
+        Self { columns: Vec::new(), nullable: None, _marker: PhantomData }
+    }
+
+    pub fn by<T>(mut self, column: Column<E, T>, order: SortOrder) -> Self where T: ToValue {
+        self.columns.push((column.name(), order));
+        if T::nullable() && self.nullable.is_none() {
+            self.nullable = Some(column.name());
+        }
+        self
+    }
+}
+
+impl<E> Order<E> where E: Entity {
+
+    /// Takes the key columns from the last row of a page. Rows can't be compared to a null, an
+    /// ordering with a nullable column can't make cursors.
+    pub fn cursor(&self, entity: &E) -> error::Result<Cursor> {
+        if let Some(column) = self.nullable {
+            return Err(Error::nullable_cursor_column(column));
+        }
+        let values = self.columns.iter().map(|(column, _)| entity.column_value(column).ok_or_else(|| Error::missing_column(*column)));
+        let columns = self.columns.iter().map(|(column, _)| (*column).to_owned()).collect();
+        Ok(Cursor { columns, values: values.collect::<error::Result<_>>()? })
+    }
+}
+
+impl<E> Default for Order<E> {
+
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Clone for Order<E> {
+
+    fn clone(&self) -> Self {
+        Self { columns: self.columns.clone(), nullable: self.nullable, _marker: PhantomData }
+    }
+}
+
+/// Displayed as an opaque string which is safe to hand out to clients. It names the columns of
+/// the ordering it was taken from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor {
+    pub(crate) columns: Vec<String>,
+    pub(crate) values: Vec<Value>,
+}
+
+impl Display for Cursor {
+
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut bytes = Vec::new();
+        for (column, value) in self.columns.iter().zip(&self.values) {
+            sized(&mut bytes, b'c', column.as_bytes());
+            encode(value, &mut bytes);
+        }
+        for byte in bytes {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Cursor {
+
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.len().is_multiple_of(2) || !s.is_ascii() {
+            return Err(Error::InvalidCursor);
+        }
+        let bytes = (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16)).collect::<Result<Vec<u8>, _>>().map_err(|_| Error::InvalidCursor)?;
+        let mut reader = bytes.as_slice();
+        let mut columns = Vec::new();
+        let mut values = Vec::new();
+        while !reader.is_empty() {
+            columns.push(decode_column(&mut reader).ok_or(Error::InvalidCursor)?);
+            values.push(decode(&mut reader).ok_or(Error::InvalidCursor)?);
+        }
+        Ok(Self { columns, values })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cursor {
+
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cursor {
+
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(D::Error::custom)
+    }
+}
+
+pub(super) fn encode(value: &Value, bytes: &mut Vec<u8>) {
+    match value {
+        Value::Null => bytes.push(b'n'),
+        Value::Bool(v) => bytes.extend([b'b', *v as u8]),
+        Value::I32(v) => tagged(bytes, b'i', &v.to_be_bytes()),
+        Value::I64(v) => tagged(bytes, b'l', &v.to_be_bytes()),
+        Value::F32(v) => tagged(bytes, b'f', &v.to_be_bytes()),
+        Value::F64(v) => tagged(bytes, b'd', &v.to_be_bytes()),
+        Value::String(v) => sized(bytes, b's', v.as_bytes()),
+        Value::Bytes(v) => sized(bytes, b'y', v),
+        #[cfg(feature = "uuid")]
+        Value::Uuid(v) => tagged(bytes, b'u', v.as_bytes()),
+        #[cfg(feature = "chrono")]
+        Value::DateTime(v) => tagged(bytes, b't', &v.timestamp_micros().to_be_bytes()),
+        #[cfg(feature = "bigdecimal")]
+        Value::Decimal(v) => sized(bytes, b'm', v.to_string().as_bytes()),
+        #[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+        Value::ObjectId(v) => tagged(bytes, b'o', &v.bytes()),
+    }
+}
+
+fn tagged(bytes: &mut Vec<u8>, tag: u8, payload: &[u8]) {
+    bytes.push(tag);
+    bytes.extend_from_slice(payload);
+}
+
+fn sized(bytes: &mut Vec<u8>, tag: u8, payload: &[u8]) {
+    bytes.push(tag);
+    bytes.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(payload);
+}
+
+fn decode_column(reader: &mut &[u8]) -> Option<String> {
+    if take::<1>(reader)?[0] != b'c' {
+        return None;
+    }
+    String::from_utf8(take_sized(reader)?).ok()
+}
+
+fn decode(reader: &mut &[u8]) -> Option<Value> {
+    let tag = take::<1>(reader)?[0];
+    Some(match tag {
+        b'n' => Value::Null,
+        b'b' => Value::Bool(take::<1>(reader)?[0] != 0),
+        b'i' => Value::I32(i32::from_be_bytes(take(reader)?)),
+        b'l' => Value::I64(i64::from_be_bytes(take(reader)?)),
+        b'f' => Value::F32(f32::from_be_bytes(take(reader)?)),
+        b'd' => Value::F64(f64::from_be_bytes(take(reader)?)),
+        b's' => Value::String(String::from_utf8(take_sized(reader)?).ok()?),
+        b'y' => Value::Bytes(take_sized(reader)?),
+        #[cfg(feature = "uuid")]
+        b'u' => Value::Uuid(uuid::Uuid::from_bytes(take(reader)?)),
+        #[cfg(feature = "chrono")]
+        b't' => Value::DateTime(chrono::DateTime::from_timestamp_micros(i64::from_be_bytes(take(reader)?))?),
+        #[cfg(feature = "bigdecimal")]
+        b'm' => Value::Decimal(String::from_utf8(take_sized(reader)?).ok()?.parse().ok()?),
+        #[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+        b'o' => Value::ObjectId(mongodb::bson::oid::ObjectId::from_bytes(take(reader)?)),
+        _ => None?,
+    })
+}
+
+fn take<const N: usize>(reader: &mut &[u8]) -> Option<[u8; N]> {
+    let (head, tail) = reader.split_first_chunk::<N>()?;
+    *reader = tail;
+    Some(*head)
+}
+
+fn take_sized(reader: &mut &[u8]) -> Option<Vec<u8>> {
+    let len = u32::from_be_bytes(take(reader)?) as usize;
+    let (head, tail) = reader.split_at_checked(len)?;
+    *reader = tail;
+    Some(head.to_vec())
+}
@@ -0,0 +1,168 @@
+use std::{future::IntoFuture, marker::PhantomData, pin::Pin};
+use crate::{connection::{AsyncExecutor, SyncExecutor}, error::{Error, Result}, types::{Entity, FromRow}};
+use super::{Filter, Update, filter::{Condition, Operator}, update::timestamp};
+
+pub struct Delete {
+    pub(crate) table: &'static str,
+    pub(crate) filter: Option<Condition>,
+    pub(crate) single: bool,
+}
+
+impl Delete {
+
+    pub(crate) fn new(table: &'static str) -> Self {
+        Self { table, filter: None, single: false }
+    }
+
+    pub(crate) fn filter(&mut self, condition: Condition) {
+        self.filter = Some(match self.filter.take() {
+            Some(existing) => existing.and(condition),
+            None => condition,
+        });
+    }
+
+    // Soft deleting entities get the deletion time set instead, rows deleted before keep theirs.
+    pub(crate) fn soft(self, column: &'static str, updated_at: Option<&'static str>) -> Result<Update> {
+        let mut update = Update::new(self.table);
+        if let Some(filter) = self.filter {
+            update.filter(filter);
+        }
+        update.filter(Condition::Null { column, negated: false });
+        let now = timestamp()?;
+        if let Some(updated_at) = updated_at {
+            update.values.push((updated_at, now.clone()));
+        }
+        update.values.push((column, now));
+        Ok(update)
+    }
+}
+
+pub struct DeleteMany<'a, E, C> {
+    delete: Delete,
+    connection: &'a mut C,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<'a, E, C> DeleteMany<'a, E, C> where E: Entity {
+
+    pub(crate) fn new(connection: &'a mut C) -> Self {
+        Self { delete: Delete::new(E::table_name()), connection, _marker: PhantomData }
+    }
+
+    pub fn filter(mut self, filter: Filter<E>) -> Self {
+        self.delete.filter(filter.condition);
+        self
+    }
+
+    /// Fetches the deleted rows, only Postgres and SQLite support this. Soft deleting entities
+    /// can't return their rows.
+    pub fn returning(self) -> DeleteManyReturning<'a, E, C> {
+        DeleteManyReturning { delete: self.delete, connection: self.connection, _marker: PhantomData }
+    }
+}
+
+impl<E, C> DeleteMany<'_, E, C> where E: Entity, C: SyncExecutor {
+
+    /// Returns the number of deleted rows.
+    pub fn run(self) -> Result<u64> {
+        match E::soft_delete_column() {
+            Some(column) => self.connection.update(&self.delete.soft(column, E::updated_at_column())?),
+            None => self.connection.delete(&self.delete),
+        }
+    }
+}
+
+impl<'a, E, C> IntoFuture for DeleteMany<'a, E, C> where E: Entity + 'a, C: AsyncExecutor {
+
+    type Output = Result<u64>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<u64>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            match E::soft_delete_column() {
+                Some(column) => self.connection.update(&self.delete.soft(column, E::updated_at_column())?).await,
+                None => self.connection.delete(&self.delete).await,
+            }
+        })
+    }
+}
+
+pub struct DeleteManyReturning<'a, E, C> {
+    delete: Delete,
+    connection: &'a mut C,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E, C> DeleteManyReturning<'_, E, C> where E: Entity + FromRow, C: SyncExecutor {
+
+    pub fn run(self) -> Result<Vec<E>> {
+        if E::soft_delete_column().is_some() {
+            return Err(Error::unsupported("soft deleting entities", "returning"));
+        }
+        self.connection.delete_returning(&self.delete)
+    }
+}
+
+impl<'a, E, C> IntoFuture for DeleteManyReturning<'a, E, C> where E: Entity + FromRow + Send + 'a, C: AsyncExecutor {
+
+    type Output = Result<Vec<E>>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<Vec<E>>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            if E::soft_delete_column().is_some() {
+                return Err(Error::unsupported("soft deleting entities", "returning"));
+            }
+            self.connection.delete_returning(&self.delete).await
+        })
+    }
+}
+
+pub struct DeleteOne<'a, E, C> {
+    entity: &'a E,
+    connection: &'a mut C,
+}
+
+impl<'a, E, C> DeleteOne<'a, E, C> where E: Entity {
+
+    pub(crate) fn new(entity: &'a E, connection: &'a mut C) -> Self {
+        Self { entity, connection }
+    }
+
+    fn delete(entity: &E) -> Result<Delete> {
+        let column = E::primary_column().ok_or_else(|| Error::missing_primary_key(E::table_name()))?;
+        let mut delete = Delete::new(E::table_name());
+        delete.filter(Condition::Compare { column, operator: Operator::Eq, value: entity.primary_value() });
+        delete.single = true;
+        Ok(delete)
+    }
+}
+
+impl<E, C> DeleteOne<'_, E, C> where E: Entity, C: SyncExecutor {
+
+    /// Returns the number of deleted rows.
+    pub fn run(self) -> Result<u64> {
+        match (Self::delete(self.entity)?, E::soft_delete_column()) {
+            (delete, Some(column)) => self.connection.update(&delete.soft(column, E::updated_at_column())?),
+            (delete, None) => self.connection.delete(&delete),
+        }
+    }
+}
+
+impl<'a, E, C> IntoFuture for DeleteOne<'a, E, C> where E: Entity + Sync, C: AsyncExecutor {
+
+    type Output = Result<u64>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<u64>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            match (Self::delete(self.entity)?, E::soft_delete_column()) {
+                (delete, Some(column)) => self.connection.update(&delete.soft(column, E::updated_at_column())?).await,
+                (delete, None) => self.connection.delete(&delete).await,
+            }
+        })
+    }
+}
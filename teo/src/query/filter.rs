@@ -0,0 +1,195 @@
+use std::{marker::PhantomData, ops::Not};
+use crate::types::{Entity, SortOrder, ToValue, Value};
+
+pub struct Column<E, T> {
+    name: &'static str,
+    _marker: PhantomData<fn() -> (E, T)>,
+}
+
+impl<E, T> Clone for Column<E, T> {
+
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E, T> Copy for Column<E, T> { }
+
+impl<E, T> Column<E, T> {
+
+    pub const fn new(name: &'static str) -> Self {
+        Self { name, _marker: PhantomData }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn compare<V>(self, operator: Operator, value: V) -> Filter<E> where V: Into<T>, T: ToValue {
+        Filter::new(Condition::Compare { column: self.name, operator, value: value.into().to_value() })
+    }
+}
+
+impl<E, T> Column<E, T> where T: ToValue {
+
+    pub fn eq<V>(self, value: V) -> Filter<E> where V: Into<T> {
+        self.compare(Operator::Eq, value)
+    }
+
+    pub fn ne<V>(self, value: V) -> Filter<E> where V: Into<T> {
+        self.compare(Operator::Ne, value)
+    }
+
+    pub fn gt<V>(self, value: V) -> Filter<E> where V: Into<T> {
+        self.compare(Operator::Gt, value)
+    }
+
+    pub fn gte<V>(self, value: V) -> Filter<E> where V: Into<T> {
+        self.compare(Operator::Gte, value)
+    }
+
+    pub fn lt<V>(self, value: V) -> Filter<E> where V: Into<T> {
+        self.compare(Operator::Lt, value)
+    }
+
+    pub fn lte<V>(self, value: V) -> Filter<E> where V: Into<T> {
+        self.compare(Operator::Lte, value)
+    }
+
+    pub fn is_in<I, V>(self, values: I) -> Filter<E> where I: IntoIterator<Item = V>, V: Into<T> {
+        let values = values.into_iter().map(|v| v.into().to_value()).collect();
+        Filter::new(Condition::In { column: self.name, values })
+    }
+}
+
+impl<E, T> Column<E, Option<T>> {
+
+    pub fn is_null(self) -> Filter<E> {
+        Filter::new(Condition::Null { column: self.name, negated: false })
+    }
+
+    pub fn is_not_null(self) -> Filter<E> {
+        Filter::new(Condition::Null { column: self.name, negated: true })
+    }
+}
+
+impl<E> Column<E, String> {
+
+    /// `%` matches any sequence of characters and `_` matches a single character.
+    pub fn like(self, pattern: impl Into<String>) -> Filter<E> {
+        Filter::new(Condition::Like { column: self.name, pattern: pattern.into() })
+    }
+}
+
+impl<E> Column<E, Option<String>> {
+
+    /// `%` matches any sequence of characters and `_` matches a single character.
+    pub fn like(self, pattern: impl Into<String>) -> Filter<E> {
+        Filter::new(Condition::Like { column: self.name, pattern: pattern.into() })
+    }
+}
+
+pub struct Filter<E> {
+    pub(crate) condition: Condition,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E> Filter<E> {
+
+    pub(crate) fn new(condition: Condition) -> Self {
+        Self { condition, _marker: PhantomData }
+    }
+
+    pub fn and(self, other: Filter<E>) -> Self {
+        Self::new(self.condition.and(other.condition))
+    }
+
+    pub fn or(self, other: Filter<E>) -> Self {
+        Self::new(match self.condition {
+            Condition::Or(mut conditions) => {
+                conditions.push(other.condition);
+                Condition::Or(conditions)
+            },
+            condition => Condition::Or(vec![condition, other.condition]),
+        })
+    }
+}
+
+impl<E> Not for Filter<E> {
+
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::new(Condition::Not(Box::new(self.condition)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Condition {
+    Compare {
+        column: &'static str,
+        operator: Operator,
+        value: Value,
+    },
+    In {
+        column: &'static str,
+        values: Vec<Value>,
+    },
+    Null {
+        column: &'static str,
+        negated: bool,
+    },
+    Like {
+        column: &'static str,
+        pattern: String,
+    },
+    After {
+        columns: Vec<(&'static str, SortOrder)>,
+        values: Vec<Value>,
+    },
+    And(Vec<Condition>),
+    Or(Vec<Condition>),
+    Not(Box<Condition>),
+}
+
+impl Condition {
+
+    // Rows after the cursor are those greater on the first column, or equal on it and
+    // after the cursor on the remaining ones.
+    pub(crate) fn expand_after(columns: &[(&'static str, SortOrder)], values: &[Value]) -> Condition {
+        let alternatives = (0..columns.len()).map(|i| {
+            let mut conditions: Vec<Condition> = (0..i).map(|j| Condition::Compare { column: columns[j].0, operator: Operator::Eq, value: values[j].clone() }).collect();
+            let operator = match columns[i].1 {
+                SortOrder::Asc => Operator::Gt,
+                SortOrder::Desc => Operator::Lt,
+            };
+            conditions.push(Condition::Compare { column: columns[i].0, operator, value: values[i].clone() });
+            Condition::And(conditions)
+        }).collect();
+        Condition::Or(alternatives)
+    }
+
+    pub(crate) fn not_deleted<E>() -> Option<Condition> where E: Entity {
+        E::soft_delete_column().map(|column| Condition::Null { column, negated: false })
+    }
+
+    pub(crate) fn and(self, other: Condition) -> Condition {
+        match self {
+            Condition::And(mut conditions) => {
+                conditions.push(other);
+                Condition::And(conditions)
+            },
+            condition => Condition::And(vec![condition, other]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Operator {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
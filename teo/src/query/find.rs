@@ -0,0 +1,192 @@
+use std::{future::{IntoFuture, ready}, marker::PhantomData, pin::Pin};
+use futures::{StreamExt, stream::{self, BoxStream}};
+use crate::{connection::{AsyncExecutor, StreamExecutor, SyncExecutor}, error::{Error, Result}, types::{Entity, FromRow, SortOrder, Value}};
+use super::{Column, Cursor, Filter, Include, Includes, Order, Select, filter::{Condition, Operator}};
+
+pub struct FindUnique<'a, E, C, I = ()> {
+    key: Value,
+    with_deleted: bool,
+    includes: I,
+    connection: &'a mut C,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<'a, E, C> FindUnique<'a, E, C> {
+
+    pub(crate) fn new(key: Value, connection: &'a mut C) -> Self {
+        Self { key, with_deleted: false, includes: (), connection, _marker: PhantomData }
+    }
+}
+
+impl<'a, E, C, I> FindUnique<'a, E, C, I> {
+
+    /// Also finds the row if it was soft deleted.
+    pub fn with_deleted(mut self) -> Self {
+        self.with_deleted = true;
+        self
+    }
+
+    /// Loads a relation of the row, the related entity has to be `Clone`.
+    pub fn include<R>(self, include: Include<E, R>) -> FindUnique<'a, E, C, (I, Include<E, R>)> {
+        FindUnique { key: self.key, with_deleted: self.with_deleted, includes: (self.includes, include), connection: self.connection, _marker: PhantomData }
+    }
+}
+
+impl<E, C, I> FindUnique<'_, E, C, I> where E: Entity {
+
+    fn select(key: Value, with_deleted: bool) -> Result<Select> {
+        let column = E::primary_column().ok_or_else(|| Error::missing_primary_key(E::table_name()))?;
+        let mut select = Select::new(E::table_name());
+        select.filter(Condition::Compare { column, operator: Operator::Eq, value: key });
+        if !with_deleted && let Some(condition) = Condition::not_deleted::<E>() {
+            select.filter(condition);
+        }
+        select.limit = Some(1);
+        Ok(select)
+    }
+}
+
+impl<E, C, I> FindUnique<'_, E, C, I> where E: Entity + FromRow, C: SyncExecutor, I: Includes<E> {
+
+    pub fn run(self) -> Result<Option<E>> {
+        let select = Self::select(self.key, self.with_deleted)?;
+        let mut entities = self.connection.find(&select)?;
+        self.includes.load(self.connection, &mut entities)?;
+        Ok(entities.into_iter().next())
+    }
+}
+
+impl<'a, E, C, I> IntoFuture for FindUnique<'a, E, C, I> where E: Entity + FromRow + Send + 'a, C: AsyncExecutor, I: Includes<E> + Send + 'a {
+
+    type Output = Result<Option<E>>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<Option<E>>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            let select = Self::select(self.key, self.with_deleted)?;
+            let mut entities = self.connection.find(&select).await?;
+            self.includes.load_async(self.connection, &mut entities).await?;
+            Ok(entities.into_iter().next())
+        })
+    }
+}
+
+pub struct FindMany<'a, E, C, I = ()> {
+    select: Select,
+    after: Option<Cursor>,
+    with_deleted: bool,
+    includes: I,
+    connection: &'a mut C,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<'a, E, C> FindMany<'a, E, C> where E: Entity {
+
+    pub(crate) fn new(connection: &'a mut C) -> Self {
+        Self { select: Select::new(E::table_name()), after: None, with_deleted: false, includes: (), connection, _marker: PhantomData }
+    }
+}
+
+impl<'a, E, C, I> FindMany<'a, E, C, I> where E: Entity {
+
+    pub fn filter(mut self, filter: Filter<E>) -> Self {
+        self.select.filter(filter.condition);
+        self
+    }
+
+    pub fn order_by<T>(mut self, column: Column<E, T>, order: SortOrder) -> Self {
+        self.select.order.push((column.name(), order));
+        self
+    }
+
+    pub fn order(mut self, order: Order<E>) -> Self {
+        self.select.order.extend(order.columns);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.select.limit = Some(limit);
+        self
+    }
+
+    pub fn take(self, take: u64) -> Self {
+        self.limit(take)
+    }
+
+    pub fn skip(mut self, skip: u64) -> Self {
+        self.select.offset = Some(skip);
+        self
+    }
+
+    /// Continues after the row the cursor was taken from, the query fails with
+    /// `Error::InvalidCursor` unless it's ordered by the columns the cursor names.
+    pub fn after(mut self, cursor: Cursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Also finds soft deleted rows.
+    pub fn with_deleted(mut self) -> Self {
+        self.with_deleted = true;
+        self
+    }
+
+    /// Loads a relation of the fetched rows with one query per relation, the related entity
+    /// has to be `Clone`.
+    pub fn include<R>(self, include: Include<E, R>) -> FindMany<'a, E, C, (I, Include<E, R>)> {
+        FindMany { select: self.select, after: self.after, with_deleted: self.with_deleted, includes: (self.includes, include), connection: self.connection, _marker: PhantomData }
+    }
+
+    fn select(self) -> Result<(Select, I, &'a mut C)> {
+        let mut select = self.select;
+        if let Some(cursor) = self.after {
+            if select.order.is_empty() || !cursor.columns.iter().map(String::as_str).eq(select.order.iter().map(|(column, _)| *column)) {
+                return Err(Error::InvalidCursor);
+            }
+            select.filter(Condition::After { columns: select.order.clone(), values: cursor.values });
+        }
+        if !self.with_deleted && let Some(condition) = Condition::not_deleted::<E>() {
+            select.filter(condition);
+        }
+        Ok((select, self.includes, self.connection))
+    }
+}
+
+impl<E, C, I> FindMany<'_, E, C, I> where E: Entity + FromRow, C: SyncExecutor, I: Includes<E> {
+
+    pub fn run(self) -> Result<Vec<E>> {
+        let (select, includes, connection) = self.select()?;
+        let mut entities = connection.find(&select)?;
+        includes.load(connection, &mut entities)?;
+        Ok(entities)
+    }
+}
+
+impl<'a, E, C, I> IntoFuture for FindMany<'a, E, C, I> where E: Entity + FromRow + Send + 'a, C: AsyncExecutor, I: Includes<E> + Send + 'a {
+
+    type Output = Result<Vec<E>>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<Vec<E>>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            let (select, includes, connection) = self.select()?;
+            let mut entities = connection.find(&select).await?;
+            includes.load_async(connection, &mut entities).await?;
+            Ok(entities)
+        })
+    }
+}
+
+impl<'a, E, C> FindMany<'a, E, C> where E: Entity + FromRow + Send + 'static, C: StreamExecutor {
+
+    /// Decodes the rows one by one as they arrive instead of collecting them, relations can't be
+    /// included in a stream.
+    pub fn stream(self) -> BoxStream<'a, Result<E>> {
+        match self.select() {
+            Ok((select, (), connection)) => connection.stream(&select),
+            Err(error) => stream::once(ready(Err(error))).boxed(),
+        }
+    }
+}
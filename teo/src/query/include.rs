@@ -0,0 +1,298 @@
+use std::{collections::HashMap, future::IntoFuture, pin::Pin};
+use crate::{connection::{AsyncExecutor, SyncExecutor}, error::{Error, Result}, types::{Entity, FromRow, Row, Value}};
+use super::{Delete, Select, Upsert, cursor::encode, filter::{Condition, Operator}};
+
+/// A relation of `E` to `R`, passed to `include` to load it alongside the queried rows.
+pub struct Include<E, R> {
+    link: Link,
+    assign: fn(&mut E, Vec<R>),
+}
+
+enum Link {
+    // `fields` of the entity hold the `references` of the related rows.
+    Columns {
+        fields: Vec<&'static str>,
+        references: Vec<&'static str>,
+    },
+    // Rows are linked by the primary keys stored in a join table.
+    Join {
+        table: &'static str,
+        source: &'static str,
+        target: &'static str,
+    },
+}
+
+impl<E, R> Include<E, R> where E: Entity, R: Entity {
+
+    #[doc(hidden)]
+    pub fn columns(fields: Vec<&'static str>, references: Vec<&'static str>, assign: fn(&mut E, Vec<R>)) -> Self {
+        Self { link: Link::Columns { fields, references }, assign }
+    }
+
+    #[doc(hidden)]
+    pub fn join(table: &'static str, source: &'static str, target: &'static str, assign: fn(&mut E, Vec<R>)) -> Self {
+        Self { link: Link::Join { table, source, target }, assign }
+    }
+
+    /// Links two rows of a many-to-many relation, linking them again does nothing.
+    pub fn connect<'a, C>(&self, connection: &'a mut C, entity: &E, related: &R) -> Connect<'a, C> {
+        let upsert = match self.link {
+            Link::Join { table, source, target } => Ok(Upsert {
+                table,
+                rows: vec![vec![(source, entity.primary_value()), (target, related.primary_value())]],
+                conflict: vec![source, target],
+                update: Vec::new(),
+            }),
+            Link::Columns { .. } => Err(Error::unsupported("relations without a join table", "connect")),
+        };
+        Connect { upsert, connection }
+    }
+
+    /// Removes the link between two rows of a many-to-many relation.
+    pub fn disconnect<'a, C>(&self, connection: &'a mut C, entity: &E, related: &R) -> Disconnect<'a, C> {
+        let delete = match self.link {
+            Link::Join { table, source, target } => {
+                let mut delete = Delete::new(table);
+                delete.filter(Condition::Compare { column: source, operator: Operator::Eq, value: entity.primary_value() });
+                delete.filter(Condition::Compare { column: target, operator: Operator::Eq, value: related.primary_value() });
+                Ok(delete)
+            },
+            Link::Columns { .. } => Err(Error::unsupported("relations without a join table", "disconnect")),
+        };
+        Disconnect { delete, connection }
+    }
+
+    fn keys(&self, entities: &[E]) -> Vec<Vec<Value>> {
+        entities.iter().map(|entity| match &self.link {
+            Link::Columns { fields, .. } => fields.iter().map(|f| entity.column_value(f).unwrap_or(Value::Null)).collect(),
+            Link::Join { .. } => vec![entity.primary_value()],
+        }).collect()
+    }
+
+    // Rows sharing a null key column are never related. Keys are split into batches that stay
+    // under the connection's parameter limit.
+    fn select(table: &'static str, columns: &[&'static str], keys: &[Vec<Value>], max_params: usize) -> Vec<Select> {
+        let mut seen = HashMap::new();
+        let keys: Vec<&Vec<Value>> = keys.iter().filter(|key| !key.contains(&Value::Null) && seen.insert(encode_key(key), ()).is_none()).collect();
+        keys.chunks((max_params / columns.len().max(1)).max(1)).map(|keys| {
+            let mut select = Select::new(table);
+            select.filter(if let [column] = columns {
+                Condition::In { column, values: keys.iter().map(|key| key[0].clone()).collect() }
+            } else {
+                Condition::Or(keys.iter().map(|key| {
+                    Condition::And(columns.iter().zip(key.iter()).map(|(column, value)| Condition::Compare { column, operator: Operator::Eq, value: value.clone() }).collect())
+                }).collect())
+            });
+            select
+        }).collect()
+    }
+
+    // Soft deleted related rows are left out.
+    fn select_related(columns: &[&'static str], keys: &[Vec<Value>], max_params: usize) -> Vec<Select> {
+        let mut selects = Self::select(R::table_name(), columns, keys, max_params);
+        if let Some(condition) = Condition::not_deleted::<R>() {
+            for select in &mut selects {
+                select.filter(condition.clone());
+            }
+        }
+        selects
+    }
+
+    fn primary_column<T>() -> Result<&'static str> where T: Entity {
+        T::primary_column().ok_or_else(|| Error::missing_primary_key(T::table_name()))
+    }
+}
+
+impl<E, R> Include<E, R> where E: Entity, R: Entity + Clone {
+
+    fn assign_rows(&self, entities: &mut [E], keys: &[Vec<Value>], rows: Vec<R>) {
+        let Link::Columns { references, .. } = &self.link else {
+            return;
+        };
+        let mut related: HashMap<Vec<u8>, Vec<R>> = HashMap::new();
+        for row in rows {
+            let key: Vec<Value> = references.iter().map(|r| row.column_value(r).unwrap_or(Value::Null)).collect();
+            related.entry(encode_key(&key)).or_default().push(row);
+        }
+        for (entity, key) in entities.iter_mut().zip(keys) {
+            (self.assign)(entity, related.get(&encode_key(key)).cloned().unwrap_or_default());
+        }
+    }
+
+    fn assign_joined(&self, entities: &mut [E], keys: &[Vec<Value>], links: Vec<JoinRow>, rows: Vec<R>) {
+        let Link::Join { source, .. } = &self.link else {
+            return;
+        };
+        let related: HashMap<Vec<u8>, R> = rows.into_iter().map(|row| (encode_key(&[row.primary_value()]), row)).collect();
+        let mut linked: HashMap<Vec<u8>, Vec<R>> = HashMap::new();
+        for link in links {
+            let (from, to) = if *source == "A" { (link.a, link.b) } else { (link.b, link.a) };
+            if let Some(row) = related.get(&encode_key(&[to])) {
+                linked.entry(encode_key(&[from])).or_default().push(row.clone());
+            }
+        }
+        for (entity, key) in entities.iter_mut().zip(keys) {
+            (self.assign)(entity, linked.remove(&encode_key(key)).unwrap_or_default());
+        }
+    }
+
+    fn target_keys(&self, links: &[JoinRow]) -> Vec<Vec<Value>> {
+        let source_is_a = matches!(self.link, Link::Join { source: "A", .. });
+        links.iter().map(|link| vec![if source_is_a { link.b.clone() } else { link.a.clone() }]).collect()
+    }
+}
+
+/// The relations included in a query.
+#[doc(hidden)]
+pub trait Includes<E> {
+
+    fn load<C>(&self, connection: &mut C, entities: &mut [E]) -> Result<()> where C: SyncExecutor;
+
+    fn load_async<C>(&self, connection: &mut C, entities: &mut [E]) -> impl Future<Output = Result<()>> + Send where C: AsyncExecutor;
+}
+
+impl<E> Includes<E> for () {
+
+    fn load<C>(&self, _connection: &mut C, _entities: &mut [E]) -> Result<()> where C: SyncExecutor {
+        Ok(())
+    }
+
+    fn load_async<C>(&self, _connection: &mut C, _entities: &mut [E]) -> impl Future<Output = Result<()>> + Send where C: AsyncExecutor {
+        std::future::ready(Ok(()))
+    }
+}
+
+impl<E, I, R> Includes<E> for (I, Include<E, R>) where I: Includes<E> + Sync, E: Entity + Send, R: Entity + FromRow + Clone + Send {
+
+    fn load<C>(&self, connection: &mut C, entities: &mut [E]) -> Result<()> where C: SyncExecutor {
+        self.0.load(connection, entities)?;
+        let include = &self.1;
+        let keys = include.keys(entities);
+        let max_params = connection.max_params();
+        match &include.link {
+            Link::Columns { references, .. } => {
+                let mut rows = Vec::new();
+                for select in Include::<E, R>::select_related(references, &keys, max_params) {
+                    rows.extend(connection.find::<R>(&select)?);
+                }
+                include.assign_rows(entities, &keys, rows);
+            },
+            Link::Join { table, source, .. } => {
+                let mut links = Vec::new();
+                for select in Include::<E, R>::select(table, &[*source], &keys, max_params) {
+                    links.extend(connection.find::<JoinRow>(&select)?);
+                }
+                let primary_column = Include::<E, R>::primary_column::<R>()?;
+                let mut rows = Vec::new();
+                for select in Include::<E, R>::select_related(&[primary_column], &include.target_keys(&links), max_params) {
+                    rows.extend(connection.find::<R>(&select)?);
+                }
+                include.assign_joined(entities, &keys, links, rows);
+            },
+        }
+        Ok(())
+    }
+
+    async fn load_async<C>(&self, connection: &mut C, entities: &mut [E]) -> Result<()> where C: AsyncExecutor {
+        self.0.load_async(connection, entities).await?;
+        let include = &self.1;
+        let keys = include.keys(entities);
+        let max_params = connection.max_params();
+        match &include.link {
+            Link::Columns { references, .. } => {
+                let mut rows = Vec::new();
+                for select in Include::<E, R>::select_related(references, &keys, max_params) {
+                    rows.extend(connection.find::<R>(&select).await?);
+                }
+                include.assign_rows(entities, &keys, rows);
+            },
+            Link::Join { table, source, .. } => {
+                let mut links = Vec::new();
+                for select in Include::<E, R>::select(table, &[*source], &keys, max_params) {
+                    links.extend(connection.find::<JoinRow>(&select).await?);
+                }
+                let primary_column = Include::<E, R>::primary_column::<R>()?;
+                let mut rows = Vec::new();
+                for select in Include::<E, R>::select_related(&[primary_column], &include.target_keys(&links), max_params) {
+                    rows.extend(connection.find::<R>(&select).await?);
+                }
+                include.assign_joined(entities, &keys, links, rows);
+            },
+        }
+        Ok(())
+    }
+}
+
+struct JoinRow {
+    a: Value,
+    b: Value,
+}
+
+impl FromRow for JoinRow {
+
+    fn from_row<T>(row: &T) -> Result<Self> where T: Row + ?Sized {
+        Ok(Self { a: row.get("A")?, b: row.get("B")? })
+    }
+}
+
+// Join table columns may be read back wider than the entity's own key type.
+fn encode_key(key: &[Value]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for value in key {
+        match value {
+            Value::I32(v) => encode(&Value::I64(*v as i64), &mut bytes),
+            value => encode(value, &mut bytes),
+        }
+    }
+    bytes
+}
+
+pub struct Connect<'a, C> {
+    upsert: Result<Upsert>,
+    connection: &'a mut C,
+}
+
+impl<C> Connect<'_, C> where C: SyncExecutor {
+
+    pub fn run(self) -> Result<u64> {
+        self.connection.upsert(&self.upsert?)
+    }
+}
+
+impl<'a, C> IntoFuture for Connect<'a, C> where C: AsyncExecutor {
+
+    type Output = Result<u64>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<u64>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            self.connection.upsert(&self.upsert?).await
+        })
+    }
+}
+
+pub struct Disconnect<'a, C> {
+    delete: Result<Delete>,
+    connection: &'a mut C,
+}
+
+impl<C> Disconnect<'_, C> where C: SyncExecutor {
+
+    pub fn run(self) -> Result<u64> {
+        self.connection.delete(&self.delete?)
+    }
+}
+
+impl<'a, C> IntoFuture for Disconnect<'a, C> where C: AsyncExecutor {
+
+    type Output = Result<u64>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<u64>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            self.connection.delete(&self.delete?).await
+        })
+    }
+}
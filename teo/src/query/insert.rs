@@ -0,0 +1,79 @@
+use std::{future::IntoFuture, pin::Pin, slice};
+use crate::{connection::{AsyncExecutor, SyncExecutor}, error::Result, types::{Entity, Value}};
+
+pub struct Insert<'a, E, C> {
+    entity: &'a mut E,
+    connection: &'a mut C,
+}
+
+impl<'a, E, C> Insert<'a, E, C> {
+
+    pub(crate) fn new(entity: &'a mut E, connection: &'a mut C) -> Self {
+        Self { entity, connection }
+    }
+}
+
+impl<E, C> Insert<'_, E, C> where E: Entity, C: SyncExecutor {
+
+    pub fn run(self) -> Result<()> {
+        let values = self.entity.insert_values();
+        let keys = self.connection.insert::<E>(slice::from_ref(&values))?;
+        assign_generated(self.entity, &values, keys)
+    }
+}
+
+impl<'a, E, C> IntoFuture for Insert<'a, E, C> where E: Entity + Send + Sync, C: AsyncExecutor {
+
+    type Output = Result<()>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            let values = self.entity.insert_values();
+            let keys = self.connection.insert::<E>(slice::from_ref(&values)).await?;
+            assign_generated(self.entity, &values, keys)
+        })
+    }
+}
+
+// Copies what the insert generated, the auto increment key and the timestamps, back into the entity.
+fn assign_generated<E>(entity: &mut E, values: &[(&'static str, Value)], keys: Vec<Value>) -> Result<()> where E: Entity {
+    entity.set_timestamps(values)?;
+    if let Some(key) = keys.into_iter().next() {
+        entity.set_auto_increment_value(key)?;
+    }
+    Ok(())
+}
+
+pub struct InsertMany<'a, E, C> {
+    entities: &'a [E],
+    connection: &'a mut C,
+}
+
+impl<'a, E, C> InsertMany<'a, E, C> {
+
+    pub(crate) fn new(entities: &'a [E], connection: &'a mut C) -> Self {
+        Self { entities, connection }
+    }
+}
+
+impl<E, C> InsertMany<'_, E, C> where E: Entity, C: SyncExecutor {
+
+    pub fn run(self) -> Result<Vec<Value>> {
+        let rows: Vec<_> = self.entities.iter().map(Entity::insert_values).collect();
+        self.connection.insert::<E>(&rows)
+    }
+}
+
+impl<'a, E, C> IntoFuture for InsertMany<'a, E, C> where E: Entity + Sync, C: AsyncExecutor {
+
+    type Output = Result<Vec<Value>>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<Vec<Value>>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let rows: Vec<_> = self.entities.iter().map(Entity::insert_values).collect();
+        Box::pin(async move { self.connection.insert::<E>(&rows).await })
+    }
+}
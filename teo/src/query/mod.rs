@@ -0,0 +1,26 @@
+mod filter;
+mod cursor;
+mod select;
+mod update;
+mod delete;
+mod upsert;
+mod insert;
+mod find;
+mod save;
+mod aggregate;
+mod include;
+pub(crate) mod sql;
+#[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+pub(crate) mod mongo;
+
+pub use filter::{Column, Filter};
+pub use cursor::{Cursor, Order};
+pub use select::Select;
+pub use update::{Update, UpdateMany};
+pub use upsert::{Upsert, UpsertMany};
+pub use delete::{Delete, DeleteMany, DeleteManyReturning, DeleteOne};
+pub use insert::{Insert, InsertMany};
+pub use find::{FindUnique, FindMany};
+pub use save::Save;
+pub use aggregate::{Aggregate, AggregateMany, AggregateRow, Count};
+pub use include::{Connect, Disconnect, Include, Includes};
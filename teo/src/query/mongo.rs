@@ -0,0 +1,157 @@
+use mongodb::{bson::{Bson, Document, Regex, doc}, options::FindOptions};
+use crate::{connectors::value::bson::{from_bson, to_bson}, error::{Error, Result}, types::Value};
+use super::{Aggregate, Select, Upsert, aggregate::Output, filter::{Condition, Operator}};
+
+pub(crate) fn filter_document(filter: Option<&Condition>) -> Document {
+    filter.map(condition_document).unwrap_or_default()
+}
+
+pub(crate) fn find_options(select: &Select) -> FindOptions {
+    let sort = if select.order.is_empty() {
+        None
+    } else {
+        Some(select.order.iter().map(|(column, order)| (column.to_string(), Bson::Int32(order.as_i32()))).collect())
+    };
+    FindOptions::builder().sort(sort).skip(select.offset).limit(select.limit.map(|limit| limit as i64)).build()
+}
+
+pub(crate) fn aggregate_pipeline(aggregate: &Aggregate) -> Vec<Document> {
+    let id = if aggregate.group.is_empty() {
+        Bson::Null
+    } else {
+        Bson::Document(aggregate.group.iter().map(|column| (column.to_string(), Bson::String(format!("${column}")))).collect())
+    };
+    let mut group = doc!{ "_id": id };
+    let mut sums = doc!{};
+    for output in &aggregate.functions {
+        // `$sum` gives 0 without values to add up where SQL gives null, so they are counted.
+        if let Output::Sum(column) = output {
+            let count = format!("{}_count", output.alias());
+            group.insert(&count, doc!{ "$sum": { "$cond": [{ "$isNumber": format!("${column}") }, 1, 0] } });
+            sums.insert(output.alias(), doc!{ "$cond": [{ "$gt": [format!("${count}"), 0] }, format!("${}", output.alias()), Bson::Null] });
+        }
+        let accumulator = match output {
+            Output::Group(_) => continue,
+            Output::Count => doc!{ "$sum": 1 },
+            Output::Sum(column) => doc!{ "$sum": format!("${column}") },
+            Output::Avg(column) => doc!{ "$avg": format!("${column}") },
+            Output::Min(column) => doc!{ "$min": format!("${column}") },
+            Output::Max(column) => doc!{ "$max": format!("${column}") },
+        };
+        group.insert(output.alias(), accumulator);
+    }
+    let mut pipeline = vec![doc!{ "$match": filter_document(aggregate.filter.as_ref()) }, doc!{ "$group": group }];
+    if !sums.is_empty() {
+        pipeline.push(doc!{ "$set": sums });
+    }
+    if !aggregate.group.is_empty() {
+        let sort: Document = aggregate.group.iter().map(|column| (format!("_id.{column}"), Bson::Int32(1))).collect();
+        pipeline.push(doc!{ "$sort": sort });
+    }
+    pipeline
+}
+
+// Without groups SQL always returns a row, an empty collection gives no document at all.
+pub(crate) fn aggregate_values(aggregate: &Aggregate, documents: Vec<Document>) -> Result<Vec<Vec<Value>>> {
+    let outputs = aggregate.outputs();
+    if documents.is_empty() && aggregate.group.is_empty() {
+        return Ok(vec![outputs.iter().map(|output| if *output == Output::Count { Value::I64(0) } else { Value::Null }).collect()]);
+    }
+    documents.into_iter().map(|document| {
+        outputs.iter().map(|output| {
+            let bson = match output {
+                Output::Group(column) => document.get_document("_id").ok().and_then(|id| id.get(column)).cloned(),
+                output => document.get(output.alias()).cloned(),
+            };
+            from_bson(bson.unwrap_or(Bson::Null))
+        }).collect()
+    }).collect()
+}
+
+// Each row becomes a filter on its conflict columns and an update which overwrites the
+// update columns and only sets the rest when the document is inserted.
+pub(crate) fn upsert_documents(upsert: &Upsert) -> Result<Vec<(Document, Document)>> {
+    upsert.rows.iter().map(|row| {
+        // An empty filter would match and overwrite an arbitrary document.
+        if let Some(column) = upsert.conflict.iter().find(|column| !row.iter().any(|(c, _)| c == *column)) {
+            return Err(Error::missing_column(*column));
+        }
+        let mut filter = doc!{};
+        let mut set = doc!{};
+        let mut set_on_insert = doc!{};
+        for (column, value) in row {
+            if upsert.conflict.is_empty() || upsert.conflict.contains(column) {
+                filter.insert(*column, to_bson(value));
+            }
+            if upsert.update.contains(column) {
+                set.insert(*column, to_bson(value));
+            } else {
+                set_on_insert.insert(*column, to_bson(value));
+            }
+        }
+        let mut update = doc!{ "$setOnInsert": set_on_insert };
+        if !set.is_empty() {
+            update.insert("$set", set);
+        }
+        Ok((filter, update))
+    }).collect()
+}
+
+fn condition_document(condition: &Condition) -> Document {
+    match condition {
+        Condition::Compare { column, operator: Operator::Eq, value } => doc!{ *column: to_bson(value) },
+        Condition::Compare { column, operator, value } => {
+            let operator = match operator {
+                Operator::Eq => "$eq",
+                Operator::Ne => "$ne",
+                Operator::Gt => "$gt",
+                Operator::Gte => "$gte",
+                Operator::Lt => "$lt",
+                Operator::Lte => "$lte",
+            };
+            doc!{ *column: { operator: to_bson(value) } }
+        },
+        Condition::In { column, values } => doc!{ *column: { "$in": values.iter().map(to_bson).collect::<Vec<Bson>>() } },
+        Condition::Null { column, negated: false } => doc!{ *column: Bson::Null },
+        Condition::Null { column, negated: true } => doc!{ *column: { "$ne": Bson::Null } },
+        Condition::Like { column, pattern } => doc!{ *column: Regex { pattern: like_to_regex(pattern), options: "s".to_owned() } },
+        Condition::After { columns, values } => condition_document(&Condition::expand_after(columns, values)),
+        Condition::And(conditions) => doc!{ "$and": conditions.iter().map(condition_document).collect::<Vec<Document>>() },
+        Condition::Or(conditions) => doc!{ "$or": conditions.iter().map(condition_document).collect::<Vec<Document>>() },
+        Condition::Not(condition) => doc!{ "$nor": [condition_document(condition)] },
+    }
+}
+
+fn like_to_regex(pattern: &str) -> String {
+    let mut regex = String::from("^");
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            c => {
+                let c = if c == '\\' { chars.next().unwrap_or(c) } else { c };
+                if "\\^$.|?*+()[]{}".contains(c) {
+                    regex.push('\\');
+                }
+                regex.push(c);
+            },
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+// Statement spans show the collection method without its arguments, which hold row values.
+pub(crate) fn method_command(collection: &str, method: &str) -> String {
+    shell_command(Some(collection), method, [])
+}
+
+// Writes a command the way the mongo shell does, migrations report these in place of statements.
+pub(crate) fn shell_command(collection: Option<&str>, method: &str, args: impl IntoIterator<Item = Bson>) -> String {
+    let args: Vec<String> = args.into_iter().map(|arg| arg.to_string()).collect();
+    match collection {
+        Some(collection) => format!("db.{collection}.{method}({})", args.join(", ")),
+        None => format!("db.{method}({})", args.join(", ")),
+    }
+}
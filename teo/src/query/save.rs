@@ -0,0 +1,66 @@
+use std::{future::IntoFuture, pin::Pin};
+use crate::{connection::{AsyncExecutor, SyncExecutor}, error::{Error, Result}, types::Entity};
+use super::{Update, filter::{Condition, Operator}, update::touch};
+
+pub struct Save<'a, E, C> {
+    entity: &'a E,
+    original: Option<&'a E>,
+    connection: &'a mut C,
+}
+
+impl<'a, E, C> Save<'a, E, C> where E: Entity {
+
+    pub(crate) fn new(entity: &'a E, connection: &'a mut C) -> Self {
+        Self { entity, original: None, connection }
+    }
+
+    /// Only writes the fields which differ from `original`, e.g. the entity as it was loaded.
+    pub fn changed_from(mut self, original: &'a E) -> Self {
+        self.original = Some(original);
+        self
+    }
+
+    fn update(entity: &E, original: Option<&E>) -> Result<Update> {
+        let column = E::primary_column().ok_or_else(|| Error::missing_primary_key(E::table_name()))?;
+        let mut update = Update::new(E::table_name());
+        update.filter(Condition::Compare { column, operator: Operator::Eq, value: entity.primary_value() });
+        update.values = entity.update_values();
+        if let Some(original) = original {
+            let original = original.update_values();
+            update.values.retain(|value| !original.contains(value));
+        }
+        if !update.values.is_empty() {
+            touch::<E>(&mut update.values)?;
+        }
+        Ok(update)
+    }
+}
+
+impl<E, C> Save<'_, E, C> where E: Entity, C: SyncExecutor {
+
+    /// Returns the number of affected rows.
+    pub fn run(self) -> Result<u64> {
+        let update = Self::update(self.entity, self.original)?;
+        if update.values.is_empty() {
+            return Ok(0);
+        }
+        self.connection.update(&update)
+    }
+}
+
+impl<'a, E, C> IntoFuture for Save<'a, E, C> where E: Entity + Sync, C: AsyncExecutor {
+
+    type Output = Result<u64>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<u64>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            let update = Self::update(self.entity, self.original)?;
+            if update.values.is_empty() {
+                return Ok(0);
+            }
+            self.connection.update(&update).await
+        })
+    }
+}
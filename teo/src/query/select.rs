@@ -0,0 +1,24 @@
+use crate::types::SortOrder;
+use super::filter::Condition;
+
+pub struct Select {
+    pub(crate) table: &'static str,
+    pub(crate) filter: Option<Condition>,
+    pub(crate) order: Vec<(&'static str, SortOrder)>,
+    pub(crate) limit: Option<u64>,
+    pub(crate) offset: Option<u64>,
+}
+
+impl Select {
+
+    pub(crate) fn new(table: &'static str) -> Self {
+        Self { table, filter: None, order: Vec::new(), limit: None, offset: None }
+    }
+
+    pub(crate) fn filter(&mut self, condition: Condition) {
+        self.filter = Some(match self.filter.take() {
+            Some(existing) => existing.and(condition),
+            None => condition,
+        });
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{connection::IsolationLevel, dialect::Dialect, error::{Error, Result}, query::{Aggregate, Delete, Select, Update, Upsert, sql::{InsertKeys, aggregate_statement, delete_statement, insert_statements, raw_statement, select_statement, update_statement, upsert_statements}}, trace, types::{Entity, FromRow, Value}};
+
+pub(crate) trait AsyncSQLExecutor: Send {
+
+    // Statements are built with the default dialect. MySQL and MariaDB only differ in DDL, so
+    // the server is only told apart when migrating and spans name MariaDB as mysql here.
+    type Dialect: Dialect + Default;
+
+    fn execute(&mut self, q: &str, params: &[Value]) -> impl Future<Output = Result<u64>> + Send;
+
+    fn query_column(&mut self, q: &str, params: &[Value]) -> impl Future<Output = Result<Vec<Value>>> + Send;
+
+    fn query_entities<E>(&mut self, q: &str, params: &[Value]) -> impl Future<Output = Result<Vec<E>>> + Send where E: FromRow + Send;
+
+    fn query_rows(&mut self, q: &str, params: &[Value]) -> impl Future<Output = Result<Vec<Vec<Value>>>> + Send;
+
+    /// Runs statements through the text protocol, transaction control can't always be prepared.
+    fn execute_batch(&mut self, q: &str) -> impl Future<Output = Result<()>> + Send;
+
+    fn last_insert_id(&mut self) -> impl Future<Output = Result<Value>> + Send {
+        async { Err(Error::unsupported(Self::Dialect::default().name(), "last insert id")) }
+    }
+
+    fn insert_rows<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> impl Future<Output = Result<Vec<Value>>> + Send where E: Entity {
+        async move {
+            let dialect = Self::Dialect::default();
+            let (statements, keys) = insert_statements::<_, E>(dialect, rows);
+            let mut values = Vec::new();
+            for statement in statements {
+                trace::statement_async(dialect.name(), Some(E::table_name()), &statement.sql, &statement.params, async {
+                    match keys {
+                        InsertKeys::None => {
+                            self.execute(&statement.sql, &statement.params).await?;
+                        },
+                        InsertKeys::Returning => {
+                            values.extend(self.query_column(&statement.sql, &statement.params).await?);
+                        },
+                        InsertKeys::LastInsertId => {
+                            self.execute(&statement.sql, &statement.params).await?;
+                            values.push(self.last_insert_id().await?);
+                        },
+                    }
+                    Ok(())
+                }).await?;
+            }
+            Ok(values)
+        }
+    }
+
+    fn find_entities<E>(&mut self, select: &Select) -> impl Future<Output = Result<Vec<E>>> + Send where E: FromRow + Send {
+        async move {
+            let dialect = Self::Dialect::default();
+            let statement = select_statement(dialect, select);
+            trace::statement_async(dialect.name(), Some(select.table), &statement.sql, &statement.params, self.query_entities(&statement.sql, &statement.params)).await
+        }
+    }
+
+    fn upsert_entities(&mut self, upsert: &Upsert) -> impl Future<Output = Result<u64>> + Send {
+        async move {
+            let dialect = Self::Dialect::default();
+            let mut count = 0;
+            for statement in upsert_statements(dialect, upsert) {
+                count += trace::statement_async(dialect.name(), Some(upsert.table), &statement.sql, &statement.params, self.execute(&statement.sql, &statement.params)).await?;
+            }
+            Ok(count)
+        }
+    }
+
+    fn update_entities(&mut self, update: &Update) -> impl Future<Output = Result<u64>> + Send {
+        async move {
+            let dialect = Self::Dialect::default();
+            let statement = update_statement(dialect, update);
+            trace::statement_async(dialect.name(), Some(update.table), &statement.sql, &statement.params, self.execute(&statement.sql, &statement.params)).await
+        }
+    }
+
+    fn delete_entities(&mut self, delete: &Delete) -> impl Future<Output = Result<u64>> + Send {
+        async move {
+            let dialect = Self::Dialect::default();
+            let statement = delete_statement(dialect, delete, false);
+            trace::statement_async(dialect.name(), Some(delete.table), &statement.sql, &statement.params, self.execute(&statement.sql, &statement.params)).await
+        }
+    }
+
+    fn delete_entities_returning<E>(&mut self, delete: &Delete) -> impl Future<Output = Result<Vec<E>>> + Send where E: FromRow + Send {
+        async move {
+            let dialect = Self::Dialect::default();
+            if !dialect.supports_returning() {
+                return Err(Error::unsupported(dialect.name(), "delete returning"));
+            }
+            let statement = delete_statement(dialect, delete, true);
+            trace::statement_async(dialect.name(), Some(delete.table), &statement.sql, &statement.params, self.query_entities(&statement.sql, &statement.params)).await
+        }
+    }
+
+    fn aggregate_values(&mut self, aggregate: &Aggregate) -> impl Future<Output = Result<Vec<Vec<Value>>>> + Send {
+        async move {
+            let dialect = Self::Dialect::default();
+            let statement = aggregate_statement(dialect, aggregate);
+            trace::statement_async(dialect.name(), Some(aggregate.table), &statement.sql, &statement.params, self.query_rows(&statement.sql, &statement.params)).await
+        }
+    }
+
+    fn query_raw_entities<E>(&mut self, q: &str, params: &[Value]) -> impl Future<Output = Result<Vec<E>>> + Send where E: FromRow + Send {
+        async move {
+            let dialect = Self::Dialect::default();
+            let statement = raw_statement(dialect, q, params)?;
+            trace::statement_async(dialect.name(), None, &statement.sql, &statement.params, self.query_entities(&statement.sql, &statement.params)).await
+        }
+    }
+
+    fn execute_raw_statement(&mut self, q: &str, params: &[Value]) -> impl Future<Output = Result<u64>> + Send {
+        async move {
+            let dialect = Self::Dialect::default();
+            let statement = raw_statement(dialect, q, params)?;
+            trace::statement_async(dialect.name(), None, &statement.sql, &statement.params, self.execute(&statement.sql, &statement.params)).await
+        }
+    }
+
+    fn begin_transaction(&mut self, isolation: Option<IsolationLevel>) -> impl Future<Output = Result<()>> + Send {
+        async move {
+            for statement in Self::Dialect::default().begin_statements(isolation)? {
+                self.transaction_statement(&statement).await?;
+            }
+            Ok(())
+        }
+    }
+
+    fn savepoint_statement(&mut self, statement: &str, name: &str) -> impl Future<Output = Result<()>> + Send {
+        async move {
+            let q = Self::Dialect::default().ident_quote_char();
+            self.transaction_statement(&format!("{statement} {q}{name}{q}")).await
+        }
+    }
+
+    fn transaction_statement(&mut self, q: &str) -> impl Future<Output = Result<()>> + Send {
+        async move {
+            let dialect = Self::Dialect::default();
+            trace::statement_async(dialect.name(), None, q, &[], self.execute_batch(q)).await
+        }
+    }
+}
@@ -0,0 +1,246 @@
+mod sync;
+mod r#async;
+
+pub(crate) use sync::SyncSQLExecutor;
+pub(crate) use r#async::AsyncSQLExecutor;
+
+use crate::{dialect::Dialect, error::{Error, Result}, query::{Aggregate, Delete, Select, Update, Upsert, aggregate::Output, filter::{Condition, Operator}}, types::{Entity, SortOrder, Value}};
+
+pub(crate) struct Statement {
+    pub(crate) sql: String,
+    pub(crate) params: Vec<Value>,
+}
+
+pub(crate) enum InsertKeys {
+    None,
+    Returning,
+    LastInsertId,
+}
+
+// Consecutive rows sharing the same column list are inserted with a single statement unless
+// keys have to be fetched one by one.
+pub(crate) fn insert_statements<D, E>(dialect: D, rows: &[Vec<(&'static str, Value)>]) -> (Vec<Statement>, InsertKeys) where D: Dialect, E: Entity {
+    let key = E::auto_increment_column();
+    let keys = match key {
+        None => InsertKeys::None,
+        Some(_) if dialect.supports_returning() => InsertKeys::Returning,
+        Some(_) => InsertKeys::LastInsertId,
+    };
+    let returning = if matches!(keys, InsertKeys::Returning) { key } else { None };
+    let rows = rows.to_vec();
+    let statements = if matches!(keys, InsertKeys::LastInsertId) {
+        rows.into_iter().map(|row| {
+            let (columns, params): (Vec<&str>, Vec<Value>) = row.into_iter().unzip();
+            let sql = dialect.insert_statement(E::table_name(), &columns, 1, returning);
+            Statement { sql, params }
+        }).collect()
+    } else {
+        batches(dialect, rows).into_iter().map(|(columns, rows)| {
+            let sql = dialect.insert_statement(E::table_name(), &columns, rows.len(), returning);
+            Statement { sql, params: rows.into_iter().flatten().collect() }
+        }).collect()
+    };
+    (statements, keys)
+}
+
+pub(crate) fn upsert_statements<D>(dialect: D, upsert: &Upsert) -> Vec<Statement> where D: Dialect {
+    let rows = upsert.rows.iter().map(|row| row.to_vec()).collect();
+    batches(dialect, rows).into_iter().map(|(columns, rows)| {
+        let mut sql = dialect.insert_statement(upsert.table, &columns, rows.len(), None);
+        sql.push_str(&dialect.upsert_clause(&columns, &upsert.conflict, &upsert.update));
+        Statement { sql, params: rows.into_iter().flatten().collect() }
+    }).collect()
+}
+
+// Groups consecutive rows with the same column list and splits each group so that a
+// statement never binds more parameters than the driver accepts.
+fn batches<D>(dialect: D, rows: Vec<Vec<(&'static str, Value)>>) -> Vec<(Vec<&'static str>, Vec<Vec<Value>>)> where D: Dialect {
+    let mut batches: Vec<(Vec<&'static str>, Vec<Vec<Value>>)> = Vec::new();
+    for row in rows {
+        let (columns, values): (Vec<&'static str>, Vec<Value>) = row.into_iter().unzip();
+        let capacity = if columns.is_empty() { 1 } else { (dialect.max_params() / columns.len()).max(1) };
+        match batches.last_mut() {
+            Some((last_columns, last_rows)) if *last_columns == columns && last_rows.len() < capacity => {
+                last_rows.push(values);
+            },
+            _ => batches.push((columns, vec![values])),
+        }
+    }
+    batches
+}
+
+pub(crate) fn select_statement<D>(dialect: D, select: &Select) -> Statement where D: Dialect {
+    let q = dialect.ident_quote_char();
+    let mut params = Vec::new();
+    let mut sql = format!("select * from {q}{}{q}", select.table);
+    if let Some(filter) = &select.filter {
+        sql.push_str(" where ");
+        sql.push_str(&condition_sql(dialect, filter, &mut params));
+    }
+    if !select.order.is_empty() {
+        let order = select.order.iter().map(|(column, order)| format!("{q}{column}{q} {}", order.as_ref())).collect::<Vec<_>>().join(",");
+        sql.push_str(&format!(" order by {order}"));
+    }
+    sql.push_str(&dialect.limit_clause(select.limit, select.offset));
+    Statement { sql, params }
+}
+
+pub(crate) fn aggregate_statement<D>(dialect: D, aggregate: &Aggregate) -> Statement where D: Dialect {
+    let q = dialect.ident_quote_char();
+    let mut params = Vec::new();
+    let outputs = aggregate.outputs().iter().map(|output| {
+        let expression = match output {
+            Output::Group(column) => return format!("{q}{column}{q}"),
+            Output::Count => "count(*)".to_owned(),
+            Output::Sum(column) => format!("sum({q}{column}{q})"),
+            Output::Avg(column) => dialect.float_cast(&format!("avg({q}{column}{q})")),
+            Output::Min(column) => format!("min({q}{column}{q})"),
+            Output::Max(column) => format!("max({q}{column}{q})"),
+        };
+        format!("{expression} as {q}{}{q}", output.alias())
+    }).collect::<Vec<_>>().join(",");
+    let mut sql = format!("select {outputs} from {q}{}{q}", aggregate.table);
+    if let Some(filter) = &aggregate.filter {
+        sql.push_str(" where ");
+        sql.push_str(&condition_sql(dialect, filter, &mut params));
+    }
+    if !aggregate.group.is_empty() {
+        let group = aggregate.group.iter().map(|column| format!("{q}{column}{q}")).collect::<Vec<_>>().join(",");
+        sql.push_str(&format!(" group by {group} order by {group}"));
+    }
+    Statement { sql, params }
+}
+
+pub(crate) fn update_statement<D>(dialect: D, update: &Update) -> Statement where D: Dialect {
+    let q = dialect.ident_quote_char();
+    let mut params = Vec::new();
+    let assignments = update.values.iter().map(|(column, value)| {
+        params.push(value.clone());
+        format!("{q}{column}{q} = {}", dialect.placeholder(params.len()))
+    }).collect::<Vec<_>>().join(",");
+    let mut sql = format!("update {q}{}{q} set {assignments}", update.table);
+    if let Some(filter) = &update.filter {
+        sql.push_str(" where ");
+        sql.push_str(&condition_sql(dialect, filter, &mut params));
+    }
+    Statement { sql, params }
+}
+
+pub(crate) fn delete_statement<D>(dialect: D, delete: &Delete, returning: bool) -> Statement where D: Dialect {
+    let q = dialect.ident_quote_char();
+    let mut params = Vec::new();
+    let mut sql = format!("delete from {q}{}{q}", delete.table);
+    if let Some(filter) = &delete.filter {
+        sql.push_str(" where ");
+        sql.push_str(&condition_sql(dialect, filter, &mut params));
+    }
+    if returning {
+        sql.push_str(" returning *");
+    }
+    Statement { sql, params }
+}
+
+// Hand-written SQL numbers its placeholders `$1`, `$2`, … for every dialect. Each placeholder
+// outside of literals, quoted identifiers and comments is replaced by the dialect's own, binding
+// the parameters in the order the placeholders appear. Every parameter has to be used.
+pub(crate) fn raw_statement<D>(dialect: D, q: &str, params: &[Value]) -> Result<Statement> where D: Dialect {
+    let chars: Vec<char> = q.chars().collect();
+    let mut sql = String::with_capacity(q.len());
+    let mut bound = Vec::new();
+    let mut used = vec![false; params.len()];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let end = match c {
+            '\'' | '"' => quoted_end(&chars, i, dialect.backslash_escapes()),
+            '`' => quoted_end(&chars, i, false),
+            '-' if chars.get(i + 1) == Some(&'-') => chars[i..].iter().position(|&e| e == '\n').map_or(chars.len(), |p| i + p + 1),
+            '/' if chars.get(i + 1) == Some(&'*') => chars[i + 2..].windows(2).position(|w| w == ['*', '/']).map_or(chars.len(), |p| i + p + 4),
+            '$' if chars.get(i + 1).is_some_and(char::is_ascii_digit) => {
+                let digits = chars[i + 1..].iter().take_while(|c| c.is_ascii_digit()).count();
+                let index: String = chars[i + 1..i + 1 + digits].iter().collect();
+                let index: usize = index.parse().unwrap_or(0);
+                let value = index.checked_sub(1).and_then(|index| params.get(index)).ok_or(Error::MissingParameter { index })?;
+                bound.push(value.clone());
+                used[index - 1] = true;
+                sql.push_str(&dialect.placeholder(bound.len()));
+                i += digits + 1;
+                continue;
+            },
+            // A dollar quoted string, `$tag$ … $tag$`, unless the dollar sign is part of an identifier.
+            '$' if dialect.dollar_quotes() && (i == 0 || !(chars[i - 1].is_alphanumeric() || chars[i - 1] == '_')) => match chars[i + 1..].iter().position(|&e| e == '$') {
+                Some(p) if chars[i + 1..i + 1 + p].iter().all(|c| c.is_alphanumeric() || *c == '_') => {
+                    let tag = &chars[i..i + p + 2];
+                    let body = i + tag.len();
+                    chars[body..].windows(tag.len()).position(|w| w == tag).map_or(chars.len(), |p| body + p + tag.len())
+                },
+                _ => i + 1,
+            },
+            _ => i + 1,
+        };
+        sql.extend(&chars[i..end]);
+        i = end;
+    }
+    if let Some(index) = used.iter().position(|used| !used) {
+        return Err(Error::UnusedParameter { index: index + 1 });
+    }
+    Ok(Statement { sql, params: bound })
+}
+
+// The index past the quote closing the one at `start`.
+fn quoted_end(chars: &[char], start: usize, backslash_escapes: bool) -> usize {
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if backslash_escapes => i += 2,
+            c if c == chars[start] => return i + 1,
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+pub(crate) fn condition_sql<D>(dialect: D, condition: &Condition, params: &mut Vec<Value>) -> String where D: Dialect {
+    let q = dialect.ident_quote_char();
+    let bind = |params: &mut Vec<Value>, value: &Value| {
+        params.push(value.clone());
+        dialect.placeholder(params.len())
+    };
+    match condition {
+        Condition::Compare { column, operator: Operator::Eq, value: Value::Null } => format!("{q}{column}{q} is null"),
+        Condition::Compare { column, operator: Operator::Ne, value: Value::Null } => format!("{q}{column}{q} is not null"),
+        Condition::Compare { column, operator, value } => {
+            let operator = match operator {
+                Operator::Eq => "=",
+                Operator::Ne => "<>",
+                Operator::Gt => ">",
+                Operator::Gte => ">=",
+                Operator::Lt => "<",
+                Operator::Lte => "<=",
+            };
+            format!("{q}{column}{q} {operator} {}", bind(params, value))
+        },
+        Condition::In { values, .. } if values.is_empty() => "1 = 0".to_owned(),
+        Condition::In { column, values } => {
+            let placeholders = values.iter().map(|value| bind(params, value)).collect::<Vec<_>>().join(",");
+            format!("{q}{column}{q} in ({placeholders})")
+        },
+        Condition::Null { column, negated } => format!("{q}{column}{q} is {}null", if *negated { "not " } else { "" }),
+        Condition::Like { column, pattern } => format!("{q}{column}{q} like {}", bind(params, &Value::String(pattern.clone()))),
+        Condition::After { columns, values } if columns.iter().all(|(_, order)| *order == columns[0].1) => {
+            let operator = match columns[0].1 {
+                SortOrder::Asc => ">",
+                SortOrder::Desc => "<",
+            };
+            let columns = columns.iter().map(|(column, _)| format!("{q}{column}{q}")).collect::<Vec<_>>().join(",");
+            let placeholders = values.iter().map(|value| bind(params, value)).collect::<Vec<_>>().join(",");
+            format!("({columns}) {operator} ({placeholders})")
+        },
+        Condition::After { columns, values } => condition_sql(dialect, &Condition::expand_after(columns, values), params),
+        Condition::And(conditions) if conditions.is_empty() => "1 = 1".to_owned(),
+        Condition::And(conditions) => conditions.iter().map(|c| format!("({})", condition_sql(dialect, c, params))).collect::<Vec<_>>().join(" and "),
+        Condition::Or(conditions) if conditions.is_empty() => "1 = 0".to_owned(),
+        Condition::Or(conditions) => conditions.iter().map(|c| format!("({})", condition_sql(dialect, c, params))).collect::<Vec<_>>().join(" or "),
+        Condition::Not(condition) => format!("not ({})", condition_sql(dialect, condition, params)),
+    }
+}
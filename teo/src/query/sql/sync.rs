@@ -0,0 +1,118 @@
+use crate::{connection::IsolationLevel, dialect::Dialect, error::{Error, Result}, query::{Aggregate, Delete, Select, Update, Upsert, sql::{InsertKeys, aggregate_statement, delete_statement, insert_statements, raw_statement, select_statement, update_statement, upsert_statements}}, trace, types::{Entity, FromRow, Value}};
+
+pub(crate) trait SyncSQLExecutor {
+
+    // Statements are built with the default dialect. MySQL and MariaDB only differ in DDL, so
+    // the server is only told apart when migrating and spans name MariaDB as mysql here.
+    type Dialect: Dialect + Default;
+
+    fn execute(&mut self, q: &str, params: &[Value]) -> Result<u64>;
+
+    fn query_column(&mut self, q: &str, params: &[Value]) -> Result<Vec<Value>>;
+
+    fn query_entities<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow;
+
+    fn query_rows(&mut self, q: &str, params: &[Value]) -> Result<Vec<Vec<Value>>>;
+
+    /// Runs statements through the text protocol, transaction control can't always be prepared.
+    fn execute_batch(&mut self, q: &str) -> Result<()>;
+
+    fn last_insert_id(&mut self) -> Result<Value> {
+        Err(Error::unsupported(Self::Dialect::default().name(), "last insert id"))
+    }
+
+    fn insert_rows<E>(&mut self, rows: &[Vec<(&'static str, Value)>]) -> Result<Vec<Value>> where E: Entity {
+        let dialect = Self::Dialect::default();
+        let (statements, keys) = insert_statements::<_, E>(dialect, rows);
+        let mut values = Vec::new();
+        for statement in statements {
+            trace::statement(dialect.name(), Some(E::table_name()), &statement.sql, &statement.params, || {
+                match keys {
+                    InsertKeys::None => {
+                        self.execute(&statement.sql, &statement.params)?;
+                    },
+                    InsertKeys::Returning => {
+                        values.extend(self.query_column(&statement.sql, &statement.params)?);
+                    },
+                    InsertKeys::LastInsertId => {
+                        self.execute(&statement.sql, &statement.params)?;
+                        values.push(self.last_insert_id()?);
+                    },
+                }
+                Ok(())
+            })?;
+        }
+        Ok(values)
+    }
+
+    fn find_entities<E>(&mut self, select: &Select) -> Result<Vec<E>> where E: FromRow {
+        let dialect = Self::Dialect::default();
+        let statement = select_statement(dialect, select);
+        trace::statement(dialect.name(), Some(select.table), &statement.sql, &statement.params, || self.query_entities(&statement.sql, &statement.params))
+    }
+
+    fn upsert_entities(&mut self, upsert: &Upsert) -> Result<u64> {
+        let dialect = Self::Dialect::default();
+        let mut count = 0;
+        for statement in upsert_statements(dialect, upsert) {
+            count += trace::statement(dialect.name(), Some(upsert.table), &statement.sql, &statement.params, || self.execute(&statement.sql, &statement.params))?;
+        }
+        Ok(count)
+    }
+
+    fn update_entities(&mut self, update: &Update) -> Result<u64> {
+        let dialect = Self::Dialect::default();
+        let statement = update_statement(dialect, update);
+        trace::statement(dialect.name(), Some(update.table), &statement.sql, &statement.params, || self.execute(&statement.sql, &statement.params))
+    }
+
+    fn delete_entities(&mut self, delete: &Delete) -> Result<u64> {
+        let dialect = Self::Dialect::default();
+        let statement = delete_statement(dialect, delete, false);
+        trace::statement(dialect.name(), Some(delete.table), &statement.sql, &statement.params, || self.execute(&statement.sql, &statement.params))
+    }
+
+    fn delete_entities_returning<E>(&mut self, delete: &Delete) -> Result<Vec<E>> where E: FromRow {
+        let dialect = Self::Dialect::default();
+        if !dialect.supports_returning() {
+            return Err(Error::unsupported(dialect.name(), "delete returning"));
+        }
+        let statement = delete_statement(dialect, delete, true);
+        trace::statement(dialect.name(), Some(delete.table), &statement.sql, &statement.params, || self.query_entities(&statement.sql, &statement.params))
+    }
+
+    fn aggregate_values(&mut self, aggregate: &Aggregate) -> Result<Vec<Vec<Value>>> {
+        let dialect = Self::Dialect::default();
+        let statement = aggregate_statement(dialect, aggregate);
+        trace::statement(dialect.name(), Some(aggregate.table), &statement.sql, &statement.params, || self.query_rows(&statement.sql, &statement.params))
+    }
+
+    fn query_raw_entities<E>(&mut self, q: &str, params: &[Value]) -> Result<Vec<E>> where E: FromRow {
+        let dialect = Self::Dialect::default();
+        let statement = raw_statement(dialect, q, params)?;
+        trace::statement(dialect.name(), None, &statement.sql, &statement.params, || self.query_entities(&statement.sql, &statement.params))
+    }
+
+    fn execute_raw_statement(&mut self, q: &str, params: &[Value]) -> Result<u64> {
+        let dialect = Self::Dialect::default();
+        let statement = raw_statement(dialect, q, params)?;
+        trace::statement(dialect.name(), None, &statement.sql, &statement.params, || self.execute(&statement.sql, &statement.params))
+    }
+
+    fn begin_transaction(&mut self, isolation: Option<IsolationLevel>) -> Result<()> {
+        for statement in Self::Dialect::default().begin_statements(isolation)? {
+            self.transaction_statement(&statement)?;
+        }
+        Ok(())
+    }
+
+    fn savepoint_statement(&mut self, statement: &str, name: &str) -> Result<()> {
+        let q = Self::Dialect::default().ident_quote_char();
+        self.transaction_statement(&format!("{statement} {q}{name}{q}"))
+    }
+
+    fn transaction_statement(&mut self, q: &str) -> Result<()> {
+        let dialect = Self::Dialect::default();
+        trace::statement(dialect.name(), None, q, &[], || self.execute_batch(q))
+    }
+}
@@ -0,0 +1,95 @@
+use std::{future::IntoFuture, marker::PhantomData, pin::Pin};
+use crate::{connection::{AsyncExecutor, SyncExecutor}, error::Result, types::{Changeset, Entity, Value}};
+use super::{Filter, filter::Condition};
+
+pub struct Update {
+    pub(crate) table: &'static str,
+    pub(crate) filter: Option<Condition>,
+    pub(crate) values: Vec<(&'static str, Value)>,
+}
+
+impl Update {
+
+    pub(crate) fn new(table: &'static str) -> Self {
+        Self { table, filter: None, values: Vec::new() }
+    }
+
+    pub(crate) fn filter(&mut self, condition: Condition) {
+        self.filter = Some(match self.filter.take() {
+            Some(existing) => existing.and(condition),
+            None => condition,
+        });
+    }
+}
+
+#[cfg(feature = "chrono")]
+pub(crate) fn timestamp() -> Result<Value> {
+    Ok(Value::now())
+}
+
+#[cfg(not(feature = "chrono"))]
+pub(crate) fn timestamp() -> Result<Value> {
+    Err(crate::error::Error::unsupported("builds without the `chrono` feature", "timestamp columns"))
+}
+
+// Changesets which leave out the `#[teo(updated_at)]` column have it set to the current time.
+pub(crate) fn touch<E>(values: &mut Vec<(&'static str, Value)>) -> Result<()> where E: Entity {
+    if let Some(column) = E::updated_at_column() && !values.iter().any(|(c, _)| *c == column) {
+        values.push((column, timestamp()?));
+    }
+    Ok(())
+}
+
+pub struct UpdateMany<'a, E, C> {
+    update: Update,
+    connection: &'a mut C,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<'a, E, C> UpdateMany<'a, E, C> where E: Entity {
+
+    pub(crate) fn new(connection: &'a mut C) -> Self {
+        Self { update: Update::new(E::table_name()), connection, _marker: PhantomData }
+    }
+
+    pub fn filter(mut self, filter: Filter<E>) -> Self {
+        self.update.filter(filter.condition);
+        self
+    }
+
+    pub fn set(mut self, changeset: E::Changeset) -> Self {
+        self.update.values.extend(changeset.changes());
+        self
+    }
+}
+
+impl<E, C> UpdateMany<'_, E, C> where E: Entity, C: SyncExecutor {
+
+    /// Returns the number of affected rows.
+    pub fn run(self) -> Result<u64> {
+        if self.update.values.is_empty() {
+            return Ok(0);
+        }
+        let mut update = self.update;
+        touch::<E>(&mut update.values)?;
+        self.connection.update(&update)
+    }
+}
+
+impl<'a, E, C> IntoFuture for UpdateMany<'a, E, C> where E: Entity + 'a, C: AsyncExecutor {
+
+    type Output = Result<u64>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<u64>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            if self.update.values.is_empty() {
+                return Ok(0);
+            }
+            let mut update = self.update;
+            touch::<E>(&mut update.values)?;
+            self.connection.update(&update).await
+        })
+    }
+}
@@ -0,0 +1,93 @@
+use std::{future::IntoFuture, pin::Pin};
+use crate::{connection::{AsyncExecutor, SyncExecutor}, error::{Error, Result}, types::{Entity, Value}};
+use super::Column;
+
+pub struct Upsert {
+    pub(crate) table: &'static str,
+    pub(crate) rows: Vec<Vec<(&'static str, Value)>>,
+    pub(crate) conflict: Vec<&'static str>,
+    pub(crate) update: Vec<&'static str>,
+}
+
+pub struct UpsertMany<'a, E, C> {
+    entities: &'a [E],
+    conflict: Vec<&'static str>,
+    update: Vec<&'static str>,
+    connection: &'a mut C,
+}
+
+impl<'a, E, C> UpsertMany<'a, E, C> where E: Entity {
+
+    pub(crate) fn new(entities: &'a [E], connection: &'a mut C) -> Self {
+        Self { entities, conflict: Vec::new(), update: Vec::new(), connection }
+    }
+
+    /// Defaults to the primary key, which is then sent even when it's auto incremented. Call
+    /// again for a composite unique key.
+    pub fn on_conflict<T>(mut self, column: Column<E, T>) -> Self {
+        self.conflict.push(column.name());
+        self
+    }
+
+    /// Columns overwritten on conflict, without any the existing row is kept.
+    pub fn update<I, T>(mut self, columns: I) -> Self where I: IntoIterator<Item = Column<E, T>> {
+        self.update.extend(columns.into_iter().map(|c| c.name()));
+        self
+    }
+
+    fn upsert(&self) -> Result<Upsert> {
+        let mut conflict = self.conflict.clone();
+        if conflict.is_empty() {
+            conflict.extend(E::primary_column());
+        }
+        if conflict.is_empty() && !self.update.is_empty() {
+            return Err(Error::missing_primary_key(E::table_name()));
+        }
+        let mut update = self.update.clone();
+        if !update.is_empty() && let Some(column) = E::updated_at_column() && !update.contains(&column) {
+            update.push(column);
+        }
+        // Insert values leave out auto increment keys, a conflict on one can only match when the
+        // key is sent along with the row.
+        let rows = self.entities.iter().map(|entity| {
+            let mut row = entity.insert_values();
+            for column in &conflict {
+                if !row.iter().any(|(c, _)| c == column) {
+                    row.push((column, entity.column_value(column).ok_or_else(|| Error::missing_column(*column))?));
+                }
+            }
+            Ok(row)
+        }).collect::<Result<_>>()?;
+        Ok(Upsert { table: E::table_name(), rows, conflict, update })
+    }
+}
+
+impl<E, C> UpsertMany<'_, E, C> where E: Entity, C: SyncExecutor {
+
+    /// Returns the number of affected rows as reported by the driver.
+    pub fn run(self) -> Result<u64> {
+        if self.entities.is_empty() {
+            return Ok(0);
+        }
+        let upsert = self.upsert()?;
+        self.connection.upsert(&upsert)
+    }
+}
+
+impl<'a, E, C> IntoFuture for UpsertMany<'a, E, C> where E: Entity + Sync, C: AsyncExecutor {
+
+    type Output = Result<u64>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<u64>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            if self.entities.is_empty() {
+                return Ok(0);
+            }
+            let upsert = self.upsert()?;
+            self.connection.upsert(&upsert).await
+        })
+    }
+}
+
@@ -0,0 +1,67 @@
+use std::{future::IntoFuture, marker::PhantomData, pin::Pin};
+use crate::{connection::{AsyncExecutor, SyncExecutor}, error::Result, types::{FromRow, ToValue, Value}};
+
+/// Runs hand-written SQL and decodes the rows with the entity's row mapping. Placeholders are
+/// written `$1`, `$2`, … for every database and rewritten to the dialect's own syntax.
+pub fn query_as<'a, E, C>(connection: &'a mut C, q: &str, params: &[&dyn ToValue]) -> QueryAs<'a, E, C> {
+    QueryAs { q: q.to_owned(), params: params.iter().map(|p| p.to_value()).collect(), connection, _marker: PhantomData }
+}
+
+/// Runs a hand-written statement and returns the number of affected rows, placeholders are
+/// written as for `query_as`.
+pub fn execute<'a, C>(connection: &'a mut C, q: &str, params: &[&dyn ToValue]) -> Execute<'a, C> {
+    Execute { q: q.to_owned(), params: params.iter().map(|p| p.to_value()).collect(), connection }
+}
+
+pub struct QueryAs<'a, E, C> {
+    q: String,
+    params: Vec<Value>,
+    connection: &'a mut C,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E, C> QueryAs<'_, E, C> where E: FromRow, C: SyncExecutor {
+
+    pub fn run(self) -> Result<Vec<E>> {
+        self.connection.query_raw(&self.q, &self.params)
+    }
+}
+
+impl<'a, E, C> IntoFuture for QueryAs<'a, E, C> where E: FromRow + Send + 'a, C: AsyncExecutor {
+
+    type Output = Result<Vec<E>>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<Vec<E>>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            self.connection.query_raw(&self.q, &self.params).await
+        })
+    }
+}
+
+pub struct Execute<'a, C> {
+    q: String,
+    params: Vec<Value>,
+    connection: &'a mut C,
+}
+
+impl<C> Execute<'_, C> where C: SyncExecutor {
+
+    pub fn run(self) -> Result<u64> {
+        self.connection.execute_raw(&self.q, &self.params)
+    }
+}
+
+impl<'a, C> IntoFuture for Execute<'a, C> where C: AsyncExecutor {
+
+    type Output = Result<u64>;
+
+    type IntoFuture = Pin<Box<dyn Future<Output = Result<u64>> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            self.connection.execute_raw(&self.q, &self.params).await
+        })
+    }
+}
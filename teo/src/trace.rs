@@ -0,0 +1,185 @@
+use futures::stream::BoxStream;
+use crate::{error::Result, types::Value};
+
+#[cfg(feature = "tracing")]
+use std::{pin::Pin, sync::atomic::{AtomicBool, Ordering}, task::{Context, Poll}, time::Instant};
+#[cfg(feature = "tracing")]
+use futures::{Stream, StreamExt};
+#[cfg(feature = "tracing")]
+use tracing::{Instrument, Span, field};
+#[cfg(feature = "tracing")]
+use crate::error::Error;
+
+#[cfg(feature = "tracing")]
+static PARAMETERS: AtomicBool = AtomicBool::new(false);
+
+/// Records statement parameters on the spans, they are redacted unless enabled.
+#[cfg(feature = "tracing")]
+pub fn record_parameters(enabled: bool) {
+    PARAMETERS.store(enabled, Ordering::Relaxed);
+}
+
+// Runs a statement inside a `teo.statement` span.
+pub(crate) fn statement<T>(dialect: &'static str, table: Option<&str>, sql: &str, params: &[Value], run: impl FnOnce() -> Result<T>) -> Result<T> {
+    #[cfg(feature = "tracing")] {
+        let span = statement_span(dialect, table, sql, params);
+        let start = Instant::now();
+        let result = span.in_scope(run);
+        finish(&span, result.as_ref().err(), start);
+        result
+    }
+    #[cfg(not(feature = "tracing"))] {
+        let _ = (dialect, table, sql, params);
+        run()
+    }
+}
+
+pub(crate) async fn statement_async<T>(dialect: &'static str, table: Option<&str>, sql: &str, params: &[Value], run: impl Future<Output = Result<T>>) -> Result<T> {
+    #[cfg(feature = "tracing")] {
+        let span = statement_span(dialect, table, sql, params);
+        let start = Instant::now();
+        let result = run.instrument(span.clone()).await;
+        finish(&span, result.as_ref().err(), start);
+        result
+    }
+    #[cfg(not(feature = "tracing"))] {
+        let _ = (dialect, table, sql, params);
+        run.await
+    }
+}
+
+// Wraps the rows of a streamed statement, the `teo.statement` span ends with the stream or when it's dropped.
+pub(crate) fn statement_stream<'a, T>(dialect: &'static str, table: Option<&str>, sql: &str, params: &[Value]) -> impl FnOnce(BoxStream<'a, Result<T>>) -> BoxStream<'a, Result<T>> + use<'a, T> where T: Send + 'a {
+    #[cfg(feature = "tracing")] {
+        let span = statement_span(dialect, table, sql, params);
+        let start = Instant::now();
+        move |stream| TracedStream { stream, span, start, finished: false }.boxed()
+    }
+    #[cfg(not(feature = "tracing"))] {
+        let _ = (dialect, table, sql, params);
+        |stream| stream
+    }
+}
+
+// Runs a catalog lookup inside a `teo.introspect` span, the lookup may take several queries.
+pub(crate) fn introspect<T>(dialect: &'static str, table: Option<&str>, operation: &'static str, run: impl FnOnce() -> Result<T>) -> Result<T> {
+    #[cfg(feature = "tracing")] {
+        let span = introspect_span(dialect, table, operation);
+        let start = Instant::now();
+        let result = span.in_scope(run);
+        finish(&span, result.as_ref().err(), start);
+        result
+    }
+    #[cfg(not(feature = "tracing"))] {
+        let _ = (dialect, table, operation);
+        run()
+    }
+}
+
+pub(crate) async fn introspect_async<T>(dialect: &'static str, table: Option<&str>, operation: &'static str, run: impl Future<Output = Result<T>>) -> Result<T> {
+    #[cfg(feature = "tracing")] {
+        let span = introspect_span(dialect, table, operation);
+        let start = Instant::now();
+        let result = run.instrument(span.clone()).await;
+        finish(&span, result.as_ref().err(), start);
+        result
+    }
+    #[cfg(not(feature = "tracing"))] {
+        let _ = (dialect, table, operation);
+        run.await
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn statement_span(dialect: &'static str, table: Option<&str>, sql: &str, params: &[Value]) -> Span {
+    let span = tracing::debug_span!(
+        target: "teo",
+        "teo.statement",
+        otel.kind = "client",
+        db.system = dialect,
+        db.sql.table = field::Empty,
+        db.statement = sql,
+        db.params = field::Empty,
+        elapsed_ms = field::Empty,
+    );
+    if let Some(table) = table {
+        span.record("db.sql.table", table);
+    }
+    if PARAMETERS.load(Ordering::Relaxed) {
+        span.record("db.params", field::debug(params));
+    } else if !params.is_empty() {
+        span.record("db.params", "[redacted]");
+    }
+    span
+}
+
+#[cfg(feature = "tracing")]
+fn introspect_span(dialect: &'static str, table: Option<&str>, operation: &'static str) -> Span {
+    let span = tracing::debug_span!(
+        target: "teo",
+        "teo.introspect",
+        otel.kind = "client",
+        db.system = dialect,
+        db.sql.table = field::Empty,
+        db.operation = operation,
+        elapsed_ms = field::Empty,
+    );
+    if let Some(table) = table {
+        span.record("db.sql.table", table);
+    }
+    span
+}
+
+#[cfg(feature = "tracing")]
+fn finish(span: &Span, error: Option<&Error>, start: Instant) {
+    let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+    span.record("elapsed_ms", elapsed_ms);
+    span.in_scope(|| match error {
+        None => tracing::debug!(target: "teo", elapsed_ms, "finished"),
+        Some(error) => tracing::error!(target: "teo", elapsed_ms, error = %error, "failed"),
+    });
+}
+
+#[cfg(feature = "tracing")]
+struct TracedStream<'a, T> {
+    stream: BoxStream<'a, Result<T>>,
+    span: Span,
+    start: Instant,
+    finished: bool,
+}
+
+#[cfg(feature = "tracing")]
+impl<T> TracedStream<'_, T> {
+
+    fn finish(&mut self, error: Option<&Error>) {
+        if !self.finished {
+            self.finished = true;
+            finish(&self.span, error, self.start);
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<T> Stream for TracedStream<'_, T> {
+
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<T>>> {
+        let this = &mut *self;
+        let poll = this.span.in_scope(|| this.stream.poll_next_unpin(cx));
+        match &poll {
+            Poll::Ready(Some(Err(error))) => this.finish(Some(error)),
+            Poll::Ready(None) => this.finish(None),
+            _ => (),
+        }
+        poll
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<T> Drop for TracedStream<'_, T> {
+
+    fn drop(&mut self) {
+        self.finish(None);
+    }
+}
@@ -0,0 +1,28 @@
+use super::Value;
+
+/// A field of a changeset, only `Set` fields are written.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Change<T> {
+    #[default]
+    Unset,
+    Set(T),
+}
+
+impl<T> Change<T> {
+
+    pub fn is_set(&self) -> bool {
+        matches!(self, Change::Set(_))
+    }
+}
+
+impl<T> From<T> for Change<T> {
+
+    fn from(value: T) -> Self {
+        Change::Set(value)
+    }
+}
+
+pub trait Changeset {
+
+    fn changes(&self) -> Vec<(&'static str, Value)>;
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "duckdb")]
+use teo_column_type::duckdb;
 #[cfg(feature = "mongo")]
 use teo_column_type::mongo;
 #[cfg(feature = "mysql")]
@@ -6,19 +8,114 @@ use teo_column_type::mysql;
 use teo_column_type::postgres;
 #[cfg(feature = "sqlite")]
 use teo_column_type::sqlite;
-use super::super::migration::TableDef;
+use crate::{error::Result, query::{AggregateMany, Count, DeleteMany, DeleteOne, FindMany, FindUnique, Insert, InsertMany, Save, UpdateMany, UpsertMany}};
+use super::{super::migration::TableDef, Changeset, ToValue, Value};
 
 pub trait Entity {
 
-    #[cfg(feature = "mysql")]
+    type PrimaryKey: ToValue;
+
+    type Changeset: Changeset;
+
+    fn table_name() -> &'static str;
+
+    fn primary_column() -> Option<&'static str>;
+
+    fn primary_value(&self) -> Value;
+
+    fn insert_values(&self) -> Vec<(&'static str, Value)>;
+
+    fn auto_increment_column() -> Option<&'static str>;
+
+    fn set_auto_increment_value(&mut self, value: Value) -> Result<()>;
+
+    /// Copies the `#[teo(created_at)]` and `#[teo(updated_at)]` values out of the inserted row.
+    fn set_timestamps(&mut self, values: &[(&'static str, Value)]) -> Result<()>;
+
+    fn update_values(&self) -> Vec<(&'static str, Value)>;
+
+    fn column_value(&self, column: &str) -> Option<Value>;
+
+    /// Set instead of deleting the row when the entity is declared with `#[teo(soft_delete)]`.
+    fn soft_delete_column() -> Option<&'static str>;
+
+    /// Set to the current time whenever the row is written when a field is declared with
+    /// `#[teo(updated_at)]`.
+    fn updated_at_column() -> Option<&'static str>;
+
+    fn insert<'a, C>(&'a mut self, connection: &'a mut C) -> Insert<'a, Self, C> where Self: Sized {
+        Insert::new(self, connection)
+    }
+
+    fn insert_many<'a, C>(connection: &'a mut C, entities: &'a [Self]) -> InsertMany<'a, Self, C> where Self: Sized {
+        InsertMany::new(entities, connection)
+    }
+
+    fn upsert_many<'a, C>(connection: &'a mut C, entities: &'a [Self]) -> UpsertMany<'a, Self, C> where Self: Sized {
+        UpsertMany::new(entities, connection)
+    }
+
+    fn find_unique<C>(connection: &mut C, key: Self::PrimaryKey) -> FindUnique<'_, Self, C> where Self: Sized {
+        FindUnique::new(key.to_value(), connection)
+    }
+
+    fn find_many<C>(connection: &mut C) -> FindMany<'_, Self, C> where Self: Sized {
+        FindMany::new(connection)
+    }
+
+    fn update<C>(connection: &mut C) -> UpdateMany<'_, Self, C> where Self: Sized {
+        UpdateMany::new(connection)
+    }
+
+    /// Overwrites every column of the row with the entity's fields, call `changed_from` on the
+    /// result to only write the fields which changed.
+    fn save<'a, C>(&'a self, connection: &'a mut C) -> Save<'a, Self, C> where Self: Sized {
+        Save::new(self, connection)
+    }
+
+    fn count<C>(connection: &mut C) -> Count<'_, Self, C> where Self: Sized {
+        Count::new(connection)
+    }
+
+    fn aggregate<C>(connection: &mut C) -> AggregateMany<'_, Self, C> where Self: Sized {
+        AggregateMany::new(connection)
+    }
+
+    fn delete_many<C>(connection: &mut C) -> DeleteMany<'_, Self, C> where Self: Sized {
+        DeleteMany::new(connection)
+    }
+
+    fn delete<'a, C>(&'a self, connection: &'a mut C) -> DeleteOne<'a, Self, C> where Self: Sized {
+        DeleteOne::new(self, connection)
+    }
+
+    #[cfg(feature = "mongo")]
     fn mongo_table_def() -> TableDef<mongo::ColumnType>;
 
     #[cfg(feature = "mongo")]
+    fn mongo_join_table_defs() -> Vec<TableDef<mongo::ColumnType>>;
+
+    #[cfg(feature = "mysql")]
     fn mysql_table_def() -> TableDef<mysql::ColumnType>;
 
+    #[cfg(feature = "mysql")]
+    fn mysql_join_table_defs() -> Vec<TableDef<mysql::ColumnType>>;
+
     #[cfg(feature = "postgres")]
     fn postgres_table_def() -> TableDef<postgres::ColumnType>;
 
+    #[cfg(feature = "postgres")]
+    fn postgres_join_table_defs() -> Vec<TableDef<postgres::ColumnType>>;
+
     #[cfg(feature = "sqlite")]
     fn sqlite_table_def() -> TableDef<sqlite::ColumnType>;
+
+    #[cfg(feature = "sqlite")]
+    fn sqlite_join_table_defs() -> Vec<TableDef<sqlite::ColumnType>>;
+
+    #[cfg(feature = "duckdb")]
+    fn duckdb_table_def() -> TableDef<duckdb::ColumnType>;
+
+    #[cfg(feature = "duckdb")]
+    fn duckdb_join_table_defs() -> Vec<TableDef<duckdb::ColumnType>>;
 }
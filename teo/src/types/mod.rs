@@ -1,7 +1,15 @@
 mod sort_order;
 mod entity;
 mod schema;
+mod value;
+mod row;
+mod changeset;
+mod relation;
 
 pub use sort_order::SortOrder;
 pub use schema::Schema;
 pub use entity::Entity;
+pub use value::{Value, ToValue, FromValue};
+pub use row::{Row, FromRow};
+pub use changeset::{Change, Changeset};
+pub use relation::Relation;
@@ -0,0 +1,28 @@
+/// Rows of a related entity, only filled in when the query includes the relation.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Relation<T> {
+    #[default]
+    NotLoaded,
+    Loaded(T),
+}
+
+impl<T> Relation<T> {
+
+    pub fn is_loaded(&self) -> bool {
+        matches!(self, Relation::Loaded(_))
+    }
+
+    pub fn get(&self) -> Option<&T> {
+        match self {
+            Relation::Loaded(value) => Some(value),
+            Relation::NotLoaded => None,
+        }
+    }
+
+    pub fn into_inner(self) -> Option<T> {
+        match self {
+            Relation::Loaded(value) => Some(value),
+            Relation::NotLoaded => None,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::error::{Error, Result};
+use super::{FromValue, Value};
+
+pub trait Row {
+
+    /// Returns `None` if the row doesn't have the column.
+    fn value(&self, column: &str) -> Result<Option<Value>>;
+
+    fn get<T>(&self, column: &str) -> Result<T> where T: FromValue {
+        match self.value(column)? {
+            Some(value) => decode(column, value),
+            None => Err(Error::missing_column(column)),
+        }
+    }
+
+    /// Like `get`, but a missing column is read as null.
+    fn get_or_null<T>(&self, column: &str) -> Result<T> where T: FromValue {
+        decode(column, self.value(column)?.unwrap_or(Value::Null))
+    }
+}
+
+pub trait FromRow: Sized {
+
+    fn from_row<R>(row: &R) -> Result<Self> where R: Row + ?Sized;
+}
+
+fn decode<T>(column: &str, value: Value) -> Result<T> where T: FromValue {
+    T::from_value(value).map_err(|error| match error {
+        Error::InvalidValue { expected, found } => Error::InvalidColumnValue { column: column.to_owned(), expected, found },
+        error => error,
+    })
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "duckdb")]
+use teo_column_type::duckdb;
 #[cfg(feature = "mongo")]
 use teo_column_type::mongo;
 #[cfg(feature = "mysql")]
@@ -24,4 +26,7 @@ pub trait Schema: Send {
 
     #[cfg(feature = "sqlite")]
     fn sqlite_table_defs() -> Vec<TableDef<sqlite::ColumnType>>;
+
+    #[cfg(feature = "duckdb")]
+    fn duckdb_table_defs() -> Vec<TableDef<duckdb::ColumnType>>;
 }
@@ -1,13 +1,13 @@
 use std::{convert::Infallible, str::FromStr};
-#[cfg(any(feature = "mongodb", feature = "serde"))]
+#[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, PartialEq)]
-#[cfg_attr(feature = "mongodb", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SortOrder {
-    #[cfg_attr(any(feature = "mongodb", feature = "serde"), serde(rename = "asc"))]
+    #[cfg_attr(feature = "serde", serde(rename = "asc"))]
     Asc,
-    #[cfg_attr(any(feature = "mongodb", feature = "serde"), serde(rename = "desc"))]
+    #[cfg_attr(feature = "serde", serde(rename = "desc"))]
     Desc,
 }
 
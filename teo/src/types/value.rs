@@ -0,0 +1,358 @@
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    #[cfg(feature = "uuid")]
+    Uuid(uuid::Uuid),
+    #[cfg(feature = "chrono")]
+    DateTime(chrono::DateTime<chrono::Utc>),
+    #[cfg(feature = "bigdecimal")]
+    Decimal(bigdecimal::BigDecimal),
+    #[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+    ObjectId(mongodb::bson::oid::ObjectId),
+}
+
+impl Value {
+
+    pub(crate) fn type_name(&self) -> &'static str {
+        match self {
+            Value::Null => "null",
+            Value::Bool(_) => "bool",
+            Value::I32(_) => "i32",
+            Value::I64(_) => "i64",
+            Value::F32(_) => "f32",
+            Value::F64(_) => "f64",
+            Value::String(_) => "string",
+            Value::Bytes(_) => "bytes",
+            #[cfg(feature = "uuid")]
+            Value::Uuid(_) => "uuid",
+            #[cfg(feature = "chrono")]
+            Value::DateTime(_) => "datetime",
+            #[cfg(feature = "bigdecimal")]
+            Value::Decimal(_) => "decimal",
+            #[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+            Value::ObjectId(_) => "object id",
+        }
+    }
+    /// The current time, truncated to the microseconds databases keep.
+    #[cfg(feature = "chrono")]
+    pub fn now() -> Self {
+        use chrono::SubsecRound;
+        Value::DateTime(chrono::Utc::now().trunc_subsecs(6))
+    }
+}
+
+pub trait ToValue {
+
+    /// Whether the value can be `Value::Null`.
+    fn nullable() -> bool where Self: Sized {
+        false
+    }
+
+    fn to_value(&self) -> Value;
+}
+
+pub trait FromValue: Sized {
+
+    fn from_value(value: Value) -> Result<Self>;
+}
+
+impl ToValue for Value {
+
+    fn to_value(&self) -> Value {
+        self.clone()
+    }
+}
+
+impl FromValue for Value {
+
+    fn from_value(value: Value) -> Result<Self> {
+        Ok(value)
+    }
+}
+
+impl<T> ToValue for &T where T: ToValue + ?Sized {
+
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T> ToValue for Option<T> where T: ToValue {
+
+    fn nullable() -> bool {
+        true
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            Some(value) => value.to_value(),
+            None => Value::Null,
+        }
+    }
+}
+
+impl<T> FromValue for Option<T> where T: FromValue {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::Null => Ok(None),
+            value => T::from_value(value).map(Some),
+        }
+    }
+}
+
+impl ToValue for bool {
+
+    fn to_value(&self) -> Value {
+        Value::Bool(*self)
+    }
+}
+
+impl FromValue for bool {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::Bool(value) => Ok(value),
+            Value::I32(value) => Ok(value != 0),
+            Value::I64(value) => Ok(value != 0),
+            value => Err(Error::invalid_value("bool", &value)),
+        }
+    }
+}
+
+impl ToValue for i32 {
+
+    fn to_value(&self) -> Value {
+        Value::I32(*self)
+    }
+}
+
+impl FromValue for i32 {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::I32(value) => Ok(value),
+            Value::I64(i) => i32::try_from(i).map_err(|_| Error::invalid_value("i32", &value)),
+            value => Err(Error::invalid_value("i32", &value)),
+        }
+    }
+}
+
+impl ToValue for i64 {
+
+    fn to_value(&self) -> Value {
+        Value::I64(*self)
+    }
+}
+
+impl FromValue for i64 {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::I32(value) => Ok(value as i64),
+            Value::I64(value) => Ok(value),
+            value => Err(Error::invalid_value("i64", &value)),
+        }
+    }
+}
+
+impl ToValue for f32 {
+
+    fn to_value(&self) -> Value {
+        Value::F32(*self)
+    }
+}
+
+impl FromValue for f32 {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::F32(value) => Ok(value),
+            Value::F64(value) => Ok(value as f32),
+            value => Err(Error::invalid_value("f32", &value)),
+        }
+    }
+}
+
+impl ToValue for f64 {
+
+    fn to_value(&self) -> Value {
+        Value::F64(*self)
+    }
+}
+
+impl FromValue for f64 {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::F32(value) => Ok(value as f64),
+            Value::F64(value) => Ok(value),
+            value => Err(Error::invalid_value("f64", &value)),
+        }
+    }
+}
+
+impl ToValue for String {
+
+    fn to_value(&self) -> Value {
+        Value::String(self.clone())
+    }
+}
+
+impl ToValue for str {
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_owned())
+    }
+}
+
+impl FromValue for String {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::String(value) => Ok(value),
+            value => Err(Error::invalid_value("string", &value)),
+        }
+    }
+}
+
+impl ToValue for Vec<u8> {
+
+    fn to_value(&self) -> Value {
+        Value::Bytes(self.clone())
+    }
+}
+
+impl FromValue for Vec<u8> {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::Bytes(value) => Ok(value),
+            value => Err(Error::invalid_value("bytes", &value)),
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl ToValue for uuid::Uuid {
+
+    fn to_value(&self) -> Value {
+        Value::Uuid(*self)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl FromValue for uuid::Uuid {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::Uuid(value) => Ok(value),
+            Value::String(ref s) => s.parse().map_err(|_| Error::invalid_value("uuid", &value)),
+            Value::Bytes(ref b) => uuid::Uuid::from_slice(b).map_err(|_| Error::invalid_value("uuid", &value)),
+            value => Err(Error::invalid_value("uuid", &value)),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToValue for chrono::DateTime<chrono::Utc> {
+
+    fn to_value(&self) -> Value {
+        Value::DateTime(*self)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromValue for chrono::DateTime<chrono::Utc> {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::DateTime(value) => Ok(value),
+            Value::String(ref s) => chrono::DateTime::parse_from_rfc3339(s).map(|d| d.to_utc()).map_err(|_| Error::invalid_value("datetime", &value)),
+            value => Err(Error::invalid_value("datetime", &value)),
+        }
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl ToValue for bigdecimal::BigDecimal {
+
+    fn to_value(&self) -> Value {
+        Value::Decimal(self.clone())
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl FromValue for bigdecimal::BigDecimal {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::Decimal(value) => Ok(value),
+            Value::I32(value) => Ok(value.into()),
+            Value::I64(value) => Ok(value.into()),
+            Value::F64(value) => value.try_into().map_err(|_| Error::invalid_value("decimal", &Value::F64(value))),
+            Value::String(ref s) => s.parse().map_err(|_| Error::invalid_value("decimal", &value)),
+            value => Err(Error::invalid_value("decimal", &value)),
+        }
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl ToValue for rust_decimal::Decimal {
+
+    #[cfg(feature = "bigdecimal")]
+    fn to_value(&self) -> Value {
+        Value::Decimal(bigdecimal::BigDecimal::new(self.mantissa().into(), self.scale().into()))
+    }
+
+    // Without bigdecimal there's no decimal value, databases convert the text themselves.
+    #[cfg(not(feature = "bigdecimal"))]
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl FromValue for rust_decimal::Decimal {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            #[cfg(feature = "bigdecimal")]
+            Value::Decimal(ref d) => d.to_string().parse().map_err(|_| Error::invalid_value("decimal", &value)),
+            Value::I32(value) => Ok(value.into()),
+            Value::I64(value) => Ok(value.into()),
+            Value::F64(value) => value.try_into().map_err(|_| Error::invalid_value("decimal", &Value::F64(value))),
+            Value::String(ref s) => s.parse().map_err(|_| Error::invalid_value("decimal", &value)),
+            value => Err(Error::invalid_value("decimal", &value)),
+        }
+    }
+}
+
+#[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+impl ToValue for mongodb::bson::oid::ObjectId {
+
+    fn to_value(&self) -> Value {
+        Value::ObjectId(*self)
+    }
+}
+
+#[cfg(any(feature = "mongodb", feature = "mongodb_sync"))]
+impl FromValue for mongodb::bson::oid::ObjectId {
+
+    fn from_value(value: Value) -> Result<Self> {
+        match value {
+            Value::ObjectId(value) => Ok(value),
+            Value::String(ref s) => s.parse().map_err(|_| Error::invalid_value("object id", &value)),
+            value => Err(Error::invalid_value("object id", &value)),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use teo::{Entity, Schema, migration::r#async::migrate};
+use bb8::{Pool, PooledConnection};
+use bb8_postgres::PostgresConnectionManager;
+use tokio_postgres::NoTls;
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+#[tokio::test]
+async fn test_migrate() {
+    let manager = PostgresConnectionManager::new_from_stringlike("host=localhost port=5432 user=postgres", NoTls).unwrap();
+    let pool = Pool::builder().build(manager).await.unwrap();
+    let mut client = pool.get().await.unwrap();
+    migrate::<PooledConnection<'_, PostgresConnectionManager<NoTls>>, Schema>(&mut client).await.unwrap();
+}
@@ -0,0 +1,32 @@
+use teo::{Entity, Schema, migration::r#async::migrate};
+use deadpool_postgres::{Config, Object, Runtime};
+use tokio_postgres::NoTls;
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect() -> Object {
+    let mut config = Config::new();
+    config.host = Some("localhost".to_owned());
+    config.port = Some(5432);
+    config.user = Some("postgres".to_owned());
+    config.dbname = Some("postgres".to_owned());
+    let pool = config.create_pool(Some(Runtime::Tokio1), NoTls).unwrap();
+    pool.get().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_migrate() {
+    let mut client = connect().await;
+    migrate::<Object, Schema>(&mut client).await.unwrap();
+}
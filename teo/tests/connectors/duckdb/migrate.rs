@@ -0,0 +1,30 @@
+use teo::{Entity, Error, Schema, migration::sync::migrate};
+use duckdb::Connection;
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+#[test]
+fn test_migrate() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+}
+
+#[test]
+fn test_migrate_unknown_column_type() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    connection.execute_batch("create table \"User\"(\"id\" integer, \"name\" integer[], \"age\" integer)").unwrap();
+    let error = migrate::<Connection, Schema>(&mut connection).unwrap_err();
+    assert!(matches!(error, Error::UnknownColumnType { table, column, .. } if table == "User" && column == "name"));
+}
@@ -0,0 +1,28 @@
+use teo::{Entity, Schema, migration::r#async::migrate};
+use mysql_async::{self, Conn, Pool};
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    #[teo(mysql(column_type = "json"))]
+    profile: String,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(s: &str) -> Conn {
+    let pool = Pool::new(s);
+    pool.get_conn().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_migrate() {
+    let mut client = connect("mysql://root@localhost:3307/databasename").await;
+    migrate::<Conn, Schema>(&mut client).await.unwrap();
+    migrate::<Conn, Schema>(&mut client).await.unwrap();
+}
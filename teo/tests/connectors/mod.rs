@@ -1,4 +1,16 @@
 mod mongodb;
+mod mongodb_sync;
+mod mysql;
 mod mysql_async;
+mod mariadb;
+mod postgres;
 mod tokio_postgres;
 mod rusqlite;
+mod tokio_rusqlite;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "duckdb")]
+mod duckdb;
+mod deadpool_postgres;
+mod bb8;
+mod r2d2;
@@ -0,0 +1,46 @@
+use teo::{Entity, Schema, migration::r#async::migrate};
+use mongodb::{Database, Client, bson::{Document, doc}};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Member {
+    #[teo(primary)]
+    id: i32,
+    name: String,
+    country: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Member))]
+struct Schema;
+
+async fn connect(c: &str, d: &str) -> Database {
+    let client = Client::with_uri_str(c).await.unwrap();
+    client.database(d)
+}
+
+fn member(id: i32, name: &str, country: &str, age: i32) -> Member {
+    Member { id, name: name.to_owned(), country: country.to_owned(), age }
+}
+
+#[tokio::test]
+async fn test_aggregate() {
+    let mut database = connect("mongodb://127.0.0.1:27017", "teo_aggregate").await;
+    migrate::<Database, Schema>(&mut database).await.unwrap();
+    database.collection::<Document>("Member").delete_many(doc! {}).await.unwrap();
+    let members = [member(1, "ada", "uk", 36), member(2, "alan", "uk", 41), member(3, "grace", "us", 85), member(4, "linus", "fi", 20), member(5, "dennis", "us", 70)];
+    Member::insert_many(&mut database, &members).await.unwrap();
+    assert_eq!(Member::count(&mut database).await.unwrap(), 5);
+    assert_eq!(Member::count(&mut database).filter(Member::age().gt(40)).await.unwrap(), 3);
+    let rows = Member::aggregate(&mut database).sum(Member::age()).avg(Member::age()).min(Member::age()).max(Member::name()).await.unwrap();
+    assert_eq!(rows[0].sum(Member::age()).unwrap(), Some(252));
+    assert_eq!(rows[0].avg(Member::age()).unwrap(), Some(50.4));
+    assert_eq!(rows[0].min(Member::age()).unwrap(), Some(20));
+    assert_eq!(rows[0].max(Member::name()).unwrap(), Some("linus".to_owned()));
+    let rows = Member::aggregate(&mut database).filter(Member::age().gte(30)).group_by([Member::country()]).count().avg(Member::age()).await.unwrap();
+    let groups: Vec<(String, u64, Option<f64>)> = rows.iter().map(|row| (row.group(Member::country()).unwrap(), row.count().unwrap(), row.avg(Member::age()).unwrap())).collect();
+    assert_eq!(groups, vec![("uk".to_owned(), 2, Some(38.5)), ("us".to_owned(), 2, Some(77.5))]);
+    database.collection::<Document>("Member").insert_one(doc! { "id": 6, "name": "ghost", "country": "nl" }).await.unwrap();
+    let rows = Member::aggregate(&mut database).filter(Member::country().eq("nl")).group_by([Member::country()]).sum(Member::age()).await.unwrap();
+    assert_eq!(rows[0].sum(Member::age()).unwrap(), None);
+}
@@ -0,0 +1,36 @@
+use teo::{Entity, Schema, migration::r#async::migrate};
+use mongodb::{Database, Client, bson::{Document, doc}};
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(c: &str, d: &str) -> Database {
+    let client = Client::with_uri_str(c).await.unwrap();
+    client.database(d)
+}
+
+#[tokio::test]
+async fn test_delete() {
+    let mut database = connect("mongodb://127.0.0.1:27017", "teo_delete").await;
+    migrate::<Database, Schema>(&mut database).await.unwrap();
+    database.collection::<Document>("User").delete_many(doc! {}).await.unwrap();
+    let users = [
+        User { id: 1, name: "ada".to_owned(), age: 36 },
+        User { id: 2, name: "alan".to_owned(), age: 41 },
+        User { id: 3, name: "dan".to_owned(), age: 18 },
+    ];
+    User::insert_many(&mut database, &users).await.unwrap();
+    assert_eq!(users[2].delete(&mut database).await.unwrap(), 1);
+    let count = User::delete_many(&mut database).filter(User::age().gt(30)).await.unwrap();
+    assert_eq!(count, 2);
+    assert_eq!(database.collection::<Document>("User").count_documents(doc! {}).await.unwrap(), 0);
+}
@@ -0,0 +1,43 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::SortOrder};
+use mongodb::{Database, Client, bson::{Document, doc}};
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(c: &str, d: &str) -> Database {
+    let client = Client::with_uri_str(c).await.unwrap();
+    client.database(d)
+}
+
+#[tokio::test]
+async fn test_find() {
+    let mut database = connect("mongodb://127.0.0.1:27017", "teo_find").await;
+    migrate::<Database, Schema>(&mut database).await.unwrap();
+    database.collection::<Document>("User").delete_many(doc! {}).await.unwrap();
+    let users = [
+        User { id: 1, name: "ada".to_owned(), age: 36 },
+        User { id: 2, name: "alan".to_owned(), age: 41 },
+        User { id: 3, name: "amy".to_owned(), age: 12 },
+        User { id: 4, name: "dan".to_owned(), age: 18 },
+    ];
+    User::insert_many(&mut database, &users).await.unwrap();
+    let user = User::find_unique(&mut database, 2).await.unwrap();
+    assert_eq!(user, Some(User { id: 2, name: "alan".to_owned(), age: 41 }));
+    let users = User::find_many(&mut database)
+        .filter(User::age().gt(18).and(User::name().like("a%")))
+        .order_by(User::age(), SortOrder::Desc)
+        .limit(20)
+        .await
+        .unwrap();
+    let names: Vec<String> = users.into_iter().map(|u| u.name).collect();
+    assert_eq!(names, vec!["alan", "ada"]);
+}
@@ -0,0 +1,43 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use teo::{Entity, Error, types::FromRow};
+use mongodb::bson::{self, Decimal128, doc};
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct Record {
+    id: i32,
+    name: String,
+    nickname: Option<String>,
+    token: Uuid,
+    created_at: DateTime<Utc>,
+    balance: BigDecimal,
+}
+
+#[test]
+fn test_from_document() {
+    let token = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+    let created_at = DateTime::parse_from_rfc3339("2024-01-02T03:04:05.123Z").unwrap().to_utc();
+    let document = doc! {
+        "id": 1,
+        "name": "Dan",
+        "token": bson::Uuid::from_bytes(*token.as_bytes()),
+        "created_at": bson::DateTime::from_millis(created_at.timestamp_millis()),
+        "balance": "12345.6789".parse::<Decimal128>().unwrap(),
+    };
+    let record = Record::from_row(&document).unwrap();
+    assert_eq!(record.id, 1);
+    assert_eq!(record.name, "Dan");
+    assert_eq!(record.nickname, None);
+    assert_eq!(record.token, token);
+    assert_eq!(record.created_at, created_at);
+    assert_eq!(record.balance, "12345.6789".parse::<BigDecimal>().unwrap());
+}
+
+#[test]
+fn test_from_document_invalid_column_value() {
+    let document = doc! { "id": "1" };
+    let error = Record::from_row(&document).err().unwrap();
+    assert!(matches!(error, Error::InvalidColumnValue { column, expected: "i32", found: "string" } if column == "id"));
+}
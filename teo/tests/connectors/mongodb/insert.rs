@@ -0,0 +1,37 @@
+use teo::{Entity, Schema, migration::r#async::migrate};
+use mongodb::{Database, Client, bson::{Document, doc}};
+
+#[derive(Entity)]
+struct User {
+    #[teo(primary)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(c: &str, d: &str) -> Database {
+    let client = Client::with_uri_str(c).await.unwrap();
+    client.database(d)
+}
+
+#[tokio::test]
+async fn test_insert() {
+    let mut database = connect("mongodb://127.0.0.1:27017", "teo_insert").await;
+    migrate::<Database, Schema>(&mut database).await.unwrap();
+    database.collection::<Document>("User").delete_many(doc! {}).await.unwrap();
+    let mut user = User { id: 1, name: "Dan".to_owned(), age: 18 };
+    user.insert(&mut database).await.unwrap();
+    let users = [
+        User { id: 2, name: "Ada".to_owned(), age: 36 },
+        User { id: 3, name: "Bob".to_owned(), age: 24 },
+    ];
+    let ids = User::insert_many(&mut database, &users).await.unwrap();
+    assert!(ids.is_empty());
+    let document = database.collection::<Document>("User").find_one(doc! { "id": 2 }).await.unwrap().unwrap();
+    assert_eq!(document.get_str("name").unwrap(), "Ada");
+    assert_eq!(database.collection::<Document>("User").count_documents(doc! {}).await.unwrap(), 3);
+}
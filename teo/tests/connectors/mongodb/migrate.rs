@@ -2,6 +2,7 @@ use teo::{Entity, Schema, migration::r#async::migrate};
 use mongodb::{Database, Client};
 
 #[derive(Entity)]
+#[allow(dead_code)]
 struct User {
     #[teo(primary, auto_increment)]
     id: i32,
@@ -15,8 +16,7 @@ struct Schema;
 
 async fn connect(c: &str, d: &str) -> Database {
     let client = Client::with_uri_str(c).await.unwrap();
-    let database = client.database(d);
-    database
+    client.database(d)
 }
 
 #[tokio::test]
@@ -24,3 +24,13 @@ async fn test_migrate() {
     let mut client = connect("mongodb://127.0.0.1:27017", "mydb2").await;
     migrate::<Database, Schema>(&mut client).await.unwrap();
 }
+
+#[tokio::test]
+async fn test_migrate_report() {
+    let mut database = connect("mongodb://127.0.0.1:27017", "teo_report").await;
+    database.drop().await.unwrap();
+    let report = migrate::<Database, Schema>(&mut database).await.unwrap();
+    assert_eq!(report.steps.len(), 1);
+    assert_eq!(report.steps[0].statements, vec!["db.createCollection(\"User\")"]);
+    assert!(migrate::<Database, Schema>(&mut database).await.unwrap().is_empty());
+}
@@ -1 +1,12 @@
 mod migrate;
+mod insert;
+mod from_row;
+mod find;
+mod update;
+mod delete;
+mod upsert;
+mod paginate;
+mod aggregate;
+mod transaction;
+mod relation;
+mod stream;
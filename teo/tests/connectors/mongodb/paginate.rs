@@ -0,0 +1,42 @@
+use teo::{Entity, Schema, migration::r#async::migrate, query::Order, types::SortOrder};
+use mongodb::{Database, Client, bson::{Document, doc}};
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(c: &str, d: &str) -> Database {
+    let client = Client::with_uri_str(c).await.unwrap();
+    client.database(d)
+}
+
+#[tokio::test]
+async fn test_paginate() {
+    let mut database = connect("mongodb://127.0.0.1:27017", "teo_paginate").await;
+    migrate::<Database, Schema>(&mut database).await.unwrap();
+    database.collection::<Document>("User").delete_many(doc! {}).await.unwrap();
+    let users = [
+        User { id: 1, name: "ada".to_owned(), age: 36 },
+        User { id: 2, name: "alan".to_owned(), age: 41 },
+        User { id: 3, name: "amy".to_owned(), age: 12 },
+        User { id: 4, name: "dan".to_owned(), age: 36 },
+    ];
+    User::insert_many(&mut database, &users).await.unwrap();
+    let page = User::find_many(&mut database).order_by(User::id(), SortOrder::Asc).skip(1).take(2).await.unwrap();
+    let ids: Vec<i32> = page.iter().map(|u| u.id).collect();
+    assert_eq!(ids, vec![2, 3]);
+    let order = Order::new().by(User::age(), SortOrder::Desc).by(User::id(), SortOrder::Asc);
+    let first = User::find_many(&mut database).order(order.clone()).take(2).await.unwrap();
+    let cursor = order.cursor(first.last().unwrap()).unwrap();
+    let rest = User::find_many(&mut database).order(order).after(cursor).await.unwrap();
+    let ids: Vec<i32> = first.iter().chain(rest.iter()).map(|u| u.id).collect();
+    assert_eq!(ids, vec![2, 1, 4, 3]);
+}
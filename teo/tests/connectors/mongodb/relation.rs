@@ -0,0 +1,87 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::{Relation, SortOrder}};
+use mongodb::{Database, Client, bson::{Document, doc}};
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Author {
+    #[teo(primary)]
+    id: i32,
+    name: String,
+    #[teo(relation(fields = [id], references = [author_id]))]
+    posts: Relation<Vec<Post>>,
+}
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Post {
+    #[teo(primary)]
+    id: i32,
+    title: String,
+    author_id: i32,
+    #[teo(relation(fields = [author_id], references = [id]))]
+    author: Relation<Author>,
+    #[teo(relation(many_to_many))]
+    tags: Relation<Vec<Tag>>,
+}
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Tag {
+    #[teo(primary)]
+    id: i32,
+    name: String,
+    #[teo(relation(many_to_many))]
+    posts: Relation<Vec<Post>>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Author))]
+#[teo(entity(path = Post))]
+#[teo(entity(path = Tag))]
+struct Schema;
+
+async fn connect(c: &str, d: &str) -> Database {
+    let client = Client::with_uri_str(c).await.unwrap();
+    client.database(d)
+}
+
+fn author(id: i32, name: &str) -> Author {
+    Author { id, name: name.to_owned(), posts: Relation::NotLoaded }
+}
+
+fn post(id: i32, title: &str, author_id: i32) -> Post {
+    Post { id, title: title.to_owned(), author_id, author: Relation::NotLoaded, tags: Relation::NotLoaded }
+}
+
+fn tag(id: i32, name: &str) -> Tag {
+    Tag { id, name: name.to_owned(), posts: Relation::NotLoaded }
+}
+
+#[tokio::test]
+async fn test_relation() {
+    let mut database = connect("mongodb://127.0.0.1:27017", "teo_relation").await;
+    migrate::<Database, Schema>(&mut database).await.unwrap();
+    for collection in ["PostToTag", "Tag", "Post", "Author"] {
+        database.collection::<Document>(collection).delete_many(doc! {}).await.unwrap();
+    }
+    Author::insert_many(&mut database, &[author(1, "ada"), author(2, "alan")]).await.unwrap();
+    Post::insert_many(&mut database, &[post(1, "engines", 1), post(2, "notes", 1), post(3, "machines", 2)]).await.unwrap();
+    Tag::insert_many(&mut database, &[tag(1, "math"), tag(2, "history")]).await.unwrap();
+    let authors = Author::find_many(&mut database).order_by(Author::id(), SortOrder::Asc).include(Author::posts()).await.unwrap();
+    let titles: Vec<Vec<&str>> = authors.iter().map(|a| {
+        let mut titles: Vec<&str> = a.posts.get().unwrap().iter().map(|p| p.title.as_str()).collect();
+        titles.sort();
+        titles
+    }).collect();
+    assert_eq!(titles, vec![vec!["engines", "notes"], vec!["machines"]]);
+    let posts = Post::find_many(&mut database).order_by(Post::id(), SortOrder::Asc).include(Post::author()).await.unwrap();
+    let names: Vec<&str> = posts.iter().map(|p| p.author.get().unwrap().name.as_str()).collect();
+    assert_eq!(names, vec!["ada", "ada", "alan"]);
+    let tags = Tag::find_many(&mut database).order_by(Tag::id(), SortOrder::Asc).await.unwrap();
+    Post::tags().connect(&mut database, &posts[0], &tags[0]).await.unwrap();
+    Post::tags().connect(&mut database, &posts[0], &tags[0]).await.unwrap();
+    Tag::posts().connect(&mut database, &tags[1], &posts[2]).await.unwrap();
+    let loaded = Post::find_many(&mut database).order_by(Post::id(), SortOrder::Asc).include(Post::tags()).await.unwrap();
+    let names: Vec<Vec<&str>> = loaded.iter().map(|p| p.tags.get().unwrap().iter().map(|t| t.name.as_str()).collect()).collect();
+    assert_eq!(names, vec![vec!["math"], vec![], vec!["history"]]);
+    Post::tags().disconnect(&mut database, &posts[0], &tags[0]).await.unwrap();
+    let loaded = Tag::find_unique(&mut database, 1).include(Tag::posts()).await.unwrap().unwrap();
+    assert_eq!(loaded.posts.get().unwrap(), &Vec::<Post>::new());
+}
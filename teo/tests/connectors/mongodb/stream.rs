@@ -0,0 +1,36 @@
+use futures::{StreamExt, TryStreamExt};
+use teo::{Entity, Schema, migration::r#async::migrate, types::SortOrder};
+use mongodb::{Database, Client, bson::{Document, doc}};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Reading {
+    #[teo(primary)]
+    id: i32,
+    value: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Reading))]
+struct Schema;
+
+#[tokio::test]
+async fn test_stream() {
+    let client = Client::with_uri_str("mongodb://127.0.0.1:27017").await.unwrap();
+    let mut database = client.database("teo_stream");
+    migrate::<Database, Schema>(&mut database).await.unwrap();
+    database.collection::<Document>("Reading").delete_many(doc! {}).await.unwrap();
+    let readings: Vec<Reading> = (0..500).map(|value| Reading { id: value + 1, value }).collect();
+    Reading::insert_many(&mut database, &readings).await.unwrap();
+    let readings: Vec<Reading> = Reading::find_many(&mut database).order_by(Reading::id(), SortOrder::Asc).stream().try_collect().await.unwrap();
+    assert_eq!(readings.len(), 500);
+    assert_eq!(readings[499], Reading { id: 500, value: 499 });
+    let values: Vec<i32> = Reading::find_many(&mut database)
+        .filter(Reading::value().gte(100))
+        .order_by(Reading::value(), SortOrder::Desc)
+        .stream()
+        .take(3)
+        .map(|reading| reading.unwrap().value)
+        .collect()
+        .await;
+    assert_eq!(values, vec![499, 498, 497]);
+}
@@ -0,0 +1,39 @@
+use teo::{Entity, Error, Schema, connection::AsyncTransactional, connectors::MongoSession, migration::r#async::migrate};
+use mongodb::{Database, Client, bson::{Document, doc}};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Account {
+    #[teo(primary)]
+    id: i32,
+    owner: String,
+    balance: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Account))]
+struct Schema;
+
+async fn connect(c: &str, d: &str) -> Database {
+    let client = Client::with_uri_str(c).await.unwrap();
+    client.database(d)
+}
+
+#[tokio::test]
+async fn test_transaction() {
+    let mut database = connect("mongodb://127.0.0.1:27017", "teo_transaction").await;
+    migrate::<Database, Schema>(&mut database).await.unwrap();
+    database.collection::<Document>("Account").delete_many(doc! {}).await.unwrap();
+    let mut session = MongoSession::start(&database).await.unwrap();
+    session.transaction(|tx| Box::pin(async move {
+        Account::insert_many(tx, &[Account { id: 1, owner: "ada".to_owned(), balance: 100 }]).await?;
+        let nested: teo::Result<()> = tx.transaction(|_| Box::pin(async move { Ok(()) })).await;
+        assert!(matches!(nested, Err(Error::Unsupported { .. })));
+        Ok(())
+    })).await.unwrap();
+    let result: teo::Result<()> = session.transaction(|tx| Box::pin(async move {
+        Account { id: 2, owner: "alan".to_owned(), balance: 50 }.insert(tx).await?;
+        Err(Error::InvalidCursor)
+    })).await;
+    assert!(result.is_err());
+    assert_eq!(Account::count(&mut database).await.unwrap(), 1);
+}
@@ -0,0 +1,43 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::Change};
+use mongodb::{Database, Client, bson::{Document, doc}};
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(c: &str, d: &str) -> Database {
+    let client = Client::with_uri_str(c).await.unwrap();
+    client.database(d)
+}
+
+#[tokio::test]
+async fn test_update() {
+    let mut database = connect("mongodb://127.0.0.1:27017", "teo_update").await;
+    migrate::<Database, Schema>(&mut database).await.unwrap();
+    database.collection::<Document>("User").delete_many(doc! {}).await.unwrap();
+    let users = [
+        User { id: 1, name: "ada".to_owned(), age: 36 },
+        User { id: 2, name: "alan".to_owned(), age: 41 },
+        User { id: 3, name: "dan".to_owned(), age: 18 },
+    ];
+    User::insert_many(&mut database, &users).await.unwrap();
+    let count = User::update(&mut database)
+        .filter(User::name().like("a%"))
+        .set(UserUpdate { age: Change::Set(50), ..Default::default() })
+        .await
+        .unwrap();
+    assert_eq!(count, 2);
+    let mut dan = User::find_unique(&mut database, 3).await.unwrap().unwrap();
+    dan.age = 19;
+    assert_eq!(dan.save(&mut database).await.unwrap(), 1);
+    let document = database.collection::<Document>("User").find_one(doc! { "id": 3 }).await.unwrap().unwrap();
+    assert_eq!(document.get_i32("age").unwrap(), 19);
+}
@@ -0,0 +1,43 @@
+use teo::{Entity, Schema, migration::r#async::migrate};
+use mongodb::{Database, Client, bson::{Document, doc}};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Subscriber {
+    #[teo(primary)]
+    id: i32,
+    #[teo(unique)]
+    email: String,
+    name: String,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Subscriber))]
+struct Schema;
+
+async fn connect(c: &str, d: &str) -> Database {
+    let client = Client::with_uri_str(c).await.unwrap();
+    client.database(d)
+}
+
+#[tokio::test]
+async fn test_upsert_many() {
+    let mut database = connect("mongodb://127.0.0.1:27017", "teo_upsert").await;
+    migrate::<Database, Schema>(&mut database).await.unwrap();
+    let collection = database.collection::<Document>("Subscriber");
+    collection.delete_many(doc! {}).await.unwrap();
+    let ada = Subscriber { id: 1, email: "ada@example.com".to_owned(), name: "Ada".to_owned() };
+    Subscriber::insert_many(&mut database, &[ada]).await.unwrap();
+    let rows = [
+        Subscriber { id: 1, email: "ada@example.com".to_owned(), name: "Ada Lovelace".to_owned() },
+        Subscriber { id: 2, email: "alan@example.com".to_owned(), name: "Alan".to_owned() },
+    ];
+    let count = Subscriber::upsert_many(&mut database, &rows)
+        .on_conflict(Subscriber::email())
+        .update([Subscriber::name()])
+        .await
+        .unwrap();
+    assert_eq!(count, 2);
+    let document = collection.find_one(doc! { "email": "ada@example.com" }).await.unwrap().unwrap();
+    assert_eq!(document.get_str("name").unwrap(), "Ada Lovelace");
+    assert_eq!(collection.count_documents(doc! {}).await.unwrap(), 2);
+}
@@ -0,0 +1,26 @@
+use teo::{Entity, Schema, migration::sync::migrate};
+use mongodb::sync::{Client, Database};
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+fn connect(c: &str, d: &str) -> Database {
+    let client = Client::with_uri_str(c).unwrap();
+    client.database(d)
+}
+
+#[test]
+fn test_migrate() {
+    let mut client = connect("mongodb://127.0.0.1:27017", "mydb3");
+    migrate::<Database, Schema>(&mut client).unwrap();
+}
@@ -0,0 +1,48 @@
+use teo::{Entity, Schema, migration::sync::migrate};
+use mysql::{Pool, PooledConn, prelude::Queryable};
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+fn connect(s: &str) -> PooledConn {
+    let pool = Pool::new(s).unwrap();
+    pool.get_conn().unwrap()
+}
+
+#[test]
+fn test_migrate() {
+    let mut client = connect("mysql://root@localhost:3306/databasename");
+    migrate::<PooledConn, Schema>(&mut client).unwrap();
+}
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct Counter {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    count: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Counter))]
+struct CounterSchema;
+
+#[test]
+fn test_migrate_auto_increment_column() {
+    let mut client = connect("mysql://root@localhost:3306/databasename");
+    client.query_drop("drop table if exists `Counter`").unwrap();
+    client.query_drop("create table `Counter`(`id` bigint not null auto_increment primary key, `count` int not null)").unwrap();
+    let report = migrate::<PooledConn, CounterSchema>(&mut client).unwrap();
+    assert!(report.steps.iter().any(|step| step.statements.iter().any(|s| s.starts_with("alter table `Counter` modify column `id`"))));
+    client.query_drop("insert into `Counter`(`count`) values (1)").unwrap();
+}
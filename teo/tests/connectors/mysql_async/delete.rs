@@ -0,0 +1,44 @@
+use teo::{Entity, Error, Schema, migration::r#async::migrate};
+use mysql_async::{self, Conn, Pool};
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(s: &str) -> Conn {
+    let pool = Pool::new(s);
+    pool.get_conn().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_delete_many() {
+    let mut conn = connect("mysql://root@localhost:3306/databasename").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    let users = [
+        User { id: 0, name: "delete_many_ada".to_owned(), age: 36 },
+        User { id: 0, name: "delete_many_alan".to_owned(), age: 41 },
+    ];
+    User::insert_many(&mut conn, &users).await.unwrap();
+    let count = User::delete_many(&mut conn).filter(User::name().like("delete\\_many\\_%")).await.unwrap();
+    assert!(count >= 2);
+    let result = User::delete_many(&mut conn).returning().await;
+    assert!(matches!(result, Err(Error::Unsupported { .. })));
+}
+
+#[tokio::test]
+async fn test_delete() {
+    let mut conn = connect("mysql://root@localhost:3306/databasename").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    let mut user = User { id: 0, name: "delete_dan".to_owned(), age: 18 };
+    user.insert(&mut conn).await.unwrap();
+    assert_eq!(user.delete(&mut conn).await.unwrap(), 1);
+    assert_eq!(User::find_unique(&mut conn, user.id).await.unwrap(), None);
+}
@@ -0,0 +1,50 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::SortOrder};
+use mysql_async::{self, Conn, Pool, prelude::Queryable};
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(s: &str) -> Conn {
+    let pool = Pool::new(s);
+    pool.get_conn().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_find_unique() {
+    let mut conn = connect("mysql://root@localhost:3306/databasename").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    let mut user = User { id: 0, name: "find_unique_grace".to_owned(), age: 45 };
+    user.insert(&mut conn).await.unwrap();
+    let found = User::find_unique(&mut conn, user.id).await.unwrap();
+    assert_eq!(found, Some(user));
+}
+
+#[tokio::test]
+async fn test_find_many() {
+    let mut conn = connect("mysql://root@localhost:3306/databasename").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    conn.query_drop("delete from `User` where `name` like 'find\\_many\\_%'").await.unwrap();
+    let users = [
+        User { id: 0, name: "find_many_ada".to_owned(), age: 36 },
+        User { id: 0, name: "find_many_alan".to_owned(), age: 41 },
+        User { id: 0, name: "find_many_amy".to_owned(), age: 12 },
+    ];
+    User::insert_many(&mut conn, &users).await.unwrap();
+    let users = User::find_many(&mut conn)
+        .filter(User::age().gt(18).and(User::name().like("find\\_many\\_a%")))
+        .order_by(User::age(), SortOrder::Desc)
+        .limit(20)
+        .await
+        .unwrap();
+    let names: Vec<String> = users.into_iter().map(|u| u.name).collect();
+    assert_eq!(names, vec!["find_many_alan", "find_many_ada"]);
+}
@@ -0,0 +1,36 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use teo::{Entity, types::FromRow};
+use mysql_async::{self, Conn, Pool, Row, prelude::Queryable};
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct Record {
+    id: i32,
+    name: String,
+    nickname: Option<String>,
+    token: Uuid,
+    created_at: DateTime<Utc>,
+    balance: BigDecimal,
+}
+
+async fn connect(s: &str) -> Conn {
+    let pool = Pool::new(s);
+    pool.get_conn().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_from_row() {
+    let mut conn = connect("mysql://root@localhost:3306/databasename").await;
+    let row: Row = conn.exec_first("select 1 as id, 'Dan' as name, null as nickname, \
+        '67e55044-10b1-426f-9247-bb680e5fe0c8' as token, timestamp('2024-01-02 03:04:05.123456') as created_at, \
+        cast(12345.6789 as decimal(10, 4)) as balance", ()).await.unwrap().unwrap();
+    let record = Record::from_row(&row).unwrap();
+    assert_eq!(record.id, 1);
+    assert_eq!(record.name, "Dan");
+    assert_eq!(record.nickname, None);
+    assert_eq!(record.token, Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8));
+    assert_eq!(record.created_at, DateTime::parse_from_rfc3339("2024-01-02T03:04:05.123456Z").unwrap().to_utc());
+    assert_eq!(record.balance, "12345.6789".parse::<BigDecimal>().unwrap());
+}
@@ -0,0 +1,46 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::Value};
+use mysql_async::{self, Conn, Pool, prelude::Queryable};
+
+#[derive(Entity)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(s: &str) -> Conn {
+    let pool = Pool::new(s);
+    pool.get_conn().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_insert() {
+    let mut conn = connect("mysql://root@localhost:3306/databasename").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    let mut user = User { id: 0, name: "Dan".to_owned(), age: 18 };
+    user.insert(&mut conn).await.unwrap();
+    assert!(user.id > 0);
+    let row: Option<(String, i32)> = conn.exec_first("select `name`, `age` from `User` where `id` = ?", (user.id,)).await.unwrap();
+    assert_eq!(row, Some(("Dan".to_owned(), 18)));
+}
+
+#[tokio::test]
+async fn test_insert_many() {
+    let mut conn = connect("mysql://root@localhost:3306/databasename").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    let users = [
+        User { id: 0, name: "Dan".to_owned(), age: 18 },
+        User { id: 0, name: "Ada".to_owned(), age: 36 },
+    ];
+    let ids = User::insert_many(&mut conn, &users).await.unwrap();
+    assert_eq!(ids.len(), 2);
+    let (Value::I64(first), Value::I64(second)) = (&ids[0], &ids[1]) else {
+        panic!("unexpected keys: {ids:?}");
+    };
+    assert!(first < second);
+}
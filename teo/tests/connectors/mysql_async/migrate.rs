@@ -1,7 +1,8 @@
 use teo::{Entity, Schema, migration::r#async::migrate};
-use mysql_async::{self, Conn, Pool, prelude::Queryable};
+use mysql_async::{self, Conn, Pool};
 
 #[derive(Entity)]
+#[allow(dead_code)]
 struct User {
     #[teo(primary, auto_increment)]
     id: i32,
@@ -15,8 +16,7 @@ struct Schema;
 
 async fn connect(s: &str) -> Conn {
     let pool = Pool::new(s);
-    let conn = pool.get_conn().await.unwrap();
-    conn
+    pool.get_conn().await.unwrap()
 }
 
 #[tokio::test]
@@ -24,3 +24,9 @@ async fn test_migrate() {
     let mut client = connect("mysql://root@localhost:3306/databasename").await;
     migrate::<Conn, Schema>(&mut client).await.unwrap();
 }
+
+#[tokio::test]
+async fn test_migrate_pool() {
+    let mut pool = Pool::new("mysql://root@localhost:3306/databasename");
+    migrate::<Pool, Schema>(&mut pool).await.unwrap();
+}
@@ -0,0 +1,54 @@
+use teo::{Entity, Schema, migration::r#async::migrate, query::Order, types::SortOrder};
+use mysql_async::{self, Conn, Pool, prelude::Queryable};
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(s: &str) -> Conn {
+    let pool = Pool::new(s);
+    pool.get_conn().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_paginate() {
+    let mut conn = connect("mysql://root@localhost:3306/databasename").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    conn.query_drop("delete from `User` where `name` like 'paginate\\_%'").await.unwrap();
+    let users = [
+        User { id: 0, name: "paginate_ada".to_owned(), age: 36 },
+        User { id: 0, name: "paginate_alan".to_owned(), age: 41 },
+        User { id: 0, name: "paginate_amy".to_owned(), age: 12 },
+        User { id: 0, name: "paginate_dan".to_owned(), age: 36 },
+    ];
+    User::insert_many(&mut conn, &users).await.unwrap();
+    let page = User::find_many(&mut conn)
+        .filter(User::name().like("paginate\\_%"))
+        .order_by(User::name(), SortOrder::Asc)
+        .skip(1)
+        .take(2)
+        .await
+        .unwrap();
+    let names: Vec<&str> = page.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, vec!["paginate_alan", "paginate_amy"]);
+    let order = Order::new().by(User::age(), SortOrder::Asc).by(User::id(), SortOrder::Asc);
+    let first = User::find_many(&mut conn).filter(User::name().like("paginate\\_%")).order(order.clone()).take(2).await.unwrap();
+    let cursor = order.cursor(first.last().unwrap()).unwrap();
+    let rest = User::find_many(&mut conn).filter(User::name().like("paginate\\_%")).order(order).after(cursor).await.unwrap();
+    let names: Vec<&str> = first.iter().chain(rest.iter()).map(|u| u.name.as_str()).collect();
+    assert_eq!(names, vec!["paginate_amy", "paginate_ada", "paginate_dan", "paginate_alan"]);
+    let order = Order::new().by(User::age(), SortOrder::Desc).by(User::id(), SortOrder::Asc);
+    let first = User::find_many(&mut conn).filter(User::name().like("paginate\\_%")).order(order.clone()).take(2).await.unwrap();
+    let cursor = order.cursor(first.last().unwrap()).unwrap();
+    let rest = User::find_many(&mut conn).filter(User::name().like("paginate\\_%")).order(order).after(cursor).await.unwrap();
+    let names: Vec<&str> = first.iter().chain(rest.iter()).map(|u| u.name.as_str()).collect();
+    assert_eq!(names, vec!["paginate_alan", "paginate_ada", "paginate_dan", "paginate_amy"]);
+}
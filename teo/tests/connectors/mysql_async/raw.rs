@@ -0,0 +1,29 @@
+use teo::{Entity, Schema, migration::r#async::migrate, raw};
+use mysql_async::{self, Conn, Pool};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Member {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    #[teo(column_name = "display_name")]
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Member))]
+struct Schema;
+
+#[tokio::test]
+async fn test_raw() {
+    let pool = Pool::new("mysql://root@localhost:3306/teo_raw");
+    let mut conn = pool.get_conn().await.unwrap();
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    raw::execute(&mut conn, "truncate `Member`", &[]).await.unwrap();
+    let inserted = raw::execute(&mut conn, "insert into `Member` (`display_name`, `age`) values ($1, $2), ($3, $2)", &[&"ada", &36, &"alan"]).await.unwrap();
+    assert_eq!(inserted, 2);
+    let members = raw::query_as::<Member, _>(&mut conn, "select * from `Member` where `age` = $2 and `display_name` <> $1 order by `id`", &[&"alan", &36]).await.unwrap();
+    assert_eq!(members, vec![Member { id: 1, name: "ada".to_owned(), age: 36 }]);
+    let members = raw::query_as::<Member, _>(&mut conn, "select * from `Member` where `display_name` <> 'a\\'b $1' and `age` = $1 order by `id`", &[&36]).await.unwrap();
+    assert_eq!(members.len(), 2);
+}
@@ -0,0 +1,92 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::{Relation, SortOrder}};
+use mysql_async::{self, Conn, Pool, prelude::Queryable};
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Author {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    #[teo(relation(fields = [id], references = [author_id]))]
+    posts: Relation<Vec<Post>>,
+}
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Post {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    title: String,
+    author_id: i32,
+    #[teo(relation(fields = [author_id], references = [id]))]
+    author: Relation<Author>,
+    #[teo(relation(many_to_many))]
+    tags: Relation<Vec<Tag>>,
+}
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Tag {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    #[teo(relation(many_to_many))]
+    posts: Relation<Vec<Post>>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Author))]
+#[teo(entity(path = Post))]
+#[teo(entity(path = Tag))]
+struct Schema;
+
+async fn connect(s: &str) -> Conn {
+    let pool = Pool::new(s);
+    pool.get_conn().await.unwrap()
+}
+
+fn author(name: &str) -> Author {
+    Author { id: 0, name: name.to_owned(), posts: Relation::NotLoaded }
+}
+
+fn post(title: &str, author_id: i32) -> Post {
+    Post { id: 0, title: title.to_owned(), author_id, author: Relation::NotLoaded, tags: Relation::NotLoaded }
+}
+
+fn tag(name: &str) -> Tag {
+    Tag { id: 0, name: name.to_owned(), posts: Relation::NotLoaded }
+}
+
+#[tokio::test]
+async fn test_relation() {
+    let mut conn = connect("mysql://root@localhost:3306/teo_relation").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    let constraints: Vec<String> = conn.query("select constraint_name from information_schema.referential_constraints where constraint_schema = database() order by constraint_name").await.unwrap();
+    assert_eq!(constraints, vec!["PostToTag_A_fkey", "PostToTag_B_fkey", "Post_author_id_fkey"]);
+    for table in ["PostToTag", "Tag", "Post", "Author"] {
+        conn.query_drop(format!("delete from `{table}`")).await.unwrap();
+        conn.query_drop(format!("alter table `{table}` auto_increment = 1")).await.unwrap();
+    }
+    Author::insert_many(&mut conn, &[author("ada"), author("alan")]).await.unwrap();
+    Post::insert_many(&mut conn, &[post("engines", 1), post("notes", 1), post("machines", 2)]).await.unwrap();
+    Tag::insert_many(&mut conn, &[tag("math"), tag("history")]).await.unwrap();
+    assert!(post("orphan", 3).insert(&mut conn).await.is_err());
+    let authors = Author::find_many(&mut conn).order_by(Author::id(), SortOrder::Asc).include(Author::posts()).await.unwrap();
+    let titles: Vec<Vec<&str>> = authors.iter().map(|a| {
+        let mut titles: Vec<&str> = a.posts.get().unwrap().iter().map(|p| p.title.as_str()).collect();
+        titles.sort();
+        titles
+    }).collect();
+    assert_eq!(titles, vec![vec!["engines", "notes"], vec!["machines"]]);
+    let posts = Post::find_many(&mut conn).order_by(Post::id(), SortOrder::Asc).include(Post::author()).await.unwrap();
+    let names: Vec<&str> = posts.iter().map(|p| p.author.get().unwrap().name.as_str()).collect();
+    assert_eq!(names, vec!["ada", "ada", "alan"]);
+    let tags = Tag::find_many(&mut conn).order_by(Tag::id(), SortOrder::Asc).await.unwrap();
+    Post::tags().connect(&mut conn, &posts[0], &tags[0]).await.unwrap();
+    Post::tags().connect(&mut conn, &posts[0], &tags[0]).await.unwrap();
+    Tag::posts().connect(&mut conn, &tags[1], &posts[2]).await.unwrap();
+    let loaded = Post::find_many(&mut conn).order_by(Post::id(), SortOrder::Asc).include(Post::tags()).await.unwrap();
+    let names: Vec<Vec<&str>> = loaded.iter().map(|p| p.tags.get().unwrap().iter().map(|t| t.name.as_str()).collect()).collect();
+    assert_eq!(names, vec![vec!["math"], vec![], vec!["history"]]);
+    Post::tags().disconnect(&mut conn, &posts[0], &tags[0]).await.unwrap();
+    let loaded = Tag::find_unique(&mut conn, 1).include(Tag::posts()).await.unwrap().unwrap();
+    assert_eq!(loaded.posts.get().unwrap(), &Vec::<Post>::new());
+}
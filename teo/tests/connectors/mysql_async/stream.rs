@@ -0,0 +1,36 @@
+use futures::{StreamExt, TryStreamExt};
+use teo::{Entity, Schema, migration::r#async::migrate, types::SortOrder};
+use mysql_async::{self, Conn, Pool, prelude::Queryable};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Reading {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    value: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Reading))]
+struct Schema;
+
+#[tokio::test]
+async fn test_stream() {
+    let mut pool = Pool::new("mysql://root@localhost:3306/teo_stream");
+    let mut conn = pool.get_conn().await.unwrap();
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    conn.query_drop("truncate `Reading`").await.unwrap();
+    let readings: Vec<Reading> = (0..500).map(|value| Reading { id: 0, value }).collect();
+    Reading::insert_many(&mut conn, &readings).await.unwrap();
+    let readings: Vec<Reading> = Reading::find_many(&mut conn).order_by(Reading::id(), SortOrder::Asc).stream().try_collect().await.unwrap();
+    assert_eq!(readings.len(), 500);
+    assert_eq!(readings[499], Reading { id: 500, value: 499 });
+    let values: Vec<i32> = Reading::find_many(&mut pool)
+        .filter(Reading::value().gte(100))
+        .order_by(Reading::value(), SortOrder::Desc)
+        .stream()
+        .take(3)
+        .map(|reading| reading.unwrap().value)
+        .collect()
+        .await;
+    assert_eq!(values, vec![499, 498, 497]);
+}
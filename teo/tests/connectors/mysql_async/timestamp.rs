@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use teo::{Entity, Schema, migration::r#async::migrate};
+use mysql_async::{self, Conn, Pool, prelude::Queryable};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Article {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    title: String,
+    #[teo(created_at)]
+    created_at: DateTime<Utc>,
+    #[teo(updated_at)]
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Article))]
+struct Schema;
+
+async fn connect(s: &str) -> Conn {
+    let pool = Pool::new(s);
+    pool.get_conn().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_timestamps() {
+    let mut conn = connect("mysql://root@localhost:3306/teo_timestamp").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    assert!(migrate::<Conn, Schema>(&mut conn).await.unwrap().is_empty());
+    let extra: Vec<String> = conn.query("select lower(extra) from information_schema.columns where table_schema = database() and table_name = 'Article' and column_name = 'updated_at'").await.unwrap();
+    assert!(extra[0].ends_with("on update current_timestamp(6)"));
+    let before = Utc::now();
+    let mut article = Article { id: 0, title: "engines".to_owned(), created_at: DateTime::UNIX_EPOCH, updated_at: DateTime::UNIX_EPOCH };
+    article.insert(&mut conn).await.unwrap();
+    assert!(article.created_at >= before);
+    assert_eq!(article.updated_at, article.created_at);
+    let inserted = Article::find_unique(&mut conn, article.id).await.unwrap().unwrap();
+    assert_eq!(inserted, article);
+}
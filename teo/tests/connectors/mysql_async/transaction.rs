@@ -0,0 +1,45 @@
+use teo::{Entity, Error, Schema, connection::{AsyncTransactional, IsolationLevel}, migration::r#async::migrate};
+use mysql_async::{self, Conn, Pool, prelude::Queryable};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Account {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    owner: String,
+    balance: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Account))]
+struct Schema;
+
+async fn connect(s: &str) -> Conn {
+    let pool = Pool::new(s);
+    pool.get_conn().await.unwrap()
+}
+
+fn account(owner: &str, balance: i32) -> Account {
+    Account { id: 0, owner: owner.to_owned(), balance }
+}
+
+#[tokio::test]
+async fn test_transaction() {
+    let mut conn = connect("mysql://root@localhost:3306/teo_transaction").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    conn.query_drop("delete from `Account`").await.unwrap();
+    conn.transaction_with(IsolationLevel::RepeatableRead, |tx| Box::pin(async move {
+        Account::insert_many(tx, &[account("ada", 100), account("alan", 50)]).await?;
+        let nested: teo::Result<()> = tx.transaction(|tx| Box::pin(async move {
+            account("grace", 10).insert(tx).await?;
+            Err(Error::InvalidCursor)
+        })).await;
+        assert!(nested.is_err());
+        Ok(())
+    })).await.unwrap();
+    let result: teo::Result<()> = conn.transaction(|tx| Box::pin(async move {
+        account("linus", 20).insert(tx).await?;
+        Err(Error::InvalidCursor)
+    })).await;
+    assert!(result.is_err());
+    assert_eq!(Account::count(&mut conn).await.unwrap(), 2);
+}
@@ -0,0 +1,49 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::Change};
+use mysql_async::{self, Conn, Pool, prelude::Queryable};
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(s: &str) -> Conn {
+    let pool = Pool::new(s);
+    pool.get_conn().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_update() {
+    let mut conn = connect("mysql://root@localhost:3306/databasename").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    conn.query_drop("delete from `User` where `name` like 'update\\_%'").await.unwrap();
+    let users = [
+        User { id: 0, name: "update_ada".to_owned(), age: 36 },
+        User { id: 0, name: "update_alan".to_owned(), age: 41 },
+    ];
+    User::insert_many(&mut conn, &users).await.unwrap();
+    let count = User::update(&mut conn)
+        .filter(User::name().like("update\\_%"))
+        .set(UserUpdate { age: Change::Set(50), ..Default::default() })
+        .await
+        .unwrap();
+    assert_eq!(count, 2);
+}
+
+#[tokio::test]
+async fn test_save() {
+    let mut conn = connect("mysql://root@localhost:3306/databasename").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    let mut user = User { id: 0, name: "save_dan".to_owned(), age: 18 };
+    user.insert(&mut conn).await.unwrap();
+    user.age = 19;
+    assert_eq!(user.save(&mut conn).await.unwrap(), 1);
+    let found = User::find_unique(&mut conn, user.id).await.unwrap();
+    assert_eq!(found, Some(user));
+}
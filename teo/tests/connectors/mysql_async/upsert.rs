@@ -0,0 +1,61 @@
+use teo::{Entity, Schema, migration::r#async::migrate};
+use mysql_async::{self, Conn, Pool, prelude::Queryable};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Subscriber {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    #[teo(unique)]
+    email: String,
+    name: String,
+}
+
+#[derive(Entity, Debug, PartialEq)]
+struct Signup {
+    #[teo(unique)]
+    email: String,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Subscriber))]
+#[teo(entity(path = Signup))]
+struct Schema;
+
+async fn connect(s: &str) -> Conn {
+    let pool = Pool::new(s);
+    pool.get_conn().await.unwrap()
+}
+
+fn subscriber(email: &str, name: &str) -> Subscriber {
+    Subscriber { id: 0, email: email.to_owned(), name: name.to_owned() }
+}
+
+#[tokio::test]
+async fn test_upsert_many() {
+    let mut conn = connect("mysql://root@localhost:3306/teo_upsert").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    conn.query_drop("delete from `Subscriber`").await.unwrap();
+    Subscriber::insert_many(&mut conn, &[subscriber("ada@example.com", "Ada")]).await.unwrap();
+    let rows = [subscriber("ada@example.com", "Ada Lovelace"), subscriber("alan@example.com", "Alan")];
+    Subscriber::upsert_many(&mut conn, &rows)
+        .on_conflict(Subscriber::email())
+        .update([Subscriber::name()])
+        .await
+        .unwrap();
+    let name: Option<String> = conn.exec_first("select `name` from `Subscriber` where `email` = ?", ("ada@example.com",)).await.unwrap();
+    assert_eq!(name.as_deref(), Some("Ada Lovelace"));
+    let total: Option<i64> = conn.query_first("select count(*) from `Subscriber`").await.unwrap();
+    assert_eq!(total, Some(2));
+}
+
+#[tokio::test]
+async fn test_upsert_many_keeps_duplicates() {
+    let mut conn = connect("mysql://root@localhost:3306/teo_upsert").await;
+    migrate::<Conn, Schema>(&mut conn).await.unwrap();
+    conn.query_drop("delete from `Signup`").await.unwrap();
+    Signup::insert_many(&mut conn, &[Signup { email: "ada@example.com".to_owned() }]).await.unwrap();
+    let rows = [Signup { email: "ada@example.com".to_owned() }, Signup { email: "alan@example.com".to_owned() }];
+    Signup::upsert_many(&mut conn, &rows).await.unwrap();
+    let total: Option<i64> = conn.query_first("select count(*) from `Signup`").await.unwrap();
+    assert_eq!(total, Some(2));
+}
@@ -0,0 +1,25 @@
+use teo::{Entity, Schema, migration::sync::migrate};
+use postgres::{Client, NoTls};
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+fn connect(s: &str) -> Client {
+    Client::connect(s, NoTls).unwrap()
+}
+
+#[test]
+fn test_migrate() {
+    let mut client = connect("host=localhost port=5432 user=postgres");
+    migrate::<Client, Schema>(&mut client).unwrap();
+}
@@ -0,0 +1,33 @@
+use teo::{Entity, Schema, migration::sync::migrate};
+use postgres::NoTls;
+use r2d2::{Pool, PooledConnection};
+use r2d2_postgres::PostgresConnectionManager;
+use r2d2_sqlite::SqliteConnectionManager;
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+#[test]
+fn test_migrate_sqlite() {
+    let pool = Pool::new(SqliteConnectionManager::memory()).unwrap();
+    let mut connection = pool.get().unwrap();
+    migrate::<PooledConnection<SqliteConnectionManager>, Schema>(&mut connection).unwrap();
+}
+
+#[test]
+fn test_migrate_postgres() {
+    let manager = PostgresConnectionManager::new("host=localhost port=5432 user=postgres".parse().unwrap(), NoTls);
+    let pool = Pool::new(manager).unwrap();
+    let mut client = pool.get().unwrap();
+    migrate::<PooledConnection<PostgresConnectionManager<NoTls>>, Schema>(&mut client).unwrap();
+}
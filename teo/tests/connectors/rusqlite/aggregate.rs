@@ -0,0 +1,64 @@
+use teo::{Entity, Schema, migration::sync::migrate};
+use rusqlite::Connection;
+
+#[derive(Entity, Debug, PartialEq)]
+struct Member {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    country: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Member))]
+struct Schema;
+
+fn member(name: &str, country: &str, age: i32) -> Member {
+    Member { id: 0, name: name.to_owned(), country: country.to_owned(), age }
+}
+
+fn seed() -> Connection {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let members = [member("ada", "uk", 36), member("alan", "uk", 41), member("grace", "us", 85), member("linus", "fi", 20), member("dennis", "us", 70)];
+    Member::insert_many(&mut connection, &members).run().unwrap();
+    connection
+}
+
+#[test]
+fn test_count() {
+    let mut connection = seed();
+    assert_eq!(Member::count(&mut connection).run().unwrap(), 5);
+    assert_eq!(Member::count(&mut connection).filter(Member::age().gt(40)).run().unwrap(), 3);
+    assert_eq!(Member::count(&mut connection).filter(Member::country().eq("se")).run().unwrap(), 0);
+}
+
+#[test]
+fn test_aggregate() {
+    let mut connection = seed();
+    let rows = Member::aggregate(&mut connection).count().sum(Member::age()).avg(Member::age()).min(Member::age()).max(Member::name()).run().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].count().unwrap(), 5);
+    assert_eq!(rows[0].sum(Member::age()).unwrap(), Some(252));
+    assert_eq!(rows[0].avg(Member::age()).unwrap(), Some(50.4));
+    assert_eq!(rows[0].min(Member::age()).unwrap(), Some(20));
+    assert_eq!(rows[0].max(Member::name()).unwrap(), Some("linus".to_owned()));
+    let rows = Member::aggregate(&mut connection).filter(Member::age().gt(100)).sum(Member::age()).min(Member::age()).run().unwrap();
+    assert_eq!(rows[0].sum(Member::age()).unwrap(), None);
+    assert_eq!(rows[0].min(Member::age()).unwrap(), None);
+}
+
+#[test]
+fn test_group_by() {
+    let mut connection = seed();
+    let rows = Member::aggregate(&mut connection)
+        .filter(Member::age().gte(30))
+        .group_by([Member::country()])
+        .count()
+        .avg(Member::age())
+        .run()
+        .unwrap();
+    let groups: Vec<(String, u64, Option<f64>)> = rows.iter().map(|row| (row.group(Member::country()).unwrap(), row.count().unwrap(), row.avg(Member::age()).unwrap())).collect();
+    assert_eq!(groups, vec![("uk".to_owned(), 2, Some(38.5)), ("us".to_owned(), 2, Some(77.5))]);
+}
@@ -0,0 +1,27 @@
+use rust_decimal::Decimal;
+use teo::{Entity, Schema, migration::sync::migrate, types::{FromValue, ToValue}};
+use rusqlite::Connection;
+
+#[derive(Entity, Debug, PartialEq)]
+struct Account {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    balance: Decimal,
+    limit: Option<rust_decimal::Decimal>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Account))]
+struct Schema;
+
+#[test]
+fn test_decimal_round_trip() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let balance: Decimal = "12345.6789".parse().unwrap();
+    assert_eq!(Decimal::from_value(balance.to_value()).unwrap(), balance);
+    let mut account = Account { id: 0, balance, limit: Some("-0.05".parse().unwrap()) };
+    account.insert(&mut connection).run().unwrap();
+    let fetched = Account::find_unique(&mut connection, account.id).run().unwrap().unwrap();
+    assert_eq!(fetched, account);
+}
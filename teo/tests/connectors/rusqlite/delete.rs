@@ -0,0 +1,55 @@
+use teo::{Entity, Schema, migration::sync::migrate, types::SortOrder};
+use rusqlite::Connection;
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+fn seed() -> Connection {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let users = [
+        User { id: 0, name: "ada".to_owned(), age: 36 },
+        User { id: 0, name: "alan".to_owned(), age: 41 },
+        User { id: 0, name: "dan".to_owned(), age: 18 },
+    ];
+    User::insert_many(&mut connection, &users).run().unwrap();
+    connection
+}
+
+#[test]
+fn test_delete_many() {
+    let mut connection = seed();
+    let count = User::delete_many(&mut connection).filter(User::age().gt(30)).run().unwrap();
+    assert_eq!(count, 2);
+    let users = User::find_many(&mut connection).run().unwrap();
+    assert_eq!(users, vec![User { id: 3, name: "dan".to_owned(), age: 18 }]);
+}
+
+#[test]
+fn test_delete_many_returning() {
+    let mut connection = seed();
+    let mut users = User::delete_many(&mut connection).filter(User::name().like("a%")).returning().run().unwrap();
+    users.sort_by_key(|u| u.id);
+    let names: Vec<String> = users.into_iter().map(|u| u.name).collect();
+    assert_eq!(names, vec!["ada", "alan"]);
+    let users = User::find_many(&mut connection).order_by(User::id(), SortOrder::Asc).run().unwrap();
+    assert_eq!(users.len(), 1);
+}
+
+#[test]
+fn test_delete() {
+    let mut connection = seed();
+    let alan = User::find_unique(&mut connection, 2).run().unwrap().unwrap();
+    assert_eq!(alan.delete(&mut connection).run().unwrap(), 1);
+    assert_eq!(User::find_unique(&mut connection, 2).run().unwrap(), None);
+    assert_eq!(alan.delete(&mut connection).run().unwrap(), 0);
+}
@@ -0,0 +1,78 @@
+use teo::{Entity, Schema, migration::sync::migrate, types::SortOrder};
+use rusqlite::Connection;
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+    nickname: Option<String>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+fn seed() -> Connection {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let users = [
+        User { id: 0, name: "ada".to_owned(), age: 36, nickname: None },
+        User { id: 0, name: "alan".to_owned(), age: 41, nickname: Some("al".to_owned()) },
+        User { id: 0, name: "amy".to_owned(), age: 12, nickname: None },
+        User { id: 0, name: "dan".to_owned(), age: 18, nickname: None },
+    ];
+    User::insert_many(&mut connection, &users).run().unwrap();
+    connection
+}
+
+#[test]
+fn test_find_unique() {
+    let mut connection = seed();
+    let user = User::find_unique(&mut connection, 2).run().unwrap();
+    assert_eq!(user, Some(User { id: 2, name: "alan".to_owned(), age: 41, nickname: Some("al".to_owned()) }));
+    let user = User::find_unique(&mut connection, 5).run().unwrap();
+    assert_eq!(user, None);
+}
+
+#[test]
+fn test_find_many() {
+    let mut connection = seed();
+    let users = User::find_many(&mut connection)
+        .filter(User::age().gt(18).and(User::name().like("a%")))
+        .order_by(User::age(), SortOrder::Desc)
+        .limit(20)
+        .run()
+        .unwrap();
+    let names: Vec<String> = users.into_iter().map(|u| u.name).collect();
+    assert_eq!(names, vec!["alan", "ada"]);
+}
+
+#[test]
+fn test_find_many_limit() {
+    let mut connection = seed();
+    let users = User::find_many(&mut connection).order_by(User::age(), SortOrder::Asc).limit(2).run().unwrap();
+    let names: Vec<String> = users.into_iter().map(|u| u.name).collect();
+    assert_eq!(names, vec!["amy", "dan"]);
+}
+
+#[test]
+fn test_find_many_operators() {
+    let mut connection = seed();
+    let users = User::find_many(&mut connection).filter(User::nickname().is_not_null()).run().unwrap();
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].name, "alan");
+    let users = User::find_many(&mut connection)
+        .filter(User::id().is_in([1, 3]).or(User::name().eq("dan")))
+        .order_by(User::id(), SortOrder::Asc)
+        .run()
+        .unwrap();
+    let ids: Vec<i32> = users.iter().map(|u| u.id).collect();
+    assert_eq!(ids, vec![1, 3, 4]);
+    let users = User::find_many(&mut connection).filter(!User::name().like("a%")).run().unwrap();
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].name, "dan");
+    let users = User::find_many(&mut connection).filter(User::id().is_in(Vec::<i32>::new())).run().unwrap();
+    assert!(users.is_empty());
+}
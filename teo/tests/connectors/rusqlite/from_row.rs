@@ -0,0 +1,52 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use teo::{Entity, Error, Schema, migration::sync::migrate, types::FromRow};
+use rusqlite::Connection;
+use uuid::Uuid;
+
+#[derive(Entity, Debug)]
+struct Record {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    nickname: Option<String>,
+    token: Uuid,
+    created_at: DateTime<Utc>,
+    balance: BigDecimal,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Record))]
+struct Schema;
+
+#[test]
+fn test_from_row() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let token = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+    let created_at = DateTime::parse_from_rfc3339("2024-01-02T03:04:05.123456Z").unwrap().to_utc();
+    let balance: BigDecimal = "12345.6789".parse().unwrap();
+    let mut record = Record { id: 0, name: "Dan".to_owned(), nickname: None, token, created_at, balance: balance.clone() };
+    record.insert(&mut connection).run().unwrap();
+    let fetched = connection.query_row("select * from \"Record\"", (), |row| Ok(Record::from_row(row))).unwrap().unwrap();
+    assert_eq!(fetched.id, 1);
+    assert_eq!(fetched.name, "Dan");
+    assert_eq!(fetched.nickname, None);
+    assert_eq!(fetched.token, token);
+    assert_eq!(fetched.created_at, created_at);
+    assert_eq!(fetched.balance, balance);
+}
+
+#[test]
+fn test_from_row_invalid_column_value() {
+    let connection = Connection::open_in_memory().unwrap();
+    let error = connection.query_row("select 1 as id, 2 as name", (), |row| Ok(Record::from_row(row))).unwrap().unwrap_err();
+    assert!(matches!(error, Error::InvalidColumnValue { column, expected: "string", found: "i64" } if column == "name"));
+}
+
+#[test]
+fn test_from_row_missing_column() {
+    let connection = Connection::open_in_memory().unwrap();
+    let error = connection.query_row("select 1 as id, 'Dan' as name", (), |row| Ok(Record::from_row(row))).unwrap().unwrap_err();
+    assert!(matches!(error, Error::MissingColumn { column } if column == "token"));
+}
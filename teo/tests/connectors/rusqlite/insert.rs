@@ -0,0 +1,40 @@
+use teo::{Entity, Schema, migration::sync::migrate, types::Value};
+use rusqlite::Connection;
+
+#[derive(Entity)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+#[test]
+fn test_insert() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let mut user = User { id: 0, name: "Dan".to_owned(), age: 18 };
+    user.insert(&mut connection).run().unwrap();
+    assert_eq!(user.id, 1);
+    let (name, age): (String, i32) = connection.query_row("select \"name\", \"age\" from \"User\" where \"id\" = 1", (), |row| Ok((row.get(0)?, row.get(1)?))).unwrap();
+    assert_eq!(name, "Dan");
+    assert_eq!(age, 18);
+}
+
+#[test]
+fn test_insert_many() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let users = [
+        User { id: 0, name: "Dan".to_owned(), age: 18 },
+        User { id: 0, name: "Ada".to_owned(), age: 36 },
+    ];
+    let ids = User::insert_many(&mut connection, &users).run().unwrap();
+    assert_eq!(ids, vec![Value::I64(1), Value::I64(2)]);
+    let count: i64 = connection.query_row("select count(*) from \"User\"", (), |row| row.get(0)).unwrap();
+    assert_eq!(count, 2);
+}
@@ -0,0 +1,165 @@
+use teo::{Entity, Error, Schema, migration::{MigrationObserver, MigrationStep, MigrationStepKind, sync::{migrate, migrate_with_observer}}, types::Relation};
+use rusqlite::Connection;
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+#[derive(Entity)]
+#[teo(table_name = "User")]
+#[allow(dead_code)]
+struct LegacyUser {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    #[teo(unique)]
+    name: String,
+    age: i32,
+    legacy: Option<String>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = LegacyUser))]
+struct LegacySchema;
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct Note {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    user_id: Option<i32>,
+    #[teo(relation(fields = [user_id], references = [id]))]
+    user: Relation<Option<User>>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+#[teo(entity(path = Note))]
+struct NoteSchema;
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct Mention {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    user_name: Option<String>,
+    #[teo(relation(fields = [user_name], references = [name]))]
+    user: Relation<Option<User>>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+#[teo(entity(path = Mention))]
+struct MentionSchema;
+
+#[derive(Default)]
+struct Events(Vec<String>);
+
+impl MigrationObserver for Events {
+
+    fn step_started(&mut self, kind: MigrationStepKind, target: &str) {
+        self.0.push(format!("started {kind} {target}"));
+    }
+
+    fn step_finished(&mut self, step: &MigrationStep, error: Option<&Error>) {
+        self.0.push(format!("finished {} {} {}", step.kind, step.target, error.is_none()));
+    }
+}
+
+struct Additive(Vec<String>);
+
+impl MigrationObserver for Additive {
+
+    fn allow_destructive_change(&mut self, kind: MigrationStepKind, target: &str) -> bool {
+        self.0.push(format!("{kind} {target}"));
+        false
+    }
+}
+
+#[test]
+fn test_migrate() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+}
+
+#[test]
+fn test_migrate_report() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    let report = migrate::<Connection, Schema>(&mut connection).unwrap();
+    assert_eq!(report.steps.len(), 1);
+    assert_eq!(report.steps[0].kind, MigrationStepKind::TableCreated);
+    assert_eq!(report.steps[0].target, "User");
+    assert_eq!(report.steps[0].statements.len(), 1);
+    assert!(report.steps[0].statements[0].starts_with("create table"));
+    assert!(migrate::<Connection, Schema>(&mut connection).unwrap().is_empty());
+}
+
+#[test]
+fn test_migrate_report_changes() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, LegacySchema>(&mut connection).unwrap();
+    let report = migrate::<Connection, Schema>(&mut connection).unwrap();
+    let steps: Vec<(MigrationStepKind, &str)> = report.steps.iter().map(|s| (s.kind, s.target.as_str())).collect();
+    assert_eq!(steps, vec![(MigrationStepKind::ColumnDropped, "User.legacy"), (MigrationStepKind::IndexDropped, "User.User_name_key")]);
+}
+
+#[test]
+fn test_migrate_destructive_change_denied() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, LegacySchema>(&mut connection).unwrap();
+    let mut additive = Additive(Vec::new());
+    let error = migrate_with_observer::<Connection, Schema>(&mut connection, &mut additive).unwrap_err();
+    assert!(matches!(error, Error::DestructiveChangeDenied { table, column: Some(column) } if table == "User" && column == "legacy"));
+    assert_eq!(additive.0, vec!["column dropped User.legacy"]);
+    let legacy: i64 = connection.query_row("select count(*) from pragma_table_info('User') where name = 'legacy'", (), |row| row.get(0)).unwrap();
+    assert_eq!(legacy, 1);
+}
+
+#[test]
+fn test_migrate_observer() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    let mut events = Events::default();
+    migrate_with_observer::<Connection, Schema>(&mut connection, &mut events).unwrap();
+    migrate_with_observer::<Connection, Schema>(&mut connection, &mut events).unwrap();
+    assert_eq!(events.0, vec!["started table created User", "finished table created User true"]);
+}
+
+#[test]
+fn test_migrate_unknown_column_type() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    connection.execute("create table \"User\"(\"id\" integer, \"name\" numeric, \"age\" integer)", ()).unwrap();
+    let error = migrate::<Connection, Schema>(&mut connection).unwrap_err();
+    assert!(matches!(error, Error::UnknownColumnType { table, column, .. } if table == "User" && column == "name"));
+}
+
+#[test]
+fn test_migrate_foreign_key_skipped() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    connection.execute("create table \"Note\"(\"id\" integer not null primary key autoincrement, \"user_id\" integer)", ()).unwrap();
+    let report = migrate::<Connection, NoteSchema>(&mut connection).unwrap();
+    let skipped: Vec<(MigrationStepKind, &str)> = report.skipped.iter().map(|s| (s.kind, s.target.as_str())).collect();
+    assert_eq!(skipped, vec![(MigrationStepKind::ForeignKeyCreated, "Note.Note_user_id_fkey")]);
+    assert!(report.skipped[0].statements.is_empty());
+    let report = migrate::<Connection, NoteSchema>(&mut connection).unwrap();
+    assert!(report.is_empty());
+    assert_eq!(report.skipped.len(), 1);
+}
+
+#[test]
+fn test_migrate_non_unique_reference() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    let error = migrate::<Connection, MentionSchema>(&mut connection).unwrap_err();
+    assert!(matches!(error, Error::NonUniqueReference { table, columns } if table == "User" && columns == ["name"]));
+    let tables: i64 = connection.query_row("select count(*) from sqlite_master where type = 'table'", (), |row| row.get(0)).unwrap();
+    assert_eq!(tables, 0);
+}
@@ -1 +1,18 @@
 mod migrate;
+mod insert;
+mod from_row;
+mod find;
+mod update;
+mod delete;
+mod upsert;
+mod paginate;
+mod aggregate;
+mod transaction;
+mod relation;
+mod raw;
+mod soft_delete;
+mod timestamp;
+#[cfg(feature = "rust_decimal")]
+mod decimal;
+#[cfg(feature = "tracing")]
+mod trace;
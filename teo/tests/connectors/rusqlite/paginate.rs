@@ -0,0 +1,109 @@
+use teo::{Entity, Error, Schema, migration::sync::migrate, query::{Column, Cursor, Order}, types::SortOrder};
+use rusqlite::Connection;
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+fn seed() -> Connection {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let users = [
+        User { id: 0, name: "ada".to_owned(), age: 36 },
+        User { id: 0, name: "alan".to_owned(), age: 41 },
+        User { id: 0, name: "amy".to_owned(), age: 12 },
+        User { id: 0, name: "dan".to_owned(), age: 36 },
+        User { id: 0, name: "eve".to_owned(), age: 20 },
+    ];
+    User::insert_many(&mut connection, &users).run().unwrap();
+    connection
+}
+
+#[test]
+fn test_skip_take() {
+    let mut connection = seed();
+    let users = User::find_many(&mut connection).order_by(User::id(), SortOrder::Asc).skip(1).take(2).run().unwrap();
+    let ids: Vec<i32> = users.iter().map(|u| u.id).collect();
+    assert_eq!(ids, vec![2, 3]);
+    let users = User::find_many(&mut connection).order_by(User::id(), SortOrder::Asc).skip(3).run().unwrap();
+    let ids: Vec<i32> = users.iter().map(|u| u.id).collect();
+    assert_eq!(ids, vec![4, 5]);
+}
+
+#[test]
+fn test_after_cursor() {
+    let mut connection = seed();
+    let order = Order::new().by(User::age(), SortOrder::Asc).by(User::id(), SortOrder::Asc);
+    let mut names = Vec::new();
+    let mut cursor: Option<Cursor> = None;
+    loop {
+        let mut find = User::find_many(&mut connection).order(order.clone()).take(2);
+        if let Some(cursor) = cursor {
+            find = find.after(cursor);
+        }
+        let page = find.run().unwrap();
+        let Some(last) = page.last() else { break };
+        cursor = Some(order.cursor(last).unwrap());
+        names.extend(page.into_iter().map(|u| u.name));
+    }
+    assert_eq!(names, vec!["amy", "eve", "ada", "dan", "alan"]);
+}
+
+#[test]
+fn test_after_mixed_order() {
+    let mut connection = seed();
+    let order = Order::new().by(User::age(), SortOrder::Desc).by(User::id(), SortOrder::Asc);
+    let first = User::find_many(&mut connection).order(order.clone()).take(2).run().unwrap();
+    let names: Vec<&str> = first.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, vec!["alan", "ada"]);
+    let cursor = order.cursor(first.last().unwrap()).unwrap();
+    let rest = User::find_many(&mut connection).order(order).after(cursor).run().unwrap();
+    let names: Vec<&str> = rest.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, vec!["dan", "eve", "amy"]);
+}
+
+#[test]
+fn test_cursor_round_trip() {
+    let mut connection = seed();
+    let order = Order::new().by(User::name(), SortOrder::Asc).by(User::id(), SortOrder::Asc);
+    let user = User::find_unique(&mut connection, 2).run().unwrap().unwrap();
+    let cursor = order.cursor(&user).unwrap();
+    let encoded = cursor.to_string();
+    assert_eq!(encoded.parse::<Cursor>().unwrap(), cursor);
+    assert!(matches!("zz".parse::<Cursor>(), Err(Error::InvalidCursor)));
+    let users = User::find_many(&mut connection).order_by(User::id(), SortOrder::Asc).after(cursor).run();
+    assert!(matches!(users, Err(Error::InvalidCursor)));
+}
+
+#[test]
+fn test_cursor_missing_column() {
+    let mut connection = seed();
+    let order = Order::new().by(Column::<User, i32>::new("rank"), SortOrder::Asc);
+    let user = User::find_unique(&mut connection, 2).run().unwrap().unwrap();
+    assert!(matches!(order.cursor(&user), Err(Error::MissingColumn { column }) if column == "rank"));
+}
+
+#[test]
+fn test_cursor_other_columns() {
+    let mut connection = seed();
+    let user = User::find_unique(&mut connection, 2).run().unwrap().unwrap();
+    let cursor = Order::new().by(User::age(), SortOrder::Asc).by(User::id(), SortOrder::Asc).cursor(&user).unwrap();
+    let users = User::find_many(&mut connection).order_by(User::name(), SortOrder::Asc).order_by(User::id(), SortOrder::Asc).after(cursor).run();
+    assert!(matches!(users, Err(Error::InvalidCursor)));
+}
+
+#[test]
+fn test_cursor_nullable_column() {
+    let mut connection = seed();
+    let order = Order::new().by(Column::<User, Option<i32>>::new("age"), SortOrder::Asc).by(User::id(), SortOrder::Asc);
+    let user = User::find_unique(&mut connection, 2).run().unwrap().unwrap();
+    assert!(matches!(order.cursor(&user), Err(Error::NullableCursorColumn { column }) if column == "age"));
+}
@@ -0,0 +1,34 @@
+use teo::{Entity, Error, Schema, migration::sync::migrate, raw};
+use rusqlite::Connection;
+
+#[derive(Entity, Debug, PartialEq)]
+struct Member {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    #[teo(column_name = "display_name")]
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Member))]
+struct Schema;
+
+#[test]
+fn test_raw() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let inserted = raw::execute(&mut connection, "insert into \"Member\" (\"display_name\", \"age\") values ($1, $2), ($3, $2)", &[&"ada", &36, &"alan"]).run().unwrap();
+    assert_eq!(inserted, 2);
+    let members = raw::query_as::<Member, _>(&mut connection, "select * from \"Member\" where \"age\" = $2 and \"display_name\" <> $1 order by \"id\"", &[&"alan", &36]).run().unwrap();
+    assert_eq!(members, vec![Member { id: 1, name: "ada".to_owned(), age: 36 }]);
+    let members = raw::query_as::<Member, _>(&mut connection, "select * from \"Member\" where \"display_name\" <> '$1' -- $2\n order by \"id\" desc", &[]).run().unwrap();
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0].name, "alan");
+    let error = raw::query_as::<Member, _>(&mut connection, "select * from \"Member\" where \"age\" = $2", &[&36]).run().unwrap_err();
+    assert!(matches!(error, Error::MissingParameter { index: 2 }));
+    let error = raw::query_as::<Member, _>(&mut connection, "select * from \"Member\" where \"age\" = $1 -- $2", &[&36, &"ada"]).run().unwrap_err();
+    assert!(matches!(error, Error::UnusedParameter { index: 2 }));
+    let error = raw::query_as::<Member, _>(&mut connection, "select * from \"Member\" where \"age\" = $1 or \"age\" = $3", &[&36, &41, &18]).run().unwrap_err();
+    assert!(matches!(error, Error::UnusedParameter { index: 2 }));
+}
@@ -0,0 +1,174 @@
+use teo::{Entity, Schema, migration::sync::migrate, types::Relation};
+use rusqlite::{Connection, limits::Limit};
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Author {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    #[teo(relation(fields = [id], references = [author_id]))]
+    posts: Relation<Vec<Post>>,
+}
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Post {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    title: String,
+    author_id: Option<i32>,
+    #[teo(relation(fields = [author_id], references = [id]))]
+    author: Relation<Option<Author>>,
+    #[teo(relation(many_to_many))]
+    tags: Relation<Vec<Tag>>,
+}
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Tag {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    #[teo(relation(many_to_many))]
+    posts: Relation<Vec<Post>>,
+}
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Person {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    #[teo(relation(many_to_many))]
+    followers: Relation<Vec<Person>>,
+    #[teo(relation(many_to_many))]
+    following: Relation<Vec<Person>>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Author))]
+#[teo(entity(path = Post))]
+#[teo(entity(path = Tag))]
+#[teo(entity(path = Person))]
+struct Schema;
+
+fn author(name: &str) -> Author {
+    Author { id: 0, name: name.to_owned(), posts: Relation::NotLoaded }
+}
+
+fn post(title: &str, author_id: Option<i32>) -> Post {
+    Post { id: 0, title: title.to_owned(), author_id, author: Relation::NotLoaded, tags: Relation::NotLoaded }
+}
+
+fn tag(name: &str) -> Tag {
+    Tag { id: 0, name: name.to_owned(), posts: Relation::NotLoaded }
+}
+
+fn person(name: &str) -> Person {
+    Person { id: 0, name: name.to_owned(), followers: Relation::NotLoaded, following: Relation::NotLoaded }
+}
+
+fn seed() -> Connection {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let authors = [author("ada"), author("alan")];
+    Author::insert_many(&mut connection, &authors).run().unwrap();
+    let posts = [post("engines", Some(1)), post("notes", Some(1)), post("machines", Some(2)), post("drafts", None)];
+    Post::insert_many(&mut connection, &posts).run().unwrap();
+    let tags = [tag("math"), tag("history")];
+    Tag::insert_many(&mut connection, &tags).run().unwrap();
+    connection
+}
+
+#[test]
+fn test_migrate_foreign_keys() {
+    let mut connection = seed();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let references: Vec<(String, String, String)> = connection
+        .prepare("select \"from\", \"table\", \"to\" from pragma_foreign_key_list('PostToTag') order by \"from\"").unwrap()
+        .query_map((), |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?))).unwrap()
+        .collect::<rusqlite::Result<_>>().unwrap();
+    assert_eq!(references, vec![
+        ("A".to_owned(), "Post".to_owned(), "id".to_owned()),
+        ("B".to_owned(), "Tag".to_owned(), "id".to_owned()),
+    ]);
+    let references: Vec<(String, String)> = connection
+        .prepare("select \"from\", \"table\" from pragma_foreign_key_list('Post')").unwrap()
+        .query_map((), |row| Ok((row.get(0)?, row.get(1)?))).unwrap()
+        .collect::<rusqlite::Result<_>>().unwrap();
+    assert_eq!(references, vec![("author_id".to_owned(), "Author".to_owned())]);
+}
+
+#[test]
+fn test_include_belongs_to() {
+    let mut connection = seed();
+    let posts = Post::find_many(&mut connection).order_by(Post::id(), teo::types::SortOrder::Asc).include(Post::author()).run().unwrap();
+    let authors: Vec<Option<String>> = posts.iter().map(|p| p.author.get().unwrap().as_ref().map(|a| a.name.clone())).collect();
+    assert_eq!(authors, vec![Some("ada".to_owned()), Some("ada".to_owned()), Some("alan".to_owned()), None]);
+    let post = Post::find_unique(&mut connection, 3).include(Post::author()).run().unwrap().unwrap();
+    assert_eq!(post.author.get().unwrap().as_ref().unwrap().name, "alan");
+    let post = Post::find_unique(&mut connection, 3).run().unwrap().unwrap();
+    assert!(!post.author.is_loaded());
+}
+
+#[test]
+fn test_include_has_many() {
+    let mut connection = seed();
+    let authors = Author::find_many(&mut connection).order_by(Author::id(), teo::types::SortOrder::Asc).include(Author::posts()).run().unwrap();
+    let titles: Vec<Vec<&str>> = authors.iter().map(|a| a.posts.get().unwrap().iter().map(|p| p.title.as_str()).collect()).collect();
+    assert_eq!(titles, vec![vec!["engines", "notes"], vec!["machines"]]);
+}
+
+#[test]
+fn test_include_many_to_many() {
+    let mut connection = seed();
+    let posts = Post::find_many(&mut connection).order_by(Post::id(), teo::types::SortOrder::Asc).run().unwrap();
+    let tags = Tag::find_many(&mut connection).order_by(Tag::id(), teo::types::SortOrder::Asc).run().unwrap();
+    Post::tags().connect(&mut connection, &posts[0], &tags[0]).run().unwrap();
+    Post::tags().connect(&mut connection, &posts[0], &tags[1]).run().unwrap();
+    Post::tags().connect(&mut connection, &posts[0], &tags[1]).run().unwrap();
+    Tag::posts().connect(&mut connection, &tags[0], &posts[2]).run().unwrap();
+    let loaded = Post::find_many(&mut connection).order_by(Post::id(), teo::types::SortOrder::Asc).include(Post::tags()).include(Post::author()).run().unwrap();
+    let names: Vec<Vec<&str>> = loaded.iter().map(|p| {
+        let mut names: Vec<&str> = p.tags.get().unwrap().iter().map(|t| t.name.as_str()).collect();
+        names.sort();
+        names
+    }).collect();
+    assert_eq!(names, vec![vec!["history", "math"], vec![], vec!["math"], vec![]]);
+    assert!(loaded[0].author.is_loaded());
+    Post::tags().disconnect(&mut connection, &posts[0], &tags[0]).run().unwrap();
+    let loaded = Tag::find_many(&mut connection).order_by(Tag::id(), teo::types::SortOrder::Asc).include(Tag::posts()).run().unwrap();
+    let titles: Vec<Vec<&str>> = loaded.iter().map(|t| t.posts.get().unwrap().iter().map(|p| p.title.as_str()).collect()).collect();
+    assert_eq!(titles, vec![vec!["machines"], vec!["engines"]]);
+}
+
+#[test]
+fn test_include_self_many_to_many() {
+    let mut connection = seed();
+    let people = [person("ada"), person("alan"), person("grace")];
+    Person::insert_many(&mut connection, &people).run().unwrap();
+    let people = Person::find_many(&mut connection).order_by(Person::id(), teo::types::SortOrder::Asc).run().unwrap();
+    Person::following().connect(&mut connection, &people[0], &people[1]).run().unwrap();
+    Person::following().connect(&mut connection, &people[2], &people[1]).run().unwrap();
+    Person::followers().connect(&mut connection, &people[0], &people[2]).run().unwrap();
+    let loaded = Person::find_many(&mut connection).order_by(Person::id(), teo::types::SortOrder::Asc).include(Person::followers()).include(Person::following()).run().unwrap();
+    let names = |people: &[Person]| {
+        let mut names: Vec<String> = people.iter().map(|p| p.name.clone()).collect();
+        names.sort();
+        names
+    };
+    let followers: Vec<Vec<String>> = loaded.iter().map(|p| names(p.followers.get().unwrap())).collect();
+    let following: Vec<Vec<String>> = loaded.iter().map(|p| names(p.following.get().unwrap())).collect();
+    assert_eq!(followers, vec![vec!["grace".to_owned()], vec!["ada".to_owned(), "grace".to_owned()], vec![]]);
+    assert_eq!(following, vec![vec!["alan".to_owned()], vec![], vec!["ada".to_owned(), "alan".to_owned()]]);
+}
+
+#[test]
+fn test_include_over_parameter_limit() {
+    let mut connection = seed();
+    let authors: Vec<Author> = (0..150).map(|i| author(&format!("author {i}"))).collect();
+    Author::insert_many(&mut connection, &authors).run().unwrap();
+    connection.set_limit(Limit::SQLITE_LIMIT_VARIABLE_NUMBER, 100).unwrap();
+    let authors = Author::find_many(&mut connection).order_by(Author::id(), teo::types::SortOrder::Asc).include(Author::posts()).run().unwrap();
+    assert_eq!(authors.len(), 152);
+    assert_eq!(authors[0].posts.get().unwrap().len(), 2);
+    assert_eq!(authors[1].posts.get().unwrap().len(), 1);
+    assert!(authors[151].posts.get().unwrap().is_empty());
+}
@@ -0,0 +1,152 @@
+use chrono::{DateTime, Utc};
+use teo::{Entity, Error, Schema, migration::sync::migrate, types::{Relation, SortOrder}};
+use rusqlite::Connection;
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+#[teo(soft_delete(partial_unique))]
+struct Account {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    #[teo(unique)]
+    email: String,
+    #[teo(relation(fields = [id], references = [account_id]))]
+    notes: Relation<Vec<Note>>,
+}
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+#[teo(soft_delete)]
+struct Note {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    #[teo(unique)]
+    title: String,
+    account_id: i32,
+}
+
+#[derive(Entity, Debug, PartialEq)]
+#[teo(soft_delete)]
+struct Draft {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    #[teo(updated_at)]
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Account))]
+#[teo(entity(path = Note))]
+#[teo(entity(path = Draft))]
+struct Schema;
+
+fn account(email: &str) -> Account {
+    Account { id: 0, email: email.to_owned(), notes: Relation::NotLoaded }
+}
+
+fn note(title: &str, account_id: i32) -> Note {
+    Note { id: 0, title: title.to_owned(), account_id }
+}
+
+fn seed() -> Connection {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let accounts = [account("ada@example.com"), account("alan@example.com"), account("grace@example.com")];
+    Account::insert_many(&mut connection, &accounts).run().unwrap();
+    let notes = [note("engines", 1), note("notes", 1), note("machines", 2)];
+    Note::insert_many(&mut connection, &notes).run().unwrap();
+    connection
+}
+
+fn emails(accounts: Vec<Account>) -> Vec<String> {
+    accounts.into_iter().map(|a| a.email).collect()
+}
+
+#[test]
+fn test_migrate_soft_delete() {
+    let mut connection = seed();
+    let deleted_at: Option<String> = connection
+        .query_row("select \"deleted_at\" from \"Account\" where \"id\" = 1", (), |row| row.get(0)).unwrap();
+    assert_eq!(deleted_at, None);
+    let sql: String = connection
+        .query_row("select sql from sqlite_master where name = 'Account_email_key'", (), |row| row.get(0)).unwrap();
+    assert!(sql.ends_with("where \"deleted_at\" IS NULL"));
+    let sql: String = connection
+        .query_row("select sql from sqlite_master where name = 'Note_title_key'", (), |row| row.get(0)).unwrap();
+    assert!(!sql.contains("where"));
+    assert!(migrate::<Connection, Schema>(&mut connection).unwrap().is_empty());
+}
+
+#[test]
+fn test_delete_many() {
+    let mut connection = seed();
+    let count = Account::delete_many(&mut connection).filter(Account::email().like("a%")).run().unwrap();
+    assert_eq!(count, 2);
+    let accounts = Account::find_many(&mut connection).run().unwrap();
+    assert_eq!(emails(accounts), vec!["grace@example.com"]);
+    let accounts = Account::find_many(&mut connection).order_by(Account::id(), SortOrder::Asc).with_deleted().run().unwrap();
+    assert_eq!(emails(accounts), vec!["ada@example.com", "alan@example.com", "grace@example.com"]);
+    let deleted: i64 = connection
+        .query_row("select count(*) from \"Account\" where \"deleted_at\" is not null", (), |row| row.get(0)).unwrap();
+    assert_eq!(deleted, 2);
+    assert_eq!(Account::delete_many(&mut connection).filter(Account::email().like("a%")).run().unwrap(), 0);
+}
+
+#[test]
+fn test_delete_one() {
+    let mut connection = seed();
+    let account = Account::find_unique(&mut connection, 2).run().unwrap().unwrap();
+    assert_eq!(account.delete(&mut connection).run().unwrap(), 1);
+    assert_eq!(account.delete(&mut connection).run().unwrap(), 0);
+    assert_eq!(Account::find_unique(&mut connection, 2).run().unwrap(), None);
+    assert_eq!(Account::find_unique(&mut connection, 2).with_deleted().run().unwrap(), Some(account));
+}
+
+#[test]
+fn test_count_and_aggregate() {
+    let mut connection = seed();
+    Account::delete_many(&mut connection).filter(Account::id().eq(1)).run().unwrap();
+    assert_eq!(Account::count(&mut connection).run().unwrap(), 2);
+    assert_eq!(Account::count(&mut connection).with_deleted().run().unwrap(), 3);
+    let rows = Account::aggregate(&mut connection).min(Account::id()).run().unwrap();
+    assert_eq!(rows[0].min(Account::id()).unwrap(), Some(2));
+    let rows = Account::aggregate(&mut connection).min(Account::id()).with_deleted().run().unwrap();
+    assert_eq!(rows[0].min(Account::id()).unwrap(), Some(1));
+}
+
+#[test]
+fn test_include_excludes_deleted() {
+    let mut connection = seed();
+    Note::delete_many(&mut connection).filter(Note::title().eq("notes")).run().unwrap();
+    let account = Account::find_unique(&mut connection, 1).include(Account::notes()).run().unwrap().unwrap();
+    assert_eq!(account.notes, Relation::Loaded(vec![Note { id: 1, title: "engines".to_owned(), account_id: 1 }]));
+}
+
+#[test]
+fn test_partial_unique() {
+    let mut connection = seed();
+    Account::delete_many(&mut connection).filter(Account::id().eq(1)).run().unwrap();
+    account("ada@example.com").insert(&mut connection).run().unwrap();
+    assert!(account("alan@example.com").insert(&mut connection).run().is_err());
+    Note::delete_many(&mut connection).filter(Note::id().eq(1)).run().unwrap();
+    assert!(note("engines", 2).insert(&mut connection).run().is_err());
+}
+
+#[test]
+fn test_delete_returning_unsupported() {
+    let mut connection = seed();
+    let error = Account::delete_many(&mut connection).returning().run().unwrap_err();
+    assert!(matches!(error, Error::Unsupported { operation: "returning", .. }));
+}
+
+#[test]
+fn test_soft_delete_sets_updated_at() {
+    let mut connection = seed();
+    let mut draft = Draft { id: 0, updated_at: DateTime::UNIX_EPOCH };
+    draft.insert(&mut connection).run().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    assert_eq!(draft.delete(&mut connection).run().unwrap(), 1);
+    let deleted = Draft::find_unique(&mut connection, draft.id).with_deleted().run().unwrap().unwrap();
+    assert!(deleted.updated_at > draft.updated_at);
+    let deleted_at: String = connection
+        .query_row("select \"deleted_at\" from \"Draft\"", (), |row| row.get(0)).unwrap();
+    assert_eq!(deleted_at.parse::<DateTime<Utc>>().unwrap(), deleted.updated_at);
+}
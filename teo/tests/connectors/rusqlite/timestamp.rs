@@ -0,0 +1,96 @@
+use std::{slice, thread::sleep, time::Duration};
+use chrono::{DateTime, Utc};
+use teo::{Entity, Schema, migration::sync::migrate, types::Change};
+use rusqlite::Connection;
+
+#[derive(Entity, Debug, PartialEq)]
+struct Article {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    #[teo(unique)]
+    title: String,
+    #[teo(created_at)]
+    created_at: DateTime<Utc>,
+    #[teo(updated_at)]
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Article))]
+struct Schema;
+
+fn article(title: &str) -> Article {
+    Article { id: 0, title: title.to_owned(), created_at: DateTime::UNIX_EPOCH, updated_at: DateTime::UNIX_EPOCH }
+}
+
+fn seed() -> (Connection, Article) {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let mut article = article("engines");
+    article.insert(&mut connection).run().unwrap();
+    let article = Article::find_unique(&mut connection, article.id).run().unwrap().unwrap();
+    sleep(Duration::from_millis(2));
+    (connection, article)
+}
+
+#[test]
+fn test_migrate_timestamp_defaults() {
+    let (mut connection, _) = seed();
+    assert!(migrate::<Connection, Schema>(&mut connection).unwrap().is_empty());
+    let before = Utc::now() - Duration::from_secs(1);
+    connection.execute("insert into \"Article\" (\"title\") values ('notes')", ()).unwrap();
+    let article = Article::find_unique(&mut connection, 2).run().unwrap().unwrap();
+    assert!(article.created_at > before);
+    assert!(article.updated_at > before);
+}
+
+#[test]
+fn test_insert_sets_timestamps() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let before = Utc::now();
+    let mut article = article("engines");
+    article.insert(&mut connection).run().unwrap();
+    assert!(article.created_at >= before);
+    assert_eq!(article.updated_at, article.created_at);
+    let inserted = Article::find_unique(&mut connection, article.id).run().unwrap().unwrap();
+    assert_eq!(inserted, article);
+}
+
+#[test]
+fn test_update_sets_updated_at() {
+    let (mut connection, article) = seed();
+    Article::update(&mut connection)
+        .filter(Article::id().eq(article.id))
+        .set(ArticleUpdate { title: Change::Set("notes".to_owned()), ..Default::default() })
+        .run()
+        .unwrap();
+    let updated = Article::find_unique(&mut connection, article.id).run().unwrap().unwrap();
+    assert_eq!(updated.created_at, article.created_at);
+    assert!(updated.updated_at > article.updated_at);
+}
+
+#[test]
+fn test_save_sets_updated_at() {
+    let (mut connection, mut article) = seed();
+    let created_at = article.created_at;
+    article.title = "notes".to_owned();
+    article.created_at = DateTime::UNIX_EPOCH;
+    article.save(&mut connection).run().unwrap();
+    let saved = Article::find_unique(&mut connection, article.id).run().unwrap().unwrap();
+    assert_eq!(saved.created_at, created_at);
+    assert!(saved.updated_at > article.updated_at);
+}
+
+#[test]
+fn test_upsert_sets_updated_at() {
+    let (mut connection, article) = seed();
+    Article::upsert_many(&mut connection, slice::from_ref(&article))
+        .on_conflict(Article::title())
+        .update([Article::title()])
+        .run()
+        .unwrap();
+    let upserted = Article::find_unique(&mut connection, article.id).run().unwrap().unwrap();
+    assert_eq!(upserted.created_at, article.created_at);
+    assert!(upserted.updated_at > article.updated_at);
+}
@@ -0,0 +1,121 @@
+use std::sync::{Arc, Mutex, atomic::{AtomicU64, Ordering}};
+use futures::TryStreamExt;
+use teo::{Entity, Schema, connection::SyncTransactional, migration::{r#async, sync::migrate}, trace};
+use rusqlite::Connection;
+use tracing::{Event, Id, Metadata, Subscriber, field::{Field, Visit}, span::{Attributes, Record}};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Member {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Member))]
+struct Schema;
+
+#[derive(Debug, Default, Clone)]
+struct Span {
+    name: &'static str,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Span {
+
+    fn field(&self, name: &str) -> Option<&str> {
+        self.fields.iter().rev().find(|(field, _)| *field == name).map(|(_, value)| value.as_str())
+    }
+}
+
+impl Visit for Span {
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.fields.push((field.name(), value.to_owned()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.fields.push((field.name(), format!("{value:?}")));
+    }
+}
+
+#[derive(Default, Clone)]
+struct Spans {
+    next: Arc<AtomicU64>,
+    spans: Arc<Mutex<Vec<Span>>>,
+}
+
+impl Subscriber for Spans {
+
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+        let mut span = Span { name: attributes.metadata().name(), fields: Vec::new() };
+        attributes.record(&mut span);
+        self.spans.lock().unwrap().push(span);
+        Id::from_u64(self.next.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, id: &Id, values: &Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        values.record(&mut spans[id.into_u64() as usize - 1]);
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn test_trace() {
+    let subscriber = Spans::default();
+    let spans = subscriber.spans.clone();
+    tracing::subscriber::with_default(subscriber, || {
+        let mut connection = Connection::open_in_memory().unwrap();
+        migrate::<Connection, Schema>(&mut connection).unwrap();
+        Member::insert_many(&mut connection, &[Member { id: 0, name: "ada".to_owned() }]).run().unwrap();
+        trace::record_parameters(true);
+        Member::find_many(&mut connection).filter(Member::name().eq("ada".to_owned())).run().unwrap();
+        trace::record_parameters(false);
+        SyncTransactional::transaction(&mut connection, |tx| Member::count(tx).run()).unwrap();
+    });
+    let spans = spans.lock().unwrap();
+    let introspect = spans.iter().find(|span| span.name == "teo.introspect" && span.field("db.operation") == Some("table names")).unwrap();
+    assert_eq!(introspect.field("db.system"), Some("sqlite"));
+    assert!(introspect.field("elapsed_ms").is_some());
+    let statements: Vec<&Span> = spans.iter().filter(|span| span.name == "teo.statement").collect();
+    let create = statements.iter().find(|span| span.field("db.statement").is_some_and(|sql| sql.starts_with("create table"))).unwrap();
+    assert_eq!(create.field("db.sql.table"), Some("Member"));
+    assert!(create.field("elapsed_ms").is_some());
+    let insert = statements.iter().find(|span| span.field("db.statement").is_some_and(|sql| sql.starts_with("insert"))).unwrap();
+    assert_eq!(insert.field("db.params"), Some("[redacted]"));
+    let select = statements.iter().find(|span| span.field("db.statement").is_some_and(|sql| sql.starts_with("select"))).unwrap();
+    assert_eq!(select.field("db.params"), Some("[String(\"ada\")]"));
+    let control: Vec<&str> = statements.iter().filter_map(|span| span.field("db.statement")).filter(|sql| sql.starts_with("begin") || *sql == "commit").collect();
+    assert_eq!(control, vec!["begin", "commit"]);
+}
+
+#[test]
+fn test_trace_stream() {
+    let subscriber = Spans::default();
+    let spans = subscriber.spans.clone();
+    tracing::subscriber::with_default(subscriber, || {
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        runtime.block_on(async {
+            let mut connection = tokio_rusqlite::Connection::open_in_memory().await.unwrap();
+            r#async::migrate::<tokio_rusqlite::Connection, Schema>(&mut connection).await.unwrap();
+            let members: Vec<Member> = Member::find_many(&mut connection).stream().try_collect().await.unwrap();
+            assert!(members.is_empty());
+        });
+    });
+    let spans = spans.lock().unwrap();
+    let select = spans.iter().find(|span| span.name == "teo.statement" && span.field("db.statement").is_some_and(|sql| sql.starts_with("select"))).unwrap();
+    assert_eq!(select.field("db.sql.table"), Some("Member"));
+    assert!(select.field("elapsed_ms").is_some());
+}
@@ -0,0 +1,102 @@
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use teo::{Entity, Error, Schema, connection::{IsolationLevel, SyncExecutor, SyncTransactional}, migration::sync::migrate};
+use rusqlite::Connection;
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+fn connect() -> Connection {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    connection
+}
+
+fn user(name: &str) -> User {
+    User { id: 0, name: name.to_owned() }
+}
+
+#[test]
+fn test_commit() {
+    let mut connection = connect();
+    let id = SyncTransactional::transaction(&mut connection, |tx| {
+        let mut ada = user("ada");
+        ada.insert(tx).run()?;
+        assert_eq!(User::count(tx).run()?, 1);
+        Ok(ada.id)
+    }).unwrap();
+    assert_eq!(id, 1);
+    assert_eq!(User::count(&mut connection).run().unwrap(), 1);
+}
+
+#[test]
+fn test_rollback_on_error() {
+    let mut connection = connect();
+    let result: teo::Result<()> = SyncTransactional::transaction(&mut connection, |tx| {
+        user("ada").insert(tx).run()?;
+        Err(Error::InvalidCursor)
+    });
+    assert!(matches!(result, Err(Error::InvalidCursor)));
+    assert_eq!(User::count(&mut connection).run().unwrap(), 0);
+}
+
+#[test]
+fn test_rollback_on_panic() {
+    let mut connection = connect();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        SyncTransactional::transaction(&mut connection, |tx| -> teo::Result<()> {
+            user("ada").insert(tx).run()?;
+            panic!("interrupted");
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(User::count(&mut connection).run().unwrap(), 0);
+}
+
+#[test]
+fn test_savepoint() {
+    let mut connection = connect();
+    SyncTransactional::transaction(&mut connection, |tx| {
+        user("ada").insert(tx).run()?;
+        let nested: teo::Result<()> = tx.transaction(|tx| {
+            user("alan").insert(tx).run()?;
+            Err(Error::InvalidCursor)
+        });
+        assert!(nested.is_err());
+        tx.transaction(|tx| user("grace").insert(tx).run())?;
+        let nested = tx.transaction_with(IsolationLevel::Serializable, |tx| user("linus").insert(tx).run());
+        assert!(matches!(nested, Err(Error::NestedIsolationLevel)));
+        Ok(())
+    }).unwrap();
+    let users = User::find_many(&mut connection).run().unwrap();
+    let names: Vec<String> = users.into_iter().map(|u| u.name).collect();
+    assert_eq!(names, vec!["ada", "grace"]);
+}
+
+#[test]
+fn test_isolation_level() {
+    let mut connection = connect();
+    SyncTransactional::transaction_with(&mut connection, IsolationLevel::Serializable, |tx| user("ada").insert(tx).run()).unwrap();
+    let result = SyncTransactional::transaction_with(&mut connection, IsolationLevel::ReadCommitted, |tx| user("alan").insert(tx).run());
+    assert!(matches!(result, Err(Error::Unsupported { .. })));
+    assert_eq!(User::count(&mut connection).run().unwrap(), 1);
+}
+
+#[test]
+fn test_rollback_on_failed_commit() {
+    let mut connection = connect();
+    connection.execute_batch("pragma foreign_keys = on; create table parent (id integer primary key); create table child (parent_id integer references parent (id));").unwrap();
+    let result = SyncTransactional::transaction(&mut connection, |tx| {
+        tx.execute_raw("pragma defer_foreign_keys = on", &[])?;
+        tx.execute_raw("insert into child values (1)", &[])
+    });
+    assert!(result.is_err());
+    assert!(connection.is_autocommit());
+}
@@ -0,0 +1,89 @@
+use teo::{Entity, Schema, migration::sync::migrate, types::Change};
+use rusqlite::Connection;
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+    nickname: Option<String>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+fn seed() -> Connection {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let users = [
+        User { id: 0, name: "ada".to_owned(), age: 36, nickname: None },
+        User { id: 0, name: "alan".to_owned(), age: 41, nickname: Some("al".to_owned()) },
+        User { id: 0, name: "dan".to_owned(), age: 18, nickname: None },
+    ];
+    User::insert_many(&mut connection, &users).run().unwrap();
+    connection
+}
+
+#[test]
+fn test_update() {
+    let mut connection = seed();
+    let count = User::update(&mut connection)
+        .filter(User::name().like("a%"))
+        .set(UserUpdate { age: Change::Set(50), ..Default::default() })
+        .run()
+        .unwrap();
+    assert_eq!(count, 2);
+    let users = User::find_many(&mut connection).filter(User::age().eq(50)).run().unwrap();
+    let names: Vec<String> = users.into_iter().map(|u| u.name).collect();
+    assert_eq!(names, vec!["ada", "alan"]);
+    let alan = User::find_unique(&mut connection, 2).run().unwrap().unwrap();
+    assert_eq!(alan.nickname.as_deref(), Some("al"));
+}
+
+#[test]
+fn test_update_to_null() {
+    let mut connection = seed();
+    let count = User::update(&mut connection)
+        .filter(User::id().eq(2))
+        .set(UserUpdate { nickname: None.into(), ..Default::default() })
+        .run()
+        .unwrap();
+    assert_eq!(count, 1);
+    let alan = User::find_unique(&mut connection, 2).run().unwrap().unwrap();
+    assert_eq!(alan.nickname, None);
+}
+
+#[test]
+fn test_update_without_changes() {
+    let mut connection = seed();
+    let count = User::update(&mut connection).set(UserUpdate::default()).run().unwrap();
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_save() {
+    let mut connection = seed();
+    let mut dan = User::find_unique(&mut connection, 3).run().unwrap().unwrap();
+    dan.age = 19;
+    dan.nickname = Some("d".to_owned());
+    assert_eq!(dan.save(&mut connection).run().unwrap(), 1);
+    let found = User::find_unique(&mut connection, 3).run().unwrap();
+    assert_eq!(found, Some(dan));
+    let ghost = User { id: 9, name: "ghost".to_owned(), age: 0, nickname: None };
+    assert_eq!(ghost.save(&mut connection).run().unwrap(), 0);
+}
+
+#[test]
+fn test_save_changed_from() {
+    let mut connection = seed();
+    let loaded = User::find_unique(&mut connection, 3).run().unwrap().unwrap();
+    let mut dan = User::find_unique(&mut connection, 3).run().unwrap().unwrap();
+    dan.age = 19;
+    User::update(&mut connection).filter(User::id().eq(3)).set(UserUpdate { name: Change::Set("daniel".to_owned()), ..Default::default() }).run().unwrap();
+    assert_eq!(dan.save(&mut connection).changed_from(&loaded).run().unwrap(), 1);
+    let found = User::find_unique(&mut connection, 3).run().unwrap().unwrap();
+    assert_eq!((found.name.as_str(), found.age), ("daniel", 19));
+    assert_eq!(dan.save(&mut connection).changed_from(&dan).run().unwrap(), 0);
+}
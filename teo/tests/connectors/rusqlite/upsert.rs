@@ -0,0 +1,87 @@
+use teo::{Entity, Schema, migration::sync::migrate, types::SortOrder};
+use rusqlite::Connection;
+
+#[derive(Entity, Debug, PartialEq)]
+struct Subscriber {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    #[teo(unique)]
+    email: String,
+    name: String,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Subscriber))]
+struct Schema;
+
+fn subscriber(email: &str, name: &str) -> Subscriber {
+    Subscriber { id: 0, email: email.to_owned(), name: name.to_owned() }
+}
+
+#[test]
+fn test_upsert_many() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    Subscriber::insert_many(&mut connection, &[subscriber("ada@example.com", "Ada")]).run().unwrap();
+    let rows = [subscriber("ada@example.com", "Ada Lovelace"), subscriber("alan@example.com", "Alan")];
+    let count = Subscriber::upsert_many(&mut connection, &rows)
+        .on_conflict(Subscriber::email())
+        .update([Subscriber::name()])
+        .run()
+        .unwrap();
+    assert_eq!(count, 2);
+    let subscribers = Subscriber::find_many(&mut connection).order_by(Subscriber::id(), SortOrder::Asc).run().unwrap();
+    let names: Vec<String> = subscribers.into_iter().map(|s| s.name).collect();
+    assert_eq!(names, vec!["Ada Lovelace", "Alan"]);
+}
+
+#[test]
+fn test_unique_index() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let sql: String = connection.query_row("select sql from sqlite_master where name = 'Subscriber_email_key'", (), |row| row.get(0)).unwrap();
+    assert!(sql.to_lowercase().starts_with("create unique index"));
+}
+
+#[test]
+fn test_upsert_many_do_nothing() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    Subscriber::insert_many(&mut connection, &[subscriber("ada@example.com", "Ada")]).run().unwrap();
+    let rows = [subscriber("ada@example.com", "Ada Lovelace")];
+    let count = Subscriber::upsert_many(&mut connection, &rows).on_conflict(Subscriber::email()).run().unwrap();
+    assert_eq!(count, 0);
+    let ada = Subscriber::find_unique(&mut connection, 1).run().unwrap().unwrap();
+    assert_eq!(ada.name, "Ada");
+}
+
+#[test]
+fn test_bulk_beyond_parameter_limit() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    let rows: Vec<Subscriber> = (0..20000).map(|i| subscriber(&format!("{i}@example.com"), "bulk")).collect();
+    let ids = Subscriber::insert_many(&mut connection, &rows).run().unwrap();
+    assert_eq!(ids.len(), 20000);
+    let count = Subscriber::upsert_many(&mut connection, &rows)
+        .on_conflict(Subscriber::email())
+        .update([Subscriber::name()])
+        .run()
+        .unwrap();
+    assert_eq!(count, 20000);
+    let total: i64 = connection.query_row("select count(*) from \"Subscriber\"", (), |row| row.get(0)).unwrap();
+    assert_eq!(total, 20000);
+}
+
+#[test]
+fn test_upsert_many_on_primary_key() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    migrate::<Connection, Schema>(&mut connection).unwrap();
+    Subscriber::insert_many(&mut connection, &[subscriber("ada@example.com", "Ada")]).run().unwrap();
+    let mut ada = Subscriber::find_unique(&mut connection, 1).run().unwrap().unwrap();
+    ada.name = "Ada Lovelace".to_owned();
+    let count = Subscriber::upsert_many(&mut connection, &[ada]).update([Subscriber::name()]).run().unwrap();
+    assert_eq!(count, 1);
+    let subscribers = Subscriber::find_many(&mut connection).run().unwrap();
+    assert_eq!(subscribers, vec![Subscriber { id: 1, email: "ada@example.com".to_owned(), name: "Ada Lovelace".to_owned() }]);
+}
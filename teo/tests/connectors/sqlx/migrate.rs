@@ -0,0 +1,36 @@
+use teo::{Entity, Schema, migration::r#async::migrate};
+use sqlx::{PgPool, SqlitePool, Sqlite, Transaction};
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+#[tokio::test]
+async fn test_migrate_sqlite_pool() {
+    let mut pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    migrate::<SqlitePool, Schema>(&mut pool).await.unwrap();
+    migrate::<SqlitePool, Schema>(&mut pool).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_migrate_sqlite_transaction() {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    let mut transaction = pool.begin().await.unwrap();
+    migrate::<Transaction<'_, Sqlite>, Schema>(&mut transaction).await.unwrap();
+    transaction.commit().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_migrate_postgres_pool() {
+    let mut pool = PgPool::connect("postgres://postgres@localhost:5432/postgres").await.unwrap();
+    migrate::<PgPool, Schema>(&mut pool).await.unwrap();
+}
@@ -0,0 +1,46 @@
+use teo::{Entity, Schema, migration::r#async::migrate};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Member {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    country: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Member))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+fn member(name: &str, country: &str, age: i32) -> Member {
+    Member { id: 0, name: name.to_owned(), country: country.to_owned(), age }
+}
+
+#[tokio::test]
+async fn test_aggregate() {
+    let admin = connect("host=localhost port=5432 user=postgres").await;
+    let _ = admin.execute("create database teo_aggregate", &[]).await;
+    let mut client = connect("host=localhost port=5432 user=postgres dbname=teo_aggregate").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    client.execute("delete from \"Member\"", &[]).await.unwrap();
+    let members = [member("ada", "uk", 36), member("alan", "uk", 41), member("grace", "us", 85), member("linus", "fi", 20), member("dennis", "us", 70)];
+    Member::insert_many(&mut client, &members).await.unwrap();
+    assert_eq!(Member::count(&mut client).await.unwrap(), 5);
+    assert_eq!(Member::count(&mut client).filter(Member::age().gt(40)).await.unwrap(), 3);
+    let rows = Member::aggregate(&mut client).sum(Member::age()).avg(Member::age()).min(Member::age()).max(Member::name()).await.unwrap();
+    assert_eq!(rows[0].sum(Member::age()).unwrap(), Some(252));
+    assert_eq!(rows[0].avg(Member::age()).unwrap(), Some(50.4));
+    assert_eq!(rows[0].min(Member::age()).unwrap(), Some(20));
+    assert_eq!(rows[0].max(Member::name()).unwrap(), Some("linus".to_owned()));
+    let rows = Member::aggregate(&mut client).filter(Member::age().gte(30)).group_by([Member::country()]).count().avg(Member::age()).await.unwrap();
+    let groups: Vec<(String, u64, Option<f64>)> = rows.iter().map(|row| (row.group(Member::country()).unwrap(), row.count().unwrap(), row.avg(Member::age()).unwrap())).collect();
+    assert_eq!(groups, vec![("uk".to_owned(), 2, Some(38.5)), ("us".to_owned(), 2, Some(77.5))]);
+}
@@ -0,0 +1,46 @@
+use teo::{Entity, Schema, migration::r#async::migrate};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+#[tokio::test]
+async fn test_delete_many() {
+    let mut client = connect("host=localhost port=5432 user=postgres").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    let users = [
+        User { id: 0, name: "delete_many_ada".to_owned(), age: 36 },
+        User { id: 0, name: "delete_many_alan".to_owned(), age: 41 },
+    ];
+    User::insert_many(&mut client, &users).await.unwrap();
+    let users = User::delete_many(&mut client).filter(User::name().like("delete\\_many\\_%")).returning().await.unwrap();
+    assert!(users.len() >= 2);
+    assert!(users.iter().all(|u| u.name.starts_with("delete_many_")));
+    let count = User::delete_many(&mut client).filter(User::name().like("delete\\_many\\_%")).await.unwrap();
+    assert_eq!(count, 0);
+}
+
+#[tokio::test]
+async fn test_delete() {
+    let mut client = connect("host=localhost port=5432 user=postgres").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    let mut user = User { id: 0, name: "delete_dan".to_owned(), age: 18 };
+    user.insert(&mut client).await.unwrap();
+    assert_eq!(user.delete(&mut client).await.unwrap(), 1);
+    assert_eq!(User::find_unique(&mut client, user.id).await.unwrap(), None);
+}
@@ -0,0 +1,54 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::SortOrder};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+#[tokio::test]
+async fn test_find_unique() {
+    let mut client = connect("host=localhost port=5432 user=postgres").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    let mut user = User { id: 0, name: "find_unique_grace".to_owned(), age: 45 };
+    user.insert(&mut client).await.unwrap();
+    let found = User::find_unique(&mut client, user.id).await.unwrap();
+    assert_eq!(found, Some(user));
+    let found = User::find_unique(&mut client, -1).await.unwrap();
+    assert_eq!(found, None);
+}
+
+#[tokio::test]
+async fn test_find_many() {
+    let mut client = connect("host=localhost port=5432 user=postgres").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    client.execute("delete from \"User\" where \"name\" like 'find\\_many\\_%'", &[]).await.unwrap();
+    let users = [
+        User { id: 0, name: "find_many_ada".to_owned(), age: 36 },
+        User { id: 0, name: "find_many_alan".to_owned(), age: 41 },
+        User { id: 0, name: "find_many_amy".to_owned(), age: 12 },
+        User { id: 0, name: "find_many_dan".to_owned(), age: 18 },
+    ];
+    User::insert_many(&mut client, &users).await.unwrap();
+    let users = User::find_many(&mut client)
+        .filter(User::age().gt(18).and(User::name().like("find\\_many\\_a%")))
+        .order_by(User::age(), SortOrder::Desc)
+        .limit(20)
+        .await
+        .unwrap();
+    let names: Vec<String> = users.into_iter().map(|u| u.name).collect();
+    assert_eq!(names, vec!["find_many_alan", "find_many_ada"]);
+}
@@ -0,0 +1,56 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use teo::{Entity, Error, types::{FromRow, Value}};
+use tokio_postgres::{self, Client, NoTls};
+use uuid::Uuid;
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct Record {
+    id: i32,
+    name: String,
+    nickname: Option<String>,
+    token: Uuid,
+    created_at: DateTime<Utc>,
+    balance: BigDecimal,
+}
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+#[tokio::test]
+async fn test_from_row() {
+    let client = connect("host=localhost port=5432 user=postgres").await;
+    let row = client.query_one("select 1::int4 as id, 'Dan'::text as name, null::text as nickname, \
+        '67e55044-10b1-426f-9247-bb680e5fe0c8'::uuid as token, '2024-01-02T03:04:05.123456Z'::timestamptz as created_at, \
+        12345.6789::numeric as balance", &[]).await.unwrap();
+    let record = Record::from_row(&row).unwrap();
+    assert_eq!(record.id, 1);
+    assert_eq!(record.name, "Dan");
+    assert_eq!(record.nickname, None);
+    assert_eq!(record.token, Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8));
+    assert_eq!(record.created_at, DateTime::parse_from_rfc3339("2024-01-02T03:04:05.123456Z").unwrap().to_utc());
+    assert_eq!(record.balance, "12345.6789".parse::<BigDecimal>().unwrap());
+}
+
+#[tokio::test]
+async fn test_decimal_round_trip() {
+    let client = connect("host=localhost port=5432 user=postgres").await;
+    for decimal in ["0", "-0.00120", "100000000", "12345.6789", "-98765432109876543210.0123456789"] {
+        let value = Value::Decimal(decimal.parse().unwrap());
+        let row = client.query_one("select $1::numeric as balance, $1::numeric::text as text", &[&value]).await.unwrap();
+        assert_eq!(row.get::<_, Value>(0), value);
+        assert_eq!(row.get::<_, String>(1), decimal);
+    }
+}
+
+#[tokio::test]
+async fn test_from_row_invalid_column_value() {
+    let client = connect("host=localhost port=5432 user=postgres").await;
+    let row = client.query_one("select 1::int4 as id, 2::int4 as name", &[]).await.unwrap();
+    let error = Record::from_row(&row).err().unwrap();
+    assert!(matches!(error, Error::InvalidColumnValue { column, expected: "string", found: "i32" } if column == "name"));
+}
@@ -0,0 +1,48 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::Value};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+#[tokio::test]
+async fn test_insert() {
+    let mut client = connect("host=localhost port=5432 user=postgres").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    let mut user = User { id: 0, name: "Dan".to_owned(), age: 18 };
+    user.insert(&mut client).await.unwrap();
+    assert!(user.id > 0);
+    let row = client.query_one("select \"name\", \"age\" from \"User\" where \"id\" = $1", &[&user.id]).await.unwrap();
+    assert_eq!(row.get::<_, String>(0), "Dan");
+    assert_eq!(row.get::<_, i32>(1), 18);
+}
+
+#[tokio::test]
+async fn test_insert_many() {
+    let mut client = connect("host=localhost port=5432 user=postgres").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    let users = [
+        User { id: 0, name: "Dan".to_owned(), age: 18 },
+        User { id: 0, name: "Ada".to_owned(), age: 36 },
+    ];
+    let ids = User::insert_many(&mut client, &users).await.unwrap();
+    assert_eq!(ids.len(), 2);
+    let (Value::I32(first), Value::I32(second)) = (&ids[0], &ids[1]) else {
+        panic!("unexpected keys: {ids:?}");
+    };
+    assert!(first < second);
+}
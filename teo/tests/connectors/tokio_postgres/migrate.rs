@@ -1,7 +1,8 @@
-use teo::{Entity, Schema, migration::r#async::migrate};
+use teo::{Entity, Schema, migration::{MigrationStepKind, r#async::migrate}};
 use tokio_postgres::{self, Client, NoTls};
 
 #[derive(Entity)]
+#[allow(dead_code)]
 struct User {
     #[teo(primary, auto_increment)]
     id: i32,
@@ -13,6 +14,20 @@ struct User {
 #[teo(entity(path = User))]
 struct Schema;
 
+#[derive(Entity)]
+#[teo(table_name = "User")]
+#[allow(dead_code)]
+struct LegacyUser {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: Option<String>,
+    legacy: String,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = LegacyUser))]
+struct LegacySchema;
+
 async fn connect(s: &str) -> Client {
     let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
     tokio::spawn(connection);
@@ -24,3 +39,20 @@ async fn test_migrate() {
     let mut client = connect("host=localhost port=5432 user=postgres").await;
     migrate::<Client, Schema>(&mut client).await.unwrap();
 }
+
+#[tokio::test]
+async fn test_migrate_report() {
+    let admin = connect("host=localhost port=5432 user=postgres").await;
+    let _ = admin.execute("drop database teo_report", &[]).await;
+    admin.execute("create database teo_report", &[]).await.unwrap();
+    let mut client = connect("host=localhost port=5432 user=postgres dbname=teo_report").await;
+    migrate::<Client, LegacySchema>(&mut client).await.unwrap();
+    let report = migrate::<Client, Schema>(&mut client).await.unwrap();
+    let steps: Vec<(MigrationStepKind, &str)> = report.steps.iter().map(|s| (s.kind, s.target.as_str())).collect();
+    assert_eq!(steps, vec![
+        (MigrationStepKind::ColumnDropped, "User.legacy"),
+        (MigrationStepKind::ColumnAdded, "User.age"),
+        (MigrationStepKind::ColumnAltered, "User.name"),
+    ]);
+    assert!(migrate::<Client, Schema>(&mut client).await.unwrap().is_empty());
+}
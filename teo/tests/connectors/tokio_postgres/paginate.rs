@@ -0,0 +1,55 @@
+use teo::{Entity, Schema, migration::r#async::migrate, query::Order, types::SortOrder};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+#[tokio::test]
+async fn test_paginate() {
+    let mut client = connect("host=localhost port=5432 user=postgres").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    client.execute("delete from \"User\" where \"name\" like 'paginate\\_%'", &[]).await.unwrap();
+    let users = [
+        User { id: 0, name: "paginate_ada".to_owned(), age: 36 },
+        User { id: 0, name: "paginate_alan".to_owned(), age: 41 },
+        User { id: 0, name: "paginate_amy".to_owned(), age: 12 },
+        User { id: 0, name: "paginate_dan".to_owned(), age: 36 },
+    ];
+    User::insert_many(&mut client, &users).await.unwrap();
+    let page = User::find_many(&mut client)
+        .filter(User::name().like("paginate\\_%"))
+        .order_by(User::name(), SortOrder::Asc)
+        .skip(1)
+        .take(2)
+        .await
+        .unwrap();
+    let names: Vec<&str> = page.iter().map(|u| u.name.as_str()).collect();
+    assert_eq!(names, vec!["paginate_alan", "paginate_amy"]);
+    let order = Order::new().by(User::age(), SortOrder::Asc).by(User::id(), SortOrder::Asc);
+    let first = User::find_many(&mut client).filter(User::name().like("paginate\\_%")).order(order.clone()).take(2).await.unwrap();
+    let cursor = order.cursor(first.last().unwrap()).unwrap();
+    let rest = User::find_many(&mut client).filter(User::name().like("paginate\\_%")).order(order).after(cursor).await.unwrap();
+    let names: Vec<&str> = first.iter().chain(rest.iter()).map(|u| u.name.as_str()).collect();
+    assert_eq!(names, vec!["paginate_amy", "paginate_ada", "paginate_dan", "paginate_alan"]);
+    let order = Order::new().by(User::age(), SortOrder::Desc).by(User::id(), SortOrder::Asc);
+    let first = User::find_many(&mut client).filter(User::name().like("paginate\\_%")).order(order.clone()).take(2).await.unwrap();
+    let cursor = order.cursor(first.last().unwrap()).unwrap();
+    let rest = User::find_many(&mut client).filter(User::name().like("paginate\\_%")).order(order).after(cursor).await.unwrap();
+    let names: Vec<&str> = first.iter().chain(rest.iter()).map(|u| u.name.as_str()).collect();
+    assert_eq!(names, vec!["paginate_alan", "paginate_ada", "paginate_dan", "paginate_amy"]);
+}
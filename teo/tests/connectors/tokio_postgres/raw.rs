@@ -0,0 +1,42 @@
+use teo::{Entity, Schema, connection::AsyncTransactional, migration::r#async::migrate, raw};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Member {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    #[teo(column_name = "display_name")]
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Member))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+#[tokio::test]
+async fn test_raw() {
+    let admin = connect("host=localhost port=5432 user=postgres").await;
+    let _ = admin.execute("create database teo_raw", &[]).await;
+    let mut client = connect("host=localhost port=5432 user=postgres dbname=teo_raw").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    raw::execute(&mut client, "truncate \"Member\" restart identity", &[]).await.unwrap();
+    let inserted = raw::execute(&mut client, "insert into \"Member\" (\"display_name\", \"age\") values ($1, $2), ($3, $2)", &[&"ada", &36, &"alan"]).await.unwrap();
+    assert_eq!(inserted, 2);
+    let members = raw::query_as::<Member, _>(&mut client, "select * from \"Member\" where \"age\" = $2 and \"display_name\" <> $1 order by \"id\"", &[&"alan", &36]).await.unwrap();
+    assert_eq!(members, vec![Member { id: 1, name: "ada".to_owned(), age: 36 }]);
+    let members = raw::query_as::<Member, _>(&mut client, "select * from \"Member\" where \"display_name\" <> $q$ $1 $q$ and \"age\" = $1", &[&36]).await.unwrap();
+    assert_eq!(members.len(), 2);
+    let members: Vec<Member> = AsyncTransactional::transaction(&mut client, |transaction| Box::pin(async move {
+        raw::execute(&mut *transaction, "update \"Member\" set \"age\" = \"age\" + $1", &[&1]).await?;
+        raw::query_as(transaction, "select * from \"Member\" where \"display_name\" like $1 || '%' order by \"id\"", &[&"a"]).await
+    })).await.unwrap();
+    let ages: Vec<i32> = members.iter().map(|member| member.age).collect();
+    assert_eq!(ages, vec![37, 37]);
+}
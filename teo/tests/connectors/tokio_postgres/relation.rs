@@ -0,0 +1,92 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::{Relation, SortOrder}};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Author {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    #[teo(relation(fields = [id], references = [author_id]))]
+    posts: Relation<Vec<Post>>,
+}
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Post {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    title: String,
+    author_id: i32,
+    #[teo(relation(fields = [author_id], references = [id]))]
+    author: Relation<Author>,
+    #[teo(relation(many_to_many))]
+    tags: Relation<Vec<Tag>>,
+}
+
+#[derive(Entity, Debug, Clone, PartialEq)]
+struct Tag {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    #[teo(relation(many_to_many))]
+    posts: Relation<Vec<Post>>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Author))]
+#[teo(entity(path = Post))]
+#[teo(entity(path = Tag))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+fn author(name: &str) -> Author {
+    Author { id: 0, name: name.to_owned(), posts: Relation::NotLoaded }
+}
+
+fn post(title: &str, author_id: i32) -> Post {
+    Post { id: 0, title: title.to_owned(), author_id, author: Relation::NotLoaded, tags: Relation::NotLoaded }
+}
+
+fn tag(name: &str) -> Tag {
+    Tag { id: 0, name: name.to_owned(), posts: Relation::NotLoaded }
+}
+
+#[tokio::test]
+async fn test_relation() {
+    let admin = connect("host=localhost port=5432 user=postgres").await;
+    let _ = admin.execute("drop database teo_relation", &[]).await;
+    admin.execute("create database teo_relation", &[]).await.unwrap();
+    let mut client = connect("host=localhost port=5432 user=postgres dbname=teo_relation").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    let constraints: Vec<String> = client.query("select conname::text from pg_constraint where contype = 'f' order by conname", &[]).await.unwrap().iter().map(|row| row.get(0)).collect();
+    assert_eq!(constraints, vec!["PostToTag_A_fkey", "PostToTag_B_fkey", "Post_author_id_fkey"]);
+    Author::insert_many(&mut client, &[author("ada"), author("alan")]).await.unwrap();
+    Post::insert_many(&mut client, &[post("engines", 1), post("notes", 1), post("machines", 2)]).await.unwrap();
+    Tag::insert_many(&mut client, &[tag("math"), tag("history")]).await.unwrap();
+    assert!(post("orphan", 3).insert(&mut client).await.is_err());
+    let authors = Author::find_many(&mut client).order_by(Author::id(), SortOrder::Asc).include(Author::posts()).await.unwrap();
+    let titles: Vec<Vec<&str>> = authors.iter().map(|a| {
+        let mut titles: Vec<&str> = a.posts.get().unwrap().iter().map(|p| p.title.as_str()).collect();
+        titles.sort();
+        titles
+    }).collect();
+    assert_eq!(titles, vec![vec!["engines", "notes"], vec!["machines"]]);
+    let posts = Post::find_many(&mut client).order_by(Post::id(), SortOrder::Asc).include(Post::author()).await.unwrap();
+    let names: Vec<&str> = posts.iter().map(|p| p.author.get().unwrap().name.as_str()).collect();
+    assert_eq!(names, vec!["ada", "ada", "alan"]);
+    let tags = Tag::find_many(&mut client).order_by(Tag::id(), SortOrder::Asc).await.unwrap();
+    Post::tags().connect(&mut client, &posts[0], &tags[0]).await.unwrap();
+    Post::tags().connect(&mut client, &posts[0], &tags[0]).await.unwrap();
+    Tag::posts().connect(&mut client, &tags[1], &posts[2]).await.unwrap();
+    let loaded = Post::find_many(&mut client).order_by(Post::id(), SortOrder::Asc).include(Post::tags()).await.unwrap();
+    let names: Vec<Vec<&str>> = loaded.iter().map(|p| p.tags.get().unwrap().iter().map(|t| t.name.as_str()).collect()).collect();
+    assert_eq!(names, vec![vec!["math"], vec![], vec!["history"]]);
+    Post::tags().disconnect(&mut client, &posts[0], &tags[0]).await.unwrap();
+    let loaded = Tag::find_unique(&mut client, 1).include(Tag::posts()).await.unwrap().unwrap();
+    assert_eq!(loaded.posts.get().unwrap(), &Vec::<Post>::new());
+}
@@ -0,0 +1,54 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::SortOrder};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity, Debug, PartialEq)]
+#[teo(soft_delete(partial_unique))]
+struct Account {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    #[teo(unique)]
+    email: String,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Account))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+fn account(email: &str) -> Account {
+    Account { id: 0, email: email.to_owned() }
+}
+
+#[tokio::test]
+async fn test_soft_delete() {
+    let admin = connect("host=localhost port=5432 user=postgres").await;
+    let _ = admin.execute("drop database teo_soft_delete", &[]).await;
+    admin.execute("create database teo_soft_delete", &[]).await.unwrap();
+    let mut client = connect("host=localhost port=5432 user=postgres dbname=teo_soft_delete").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    assert!(migrate::<Client, Schema>(&mut client).await.unwrap().is_empty());
+    let row = client.query_one("select indexdef from pg_indexes where indexname = 'Account_email_key'", &[]).await.unwrap();
+    let def: String = row.get(0);
+    assert!(def.ends_with("WHERE (deleted_at IS NULL)"));
+    Account::insert_many(&mut client, &[account("ada@example.com"), account("alan@example.com")]).await.unwrap();
+    let ada = Account::find_unique(&mut client, 1).await.unwrap().unwrap();
+    assert_eq!(ada.delete(&mut client).await.unwrap(), 1);
+    assert_eq!(Account::find_unique(&mut client, 1).await.unwrap(), None);
+    assert_eq!(Account::find_unique(&mut client, 1).with_deleted().await.unwrap(), Some(ada));
+    account("ada@example.com").insert(&mut client).await.unwrap();
+    assert!(account("alan@example.com").insert(&mut client).await.is_err());
+    assert_eq!(Account::delete_many(&mut client).filter(Account::email().eq("alan@example.com")).await.unwrap(), 1);
+    let emails: Vec<String> = Account::find_many(&mut client).await.unwrap().into_iter().map(|a| a.email).collect();
+    assert_eq!(emails, vec!["ada@example.com"]);
+    let accounts = Account::find_many(&mut client).order_by(Account::id(), SortOrder::Asc).with_deleted().await.unwrap();
+    assert_eq!(accounts.len(), 3);
+    assert_eq!(Account::count(&mut client).await.unwrap(), 1);
+    assert_eq!(Account::count(&mut client).with_deleted().await.unwrap(), 3);
+    let deleted: i64 = client.query_one("select count(*) from \"Account\" where deleted_at is not null", &[]).await.unwrap().get(0);
+    assert_eq!(deleted, 2);
+}
@@ -0,0 +1,42 @@
+use futures::{StreamExt, TryStreamExt};
+use teo::{Entity, Schema, connection::AsyncTransactional, migration::r#async::migrate, types::SortOrder};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Reading {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    value: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Reading))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+#[tokio::test]
+async fn test_stream() {
+    let admin = connect("host=localhost port=5432 user=postgres").await;
+    let _ = admin.execute("create database teo_stream", &[]).await;
+    let mut client = connect("host=localhost port=5432 user=postgres dbname=teo_stream").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    client.execute("truncate \"Reading\" restart identity", &[]).await.unwrap();
+    client.execute("insert into \"Reading\" (\"value\") select generate_series(0, 4999)", &[]).await.unwrap();
+    let mut stream = Reading::find_many(&mut client).order_by(Reading::id(), SortOrder::Asc).stream();
+    let mut count = 0;
+    while let Some(reading) = stream.next().await {
+        assert_eq!(reading.unwrap().value, count);
+        count += 1;
+    }
+    drop(stream);
+    assert_eq!(count, 5000);
+    let values: Vec<i32> = AsyncTransactional::transaction(&mut client, |transaction| Box::pin(async move {
+        Reading::find_many(transaction).filter(Reading::value().lt(3)).order_by(Reading::value(), SortOrder::Desc).stream().map_ok(|reading| reading.value).try_collect().await
+    })).await.unwrap();
+    assert_eq!(values, vec![2, 1, 0]);
+}
@@ -0,0 +1,56 @@
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use teo::{Entity, Schema, migration::r#async::migrate, types::Change};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Article {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    title: String,
+    #[teo(created_at)]
+    created_at: DateTime<Utc>,
+    #[teo(updated_at)]
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Article))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+#[tokio::test]
+async fn test_timestamps() {
+    let admin = connect("host=localhost port=5432 user=postgres").await;
+    let _ = admin.execute("drop database teo_timestamp", &[]).await;
+    admin.execute("create database teo_timestamp", &[]).await.unwrap();
+    let mut client = connect("host=localhost port=5432 user=postgres dbname=teo_timestamp").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    assert!(migrate::<Client, Schema>(&mut client).await.unwrap().is_empty());
+    let default: String = client.query_one("select column_default from information_schema.columns where table_name = 'Article' and column_name = 'created_at'", &[]).await.unwrap().get(0);
+    assert_eq!(default, "now()");
+    let before = Utc::now();
+    let mut article = Article { id: 0, title: "engines".to_owned(), created_at: DateTime::UNIX_EPOCH, updated_at: DateTime::UNIX_EPOCH };
+    article.insert(&mut client).await.unwrap();
+    assert!(article.created_at >= before);
+    assert_eq!(article.updated_at, article.created_at);
+    let inserted = Article::find_unique(&mut client, article.id).await.unwrap().unwrap();
+    assert_eq!(inserted, article);
+    tokio::time::sleep(Duration::from_millis(2)).await;
+    Article::update(&mut client)
+        .filter(Article::id().eq(article.id))
+        .set(ArticleUpdate { title: Change::Set("notes".to_owned()), ..Default::default() })
+        .await
+        .unwrap();
+    let updated = Article::find_unique(&mut client, article.id).await.unwrap().unwrap();
+    assert_eq!(updated.created_at, inserted.created_at);
+    assert!(updated.updated_at > inserted.updated_at);
+    client.execute("insert into \"Article\" (title) values ('raw')", &[]).await.unwrap();
+    let raw = Article::find_many(&mut client).filter(Article::title().eq("raw")).await.unwrap();
+    assert!(raw[0].created_at >= before);
+}
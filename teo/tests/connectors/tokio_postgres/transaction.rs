@@ -0,0 +1,63 @@
+use std::panic::AssertUnwindSafe;
+use futures::FutureExt;
+use teo::{Entity, Error, Schema, connection::{AsyncTransactional, IsolationLevel}, migration::r#async::migrate};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Account {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    owner: String,
+    balance: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Account))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+fn account(owner: &str, balance: i32) -> Account {
+    Account { id: 0, owner: owner.to_owned(), balance }
+}
+
+#[tokio::test]
+async fn test_transaction() {
+    let admin = connect("host=localhost port=5432 user=postgres").await;
+    let _ = admin.execute("create database teo_transaction", &[]).await;
+    let mut client = connect("host=localhost port=5432 user=postgres dbname=teo_transaction").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    client.execute("delete from \"Account\"", &[]).await.unwrap();
+    AsyncTransactional::transaction(&mut client, |tx| Box::pin(async move {
+        Account::insert_many(tx, &[account("ada", 100), account("alan", 50)]).await?;
+        let nested: teo::Result<()> = tx.transaction(|tx| Box::pin(async move {
+            account("grace", 10).insert(tx).await?;
+            Err(Error::InvalidCursor)
+        })).await;
+        assert!(nested.is_err());
+        Ok(())
+    })).await.unwrap();
+    assert_eq!(Account::count(&mut client).await.unwrap(), 2);
+    let result: teo::Result<()> = AsyncTransactional::transaction_with(&mut client, IsolationLevel::Serializable, |tx| Box::pin(async move {
+        account("linus", 20).insert(tx).await?;
+        Err(Error::InvalidCursor)
+    })).await;
+    assert!(result.is_err());
+    let result = AssertUnwindSafe(AsyncTransactional::transaction::<_, ()>(&mut client, |tx| Box::pin(async move {
+        account("dennis", 30).insert(tx).await?;
+        panic!("interrupted")
+    }))).catch_unwind().await;
+    assert!(result.is_err());
+    assert_eq!(Account::count(&mut client).await.unwrap(), 2);
+    let handle = tokio::spawn(async move {
+        AsyncTransactional::transaction(&mut client, |tx| Box::pin(async move {
+            account("ken", 40).insert(tx).await
+        })).await.unwrap();
+        Account::count(&mut client).await.unwrap()
+    });
+    assert_eq!(handle.await.unwrap(), 3);
+}
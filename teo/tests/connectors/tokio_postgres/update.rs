@@ -0,0 +1,52 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::Change};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity, Debug, PartialEq)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+#[tokio::test]
+async fn test_update() {
+    let mut client = connect("host=localhost port=5432 user=postgres").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    client.execute("delete from \"User\" where \"name\" like 'update\\_%'", &[]).await.unwrap();
+    let users = [
+        User { id: 0, name: "update_ada".to_owned(), age: 36 },
+        User { id: 0, name: "update_alan".to_owned(), age: 41 },
+    ];
+    User::insert_many(&mut client, &users).await.unwrap();
+    let count = User::update(&mut client)
+        .filter(User::name().like("update\\_%"))
+        .set(UserUpdate { age: Change::Set(50), ..Default::default() })
+        .await
+        .unwrap();
+    assert_eq!(count, 2);
+    let users = User::find_many(&mut client).filter(User::name().like("update\\_%")).await.unwrap();
+    assert!(users.iter().all(|u| u.age == 50));
+}
+
+#[tokio::test]
+async fn test_save() {
+    let mut client = connect("host=localhost port=5432 user=postgres").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    let mut user = User { id: 0, name: "save_dan".to_owned(), age: 18 };
+    user.insert(&mut client).await.unwrap();
+    user.age = 19;
+    assert_eq!(user.save(&mut client).await.unwrap(), 1);
+    let found = User::find_unique(&mut client, user.id).await.unwrap();
+    assert_eq!(found, Some(user));
+}
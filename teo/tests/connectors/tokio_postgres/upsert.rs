@@ -0,0 +1,48 @@
+use teo::{Entity, Schema, migration::r#async::migrate, types::SortOrder};
+use tokio_postgres::{self, Client, NoTls};
+
+#[derive(Entity, Debug, PartialEq)]
+struct Subscriber {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    #[teo(unique)]
+    email: String,
+    name: String,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Subscriber))]
+struct Schema;
+
+async fn connect(s: &str) -> Client {
+    let (client, connection) = tokio_postgres::connect(s, NoTls).await.unwrap();
+    tokio::spawn(connection);
+    client
+}
+
+fn subscriber(email: &str, name: &str) -> Subscriber {
+    Subscriber { id: 0, email: email.to_owned(), name: name.to_owned() }
+}
+
+#[tokio::test]
+async fn test_upsert_many() {
+    let admin = connect("host=localhost port=5432 user=postgres").await;
+    let _ = admin.execute("create database teo_upsert", &[]).await;
+    let mut client = connect("host=localhost port=5432 user=postgres dbname=teo_upsert").await;
+    migrate::<Client, Schema>(&mut client).await.unwrap();
+    client.execute("delete from \"Subscriber\"", &[]).await.unwrap();
+    Subscriber::insert_many(&mut client, &[subscriber("ada@example.com", "Ada")]).await.unwrap();
+    let rows = [subscriber("ada@example.com", "Ada Lovelace"), subscriber("alan@example.com", "Alan")];
+    let count = Subscriber::upsert_many(&mut client, &rows)
+        .on_conflict(Subscriber::email())
+        .update([Subscriber::name()])
+        .await
+        .unwrap();
+    assert_eq!(count, 2);
+    let subscribers = Subscriber::find_many(&mut client).order_by(Subscriber::id(), SortOrder::Asc).await.unwrap();
+    let names: Vec<String> = subscribers.into_iter().map(|s| s.name).collect();
+    assert_eq!(names, vec!["Ada Lovelace", "Alan"]);
+    let rows: Vec<Subscriber> = (0..30000).map(|i| subscriber(&format!("{i}@example.com"), "bulk")).collect();
+    let count = Subscriber::upsert_many(&mut client, &rows).on_conflict(Subscriber::email()).await.unwrap();
+    assert_eq!(count, 30000);
+}
@@ -0,0 +1,57 @@
+use teo::{Entity, Error, Schema, migration::{MigrationObserver, MigrationStep, MigrationStepKind, r#async::{migrate, migrate_with_observer}}};
+use tokio_rusqlite::Connection;
+
+#[derive(Entity)]
+#[allow(dead_code)]
+struct User {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    name: String,
+    age: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = User))]
+struct Schema;
+
+#[derive(Default)]
+struct Events(Vec<String>);
+
+impl MigrationObserver for Events {
+
+    fn step_started(&mut self, kind: MigrationStepKind, target: &str) {
+        self.0.push(format!("started {kind} {target}"));
+    }
+
+    fn step_finished(&mut self, step: &MigrationStep, error: Option<&Error>) {
+        self.0.push(format!("finished {} {} {}", step.kind, step.target, error.is_none()));
+    }
+}
+
+#[tokio::test]
+async fn test_migrate() {
+    let mut connection = Connection::open_in_memory().await.unwrap();
+    migrate::<Connection, Schema>(&mut connection).await.unwrap();
+    migrate::<Connection, Schema>(&mut connection).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_migrate_unknown_column_type() {
+    let mut connection = Connection::open_in_memory().await.unwrap();
+    connection.call(|connection| {
+        connection.execute("create table \"User\"(\"id\" integer, \"name\" numeric, \"age\" integer)", ())
+    }).await.unwrap();
+    let error = migrate::<Connection, Schema>(&mut connection).await.unwrap_err();
+    assert!(matches!(error, Error::UnknownColumnType { table, column, .. } if table == "User" && column == "name"));
+}
+
+#[tokio::test]
+async fn test_migrate_observer() {
+    let mut connection = Connection::open_in_memory().await.unwrap();
+    let mut events = Events::default();
+    let report = migrate_with_observer::<Connection, Schema>(&mut connection, &mut events).await.unwrap();
+    assert_eq!(report.steps.len(), 1);
+    assert!(report.steps[0].statements[0].starts_with("create table"));
+    assert!(migrate::<Connection, Schema>(&mut connection).await.unwrap().is_empty());
+    assert_eq!(events.0, vec!["started table created User", "finished table created User true"]);
+}
@@ -0,0 +1,40 @@
+use futures::{StreamExt, TryStreamExt};
+use teo::{Entity, Schema, migration::r#async::migrate, types::SortOrder};
+use tokio_rusqlite::Connection;
+
+#[derive(Entity, Debug, PartialEq)]
+struct Reading {
+    #[teo(primary, auto_increment)]
+    id: i32,
+    value: i32,
+}
+
+#[derive(Schema)]
+#[teo(entity(path = Reading))]
+struct Schema;
+
+#[tokio::test]
+async fn test_stream() {
+    let mut connection = Connection::open_in_memory().await.unwrap();
+    migrate::<Connection, Schema>(&mut connection).await.unwrap();
+    connection.call(|connection| {
+        for value in 0..500 {
+            connection.execute("insert into \"Reading\" (\"value\") values (?1)", [value])?;
+        }
+        Ok::<_, rusqlite::Error>(())
+    }).await.unwrap();
+    let readings: Vec<Reading> = Reading::find_many(&mut connection).order_by(Reading::id(), SortOrder::Asc).stream().try_collect().await.unwrap();
+    assert_eq!(readings.len(), 500);
+    assert_eq!(readings[499], Reading { id: 500, value: 499 });
+    let values: Vec<i32> = Reading::find_many(&mut connection)
+        .filter(Reading::value().gte(100))
+        .order_by(Reading::value(), SortOrder::Desc)
+        .stream()
+        .take(3)
+        .map(|reading| reading.unwrap().value)
+        .collect()
+        .await;
+    assert_eq!(values, vec![499, 498, 497]);
+    let count: i32 = connection.call(|connection| connection.query_row("select count(*) from \"Reading\"", [], |row| row.get(0))).await.unwrap();
+    assert_eq!(count, 500);
+}
@@ -1,7 +1,7 @@
 use teo::Entity;
-use teo::teo_column_type::postgres::ColumnType;
 
 #[derive(Entity)]
+#[allow(dead_code)]
 #[teo(table_name = "User")]
 #[teo(index(
     column(name = a, order = "asc"),